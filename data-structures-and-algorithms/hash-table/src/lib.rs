@@ -3,13 +3,15 @@ Minimal Hash Table Crate
 ========================
 
 This crate defines:
-  - A `HashTable` trait (in `traits.rs`) for a uniform interface.
   - A `LinkedOpenAddressing` implementation (in `linked_open_addressing.rs`)
     that uses open addressing *plus* a doubly linked list, enabling O(1) for
     get_first() and get_last().
+  - An implementation of `kv_traits::HashTable` for it, so it can be used anywhere that trait is
+    expected (and benchmarked against `kv_traits::HashMapStore`).
 
 Reasoning :
-  - The `HashTable` trait makes it easy to swap in different collision strategies.
+  - The `HashTable` trait (defined in the `kv-traits` crate) makes it easy to swap in different
+    collision strategies.
   - `LinkedOpenAddressing` meets the fixed-size requirement with O(1) insert, remove, and get,
     while also providing O(1) get_first and get_last by linking entries.
 
@@ -18,13 +20,15 @@ Note: Re-export trait and struct here, so users can simply `use hash_table::Hash
 */
 
 mod linked_open_addressing;
-mod traits;
 
-// Re-export the HashTable trait so consumers can do `use hash_table::HashTable;`.
-pub use traits::HashTable;
+// Re-export the HashTable trait (and its error type) so consumers can do
+// `use hash_table::HashTable;` without depending on `kv-traits` directly.
+pub use kv_traits::{HashTable, InsertError};
 
 // Re-export our linked open addressing table with doubly linked list tracking.
 pub use linked_open_addressing::LinkedOpenAddressing as LinkedHashTable;
+pub use linked_open_addressing::TableStats;
+pub use linked_open_addressing::{GrowthPolicy, IntoIter, LinkedIter, LinkedIterMut};
 
 #[cfg(test)]
 mod tests {
@@ -1,7 +1,7 @@
 use std::collections::hash_map::RandomState;
 use std::hash::{BuildHasher, Hash};
 
-use crate::traits::HashTable;
+use kv_traits::{HashTable, InsertError};
 
 // ---------------------------------------------------------------------------------------------
 // COMMENTS / RATIONALE:
@@ -22,12 +22,60 @@ use crate::traits::HashTable;
 //      and "open addressing" because we store references to nodes in 'slots'
 //      (which are probed linearly).
 //
-// 4) Potential Improvement:
-//    - This example doesn't implement node recycling; once we remove a node,
-//      that index is effectively "lost." A real system might keep a free list.
+// 4) Node Recycling:
+//    - A removed node's index goes onto `free_nodes`, and `allocate_node` reuses it before
+//      appending a new one at `next_node_index`. Otherwise a workload that repeatedly inserts
+//      and removes would exhaust `next_node_index` and panic even with `len` well below
+//      `capacity`.
+//
+// 5) Fixed Size by Default, Growable on Request:
+//    - `new()`/`with_hasher()` build a table that panics (or returns `InsertError::TableFull`)
+//      once `capacity` is reached, matching the original fixed-size assignment.
+//    - `with_growth_policy()`/`with_hasher_and_growth_policy()` opt into rehashing into a larger
+//      slot array instead, per `GrowthPolicy`. See `grow()`.
 //
 // ---------------------------------------------------------------------------------------------
 
+/// Controls whether a [`LinkedOpenAddressing`] table rehashes into a larger slot array instead of
+/// panicking (or returning [`InsertError::TableFull`]) once it fills up. Opt in via
+/// [`LinkedOpenAddressing::with_growth_policy`]; tables created with [`LinkedOpenAddressing::new`]
+/// stay fixed-size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GrowthPolicy {
+    /// Once inserting a new key would push `len / capacity` above this threshold, the table
+    /// rehashes into a larger slot array first.
+    pub load_factor_threshold: f64,
+    /// Multiplier applied to `capacity` on each rehash (e.g. `2.0` doubles it).
+    pub growth_factor: f64,
+}
+
+impl Default for GrowthPolicy {
+    /// Matches the load factor `std::collections::HashMap` grows at, doubling on each rehash.
+    fn default() -> Self {
+        Self { load_factor_threshold: 0.75, growth_factor: 2.0 }
+    }
+}
+
+/// A snapshot of a table's load and probing behavior, returned by [`LinkedOpenAddressing::stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TableStats {
+    /// Maximum number of entries the table can hold at its current size (a table with a
+    /// growth policy raises this each time it rehashes).
+    pub capacity: usize,
+    /// Number of active entries currently stored.
+    pub len: usize,
+    /// Number of tombstoned (removed) slots still occupying table space.
+    pub tombstones: usize,
+    /// `len / capacity`.
+    pub load_factor: f64,
+    /// Average number of slots examined per occupied entry before finding it.
+    pub average_probe_length: f64,
+    /// Longest probe sequence among occupied entries.
+    pub max_probe_length: usize,
+    /// Rough estimate of the table's heap + struct memory usage, in bytes.
+    pub memory_bytes: usize,
+}
+
 /// Each slot in the open-addressed array can be:
 ///    - Empty: never used
 ///    - Tombstone: was occupied, then removed; used to allow continued probing
@@ -74,19 +122,34 @@ where
     head: Option<usize>,
     tail: Option<usize>,
 
-    // Next index to assign in `nodes`. For simplicity, not reusing freed slots here.
+    // Next index to assign in `nodes` when `free_nodes` is empty.
     next_node_index: usize,
+
+    // Indices of removed nodes, available for `allocate_node` to reuse before growing `nodes`.
+    free_nodes: Vec<usize>,
+
+    // `None` means the table is fixed-size (the original behavior): inserting into a full table
+    // panics (or returns `InsertError::TableFull`). `Some` means it rehashes into a larger slot
+    // array instead, per the policy.
+    growth_policy: Option<GrowthPolicy>,
 }
 
 impl<K, V> LinkedOpenAddressing<K, V>
 where
     K: Eq + Hash,
 {
-    /// Creates a table with a given capacity using default hashing (RandomState).
+    /// Creates a fixed-size table with a given capacity using default hashing (RandomState).
     /// Panics if capacity == 0.
     pub fn new(capacity: usize) -> Self {
         Self::with_hasher(capacity, RandomState::default())
     }
+
+    /// Creates a table with a given starting capacity using default hashing (RandomState) that
+    /// rehashes into a larger slot array per `growth_policy` instead of panicking (or returning
+    /// [`InsertError::TableFull`]) once it fills up. Panics if capacity == 0.
+    pub fn with_growth_policy(capacity: usize, growth_policy: GrowthPolicy) -> Self {
+        Self::with_hasher_and_growth_policy(capacity, growth_policy, RandomState::default())
+    }
 }
 
 impl<K, V, S> LinkedOpenAddressing<K, V, S>
@@ -94,7 +157,7 @@ where
     K: Eq + Hash,
     S: BuildHasher,
 {
-    /// Creates a table with given capacity and a custom hasher.
+    /// Creates a fixed-size table with given capacity and a custom hasher.
     /// Panics if capacity == 0.
     pub fn with_hasher(capacity: usize, hasher_builder: S) -> Self {
         assert!(capacity > 0, "Cannot create a 0-capacity hash table.");
@@ -108,9 +171,30 @@ where
             head: None,
             tail: None,
             next_node_index: 0,
+            free_nodes: Vec::new(),
+            growth_policy: None,
         }
     }
 
+    /// Creates a table with a given starting capacity and a custom hasher that rehashes into a
+    /// larger slot array per `growth_policy` instead of panicking (or returning
+    /// [`InsertError::TableFull`]) once it fills up. Panics if capacity == 0.
+    pub fn with_hasher_and_growth_policy(
+        capacity: usize,
+        growth_policy: GrowthPolicy,
+        hasher_builder: S,
+    ) -> Self {
+        assert!(growth_policy.growth_factor > 1.0, "growth_factor must be greater than 1.0");
+        assert!(
+            growth_policy.load_factor_threshold > 0.0 && growth_policy.load_factor_threshold <= 1.0,
+            "load_factor_threshold must be in (0.0, 1.0]"
+        );
+
+        let mut table = Self::with_hasher(capacity, hasher_builder);
+        table.growth_policy = Some(growth_policy);
+        table
+    }
+
     /// Returns the current number of (active) entries.
     pub fn len(&self) -> usize {
         self.len
@@ -121,9 +205,64 @@ where
         self.len == 0
     }
 
+    /// Returns the table's current maximum number of entries: fixed for tables built with
+    /// `new()`/`with_hasher()`, raised on each rehash for tables with a growth policy.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Computes a snapshot of the table's current load and probing behavior.
+    ///
+    /// Probe length is measured per occupied slot as the number of slots examined
+    /// (starting at 1) from that key's ideal slot to where it actually landed.
+    pub fn stats(&self) -> TableStats {
+        let mut tombstones = 0;
+        let mut occupied = 0;
+        let mut total_probe_length = 0usize;
+        let mut max_probe_length = 0usize;
+
+        for (idx, slot) in self.slots.iter().enumerate() {
+            match slot {
+                Slot::Tombstone => tombstones += 1,
+                Slot::Occupied(node_idx) => {
+                    occupied += 1;
+                    let ideal = self.index_for(&self.nodes[*node_idx].key);
+                    let probe_length = (idx + self.capacity - ideal) % self.capacity + 1;
+                    total_probe_length += probe_length;
+                    max_probe_length = max_probe_length.max(probe_length);
+                }
+                Slot::Empty => {}
+            }
+        }
+
+        let memory_bytes = std::mem::size_of::<Self>()
+            + self.slots.capacity() * std::mem::size_of::<Slot>()
+            + self.nodes.capacity() * std::mem::size_of::<Node<K, V>>();
+
+        TableStats {
+            capacity: self.capacity,
+            len: self.len,
+            tombstones,
+            load_factor: self.len as f64 / self.capacity as f64,
+            average_probe_length: if occupied > 0 {
+                total_probe_length as f64 / occupied as f64
+            } else {
+                0.0
+            },
+            max_probe_length,
+            memory_bytes,
+        }
+    }
+
     /// Hashes the key and maps it to a slot index.
     fn index_for(&self, key: &K) -> usize {
-        (self.hasher_builder.hash_one(key) % self.capacity as u64) as usize
+        self.index_for_capacity(key, self.capacity)
+    }
+
+    /// Hashes the key and maps it to a slot index for a (possibly not-yet-installed) capacity,
+    /// so [`Self::grow`] can compute new slot positions before replacing `self.slots`.
+    fn index_for_capacity(&self, key: &K, capacity: usize) -> usize {
+        (self.hasher_builder.hash_one(key) % capacity as u64) as usize
     }
 
     /// Finds the slot for `key` or an insertion slot (first tombstone or empty).
@@ -164,9 +303,14 @@ where
         }
     }
 
-    /// Allocates a new node in `nodes` at index `next_node_index`.
-    /// Real code might reuse freed slots, but I'm keeping it straightforward for the assignment.
+    /// Allocates a node for `key`/`value`, reusing a removed node's index from `free_nodes` if
+    /// one is available, otherwise appending a new one at `next_node_index`.
     fn allocate_node(&mut self, key: K, value: V) -> usize {
+        if let Some(idx) = self.free_nodes.pop() {
+            self.nodes[idx] = Node { key, value, prev: None, next: None };
+            return idx;
+        }
+
         if self.next_node_index >= self.capacity {
             panic!("No more space to allocate new nodes!");
         }
@@ -207,6 +351,32 @@ where
         }
     }
 
+    /// Rehashes every live entry (walking the linked list, so tombstoned slots are dropped along
+    /// the way) into a larger slot array sized per `growth_policy`. Node indices are untouched,
+    /// since only `slots` -- not `nodes` -- depends on `capacity`.
+    ///
+    /// Only ever called when `self.growth_policy` is `Some`.
+    fn grow(&mut self) {
+        let policy = self.growth_policy.expect("grow() called on a fixed-size table");
+        let new_capacity =
+            ((self.capacity as f64 * policy.growth_factor).ceil() as usize).max(self.capacity + 1);
+
+        let mut new_slots = vec![Slot::Empty; new_capacity];
+        let mut current = self.head;
+        while let Some(node_idx) = current {
+            let mut probe_idx = self.index_for_capacity(&self.nodes[node_idx].key, new_capacity);
+            while !matches!(new_slots[probe_idx], Slot::Empty) {
+                probe_idx = (probe_idx + 1) % new_capacity;
+            }
+            new_slots[probe_idx] = Slot::Occupied(node_idx);
+
+            current = self.nodes[node_idx].next;
+        }
+
+        self.slots = new_slots;
+        self.capacity = new_capacity;
+    }
+
     /// Links a newly added or updated node at the tail (newest) of the list.
     fn link_at_tail(&mut self, node_idx: usize) {
         let old_tail = self.tail;
@@ -223,18 +393,17 @@ where
 
         self.nodes[node_idx].next = None;
     }
-}
 
-/// Implement trait that requires O(1) for insert, remove, get, get_first, get_last.
-impl<K, V, S> HashTable<K, V> for LinkedOpenAddressing<K, V, S>
-where
-    K: Eq + Hash,
-    S: BuildHasher,
-{
-    fn insert(&mut self, key: K, value: V) -> Option<V> {
-        if self.len == self.capacity {
-            // We are at max capacity: assignment says "fixed size" -> panic.
-            panic!("Hash table is full, cannot insert new key!");
+    /// Shared implementation behind [`HashTable::insert`] and [`HashTable::try_insert`].
+    ///
+    /// Probes for `key` *before* checking capacity, so updating an existing key never fails
+    /// just because the table happens to be full — only inserting a genuinely new key into a
+    /// full table does.
+    fn insert_impl(&mut self, key: K, value: V) -> Result<Option<V>, InsertError> {
+        // A completely occupied slot array has no Empty or Tombstone slot for `probe` to stop
+        // at, so a growable table rehashes before probing rather than letting `probe` panic.
+        if self.growth_policy.is_some() && self.len == self.capacity {
+            self.grow();
         }
 
         match self.probe(&key) {
@@ -250,13 +419,30 @@ where
                     // Now re-link at tail
                     self.link_at_tail(node_idx);
 
-                    Some(old_value)
+                    Ok(Some(old_value))
                 } else {
                     // Shouldn't happen
                     unreachable!("Found key but slot isn't occupied?")
                 }
             }
             Err(slot_idx) => {
+                if let Some(policy) = self.growth_policy {
+                    let would_exceed_threshold =
+                        (self.len + 1) as f64 / self.capacity as f64 > policy.load_factor_threshold;
+
+                    let nodes_exhausted =
+                        self.free_nodes.is_empty() && self.next_node_index >= self.capacity;
+
+                    if would_exceed_threshold || nodes_exhausted {
+                        self.grow();
+                        // `slot_idx` was computed against the old capacity; reprobe against the
+                        // grown table instead of reusing it.
+                        return self.insert_impl(key, value);
+                    }
+                } else if self.len == self.capacity {
+                    return Err(InsertError::TableFull);
+                }
+
                 // Key not found: we can insert at slot_idx
                 self.len += 1;
 
@@ -269,10 +455,148 @@ where
                 // Occupy this slot with a pointer to that node index
                 self.slots[slot_idx] = Slot::Occupied(node_idx);
 
-                None
+                Ok(None)
             }
         }
     }
+}
+
+/// Iterates over a [`LinkedOpenAddressing`] table's entries in insertion/update order (oldest to
+/// newest), following the doubly linked list rather than scanning `slots`. Returned by
+/// [`HashTable::iter`] and by the `&Table` [`IntoIterator`] impl.
+pub struct LinkedIter<'a, K, V> {
+    nodes: &'a [Node<K, V>],
+    current: Option<usize>,
+}
+
+impl<'a, K, V> Iterator for LinkedIter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = &self.nodes[self.current?];
+        self.current = node.next;
+        Some((&node.key, &node.value))
+    }
+}
+
+/// Consuming iterator over a [`LinkedOpenAddressing`] table's entries, in insertion/update order,
+/// returned by its [`IntoIterator`] impl.
+pub struct IntoIter<K, V> {
+    nodes: Vec<Option<Node<K, V>>>,
+    current: Option<usize>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.current?;
+        let node = self.nodes[idx].take().expect("the linked list visits each node at most once");
+        self.current = node.next;
+        Some((node.key, node.value))
+    }
+}
+
+impl<K, V, S> IntoIterator for LinkedOpenAddressing<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = (K, V);
+    type IntoIter = IntoIter<K, V>;
+
+    /// Drains the table into owned `(K, V)` pairs in insertion/update order, without cloning
+    /// keys or values.
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            current: self.head,
+            nodes: self.nodes.into_iter().map(Some).collect(),
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a LinkedOpenAddressing<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = LinkedIter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        LinkedIter {
+            nodes: &self.nodes,
+            current: self.head,
+        }
+    }
+}
+
+/// Mutable iterator over a [`LinkedOpenAddressing`] table's entries, in insertion/update order,
+/// returned by its `&mut Table` [`IntoIterator`] impl.
+pub struct LinkedIterMut<'a, K, V> {
+    nodes: &'a mut [Node<K, V>],
+    order: std::vec::IntoIter<usize>,
+}
+
+impl<'a, K, V> Iterator for LinkedIterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.order.next()?;
+        // SAFETY: `order` was built by walking the linked list once and lists each node index
+        // exactly once, so each index is dereferenced at most once here: handing out a unique
+        // `&'a mut` per node doesn't alias with any other reference this iterator produces.
+        let node = unsafe { &mut *(&mut self.nodes[idx] as *mut Node<K, V>) };
+        Some((&node.key, &mut node.value))
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut LinkedOpenAddressing<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = LinkedIterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut order = Vec::with_capacity(self.len);
+        let mut current = self.head;
+        while let Some(idx) = current {
+            order.push(idx);
+            current = self.nodes[idx].next;
+        }
+
+        LinkedIterMut { nodes: &mut self.nodes, order: order.into_iter() }
+    }
+}
+
+/// Implement trait that requires O(1) for insert, remove, get, get_first, get_last.
+impl<K, V, S> HashTable<K, V> for LinkedOpenAddressing<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher,
+{
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.insert_impl(key, value) {
+            Ok(old_value) => old_value,
+            // assignment says "fixed size" -> panic, same as the original behavior.
+            Err(InsertError::TableFull) => panic!("Hash table is full, cannot insert new key!"),
+        }
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, InsertError> {
+        // With every slot occupied, `probe` has no Empty or Tombstone slot to stop at and panics
+        // looking for a missing key, so a full fixed-size table is checked here by walking the
+        // linked list instead.
+        if self.growth_policy.is_none()
+            && self.len == self.capacity
+            && !self.iter().any(|(existing, _)| existing == &key)
+        {
+            return Err(InsertError::TableFull);
+        }
+        self.insert_impl(key, value)
+    }
 
     fn remove(&mut self, key: &K) -> Option<V> {
         match self.probe(key) {
@@ -292,8 +616,10 @@ where
                         std::mem::zeroed()
                     });
 
-                    // This isn't ideal but works for this example - in production code we might
-                    // have a free list to reuse these nodes
+                    // Make this index available to `allocate_node` again, so a workload that
+                    // repeatedly inserts and removes doesn't exhaust `next_node_index`.
+                    self.free_nodes.push(node_idx);
+
                     Some(value)
                 } else {
                     None
@@ -316,6 +642,19 @@ where
         }
     }
 
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        match self.probe(key) {
+            Ok(slot_idx) => {
+                if let Slot::Occupied(node_idx) = self.slots[slot_idx] {
+                    Some(&mut self.nodes[node_idx].value)
+                } else {
+                    None
+                }
+            }
+            Err(_) => None,
+        }
+    }
+
     fn get_first(&self) -> Option<(&K, &V)> {
         // The "oldest" node is at self.head
         self.head.map(|head_idx| {
@@ -331,6 +670,33 @@ where
             (&node.key, &node.value)
         })
     }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        LinkedIter {
+            nodes: &self.nodes,
+            current: self.head,
+        }
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.probe(key).is_ok()
+    }
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.capacity()
+    }
 }
 
 #[cfg(test)]
@@ -391,6 +757,27 @@ mod tests {
         assert_eq!(table.get_last(), Some((&"Celestia", &35)));
     }
 
+    #[test]
+    fn test_stats() {
+        let mut table = LinkedOpenAddressing::new(10);
+        table.insert("a", 1);
+        table.insert("b", 2);
+        table.insert("c", 3);
+
+        let stats = table.stats();
+        assert_eq!(stats.capacity, 10);
+        assert_eq!(stats.len, 3);
+        assert_eq!(stats.tombstones, 0);
+        assert!((stats.load_factor - 0.3).abs() < 1e-9);
+        assert!(stats.average_probe_length >= 1.0);
+        assert!(stats.max_probe_length >= 1);
+
+        table.remove(&"a");
+        let stats_after_remove = table.stats();
+        assert_eq!(stats_after_remove.len, 2);
+        assert_eq!(stats_after_remove.tombstones, 1);
+    }
+
     #[test]
     fn test_collisions() {
         use std::hash::Hasher;
@@ -423,4 +810,157 @@ mod tests {
         assert_eq!(table.get(&"Stellar"), Some(&2));
         assert_eq!(table.get(&"Hedera"), Some(&3));
     }
+
+    #[test]
+    fn test_repeated_insert_and_remove_does_not_exhaust_node_indices() {
+        let mut table = LinkedOpenAddressing::new(2);
+
+        // Without recycling, this would panic once `next_node_index` reaches `capacity`, even
+        // though `len` never exceeds 1.
+        for i in 0..100 {
+            table.insert(i, i);
+            assert_eq!(table.remove(&i), Some(i));
+        }
+
+        assert_eq!(table.len(), 0);
+    }
+
+    #[test]
+    fn test_churn_with_a_growth_policy_reuses_free_nodes_instead_of_growing() {
+        let mut table = LinkedOpenAddressing::with_growth_policy(4, GrowthPolicy::default());
+
+        // `len` never exceeds 1, so the load factor threshold never trips. Before recycled
+        // node indices were considered here, `next_node_index` alone would reach `capacity`
+        // on the very first round of churn and force a grow anyway.
+        for i in 0..100 {
+            table.insert(i, i);
+            assert_eq!(table.remove(&i), Some(i));
+        }
+
+        assert_eq!(table.capacity(), 4);
+    }
+
+    #[test]
+    fn test_removed_node_index_is_reused() {
+        let mut table = LinkedOpenAddressing::new(3);
+        table.insert("a", 1);
+        table.insert("b", 2);
+        table.remove(&"a");
+
+        table.insert("c", 3);
+
+        assert_eq!(table.get(&"b"), Some(&2));
+        assert_eq!(table.get(&"c"), Some(&3));
+        assert_eq!(table.len(), 2);
+    }
+
+    #[test]
+    fn test_growth_policy_rehashes_instead_of_failing() {
+        let mut table = LinkedOpenAddressing::with_growth_policy(
+            2,
+            GrowthPolicy { load_factor_threshold: 0.5, growth_factor: 2.0 },
+        );
+
+        for i in 0..20 {
+            table.insert(i, i * 10);
+        }
+
+        assert_eq!(table.len(), 20);
+        assert!(table.capacity() > 2);
+        for i in 0..20 {
+            assert_eq!(table.get(&i), Some(&(i * 10)));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Hash table is completely full!")]
+    fn test_fixed_size_table_still_fails_without_a_growth_policy() {
+        let mut table = LinkedOpenAddressing::new(2);
+        table.insert("a", 1);
+        table.insert("b", 2);
+
+        // No growth policy, and the table is genuinely full with no tombstones to reclaim.
+        table.insert("c", 3);
+    }
+
+    #[test]
+    fn test_growth_preserves_insertion_order() {
+        let mut table = LinkedOpenAddressing::with_growth_policy(2, GrowthPolicy::default());
+        table.insert("Celestia", 25);
+        table.insert("Casper", 2);
+        table.insert("Akash", 15);
+
+        assert_eq!(table.get_first(), Some((&"Celestia", &25)));
+        assert_eq!(table.get_last(), Some((&"Akash", &15)));
+    }
+
+    #[test]
+    fn test_contains_key_and_capacity_via_the_trait() {
+        fn generic_check<T: HashTable<&'static str, i32>>(table: &T) -> (bool, usize, usize) {
+            (table.contains_key(&"Injective"), table.len(), table.capacity())
+        }
+
+        let mut table = LinkedOpenAddressing::new(5);
+        table.insert("Injective", 55);
+
+        let (contains, len, capacity) = generic_check(&table);
+        assert!(contains);
+        assert_eq!(len, 1);
+        assert_eq!(capacity, 5);
+        assert!(!HashTable::contains_key(&table, &"Osmosis"));
+    }
+
+    #[test]
+    fn test_get_mut_updates_value_in_place() {
+        let mut table = LinkedOpenAddressing::new(5);
+        table.insert("Injective", 55);
+
+        *table.get_mut(&"Injective").unwrap() += 1;
+
+        assert_eq!(table.get(&"Injective"), Some(&56));
+    }
+
+    #[test]
+    fn test_get_mut_missing_key_returns_none() {
+        let mut table: LinkedOpenAddressing<&str, i32> = LinkedOpenAddressing::new(5);
+        assert_eq!(table.get_mut(&"Injective"), None);
+    }
+
+    #[test]
+    fn test_into_iter_owned_yields_insertion_order() {
+        let mut table = LinkedOpenAddressing::new(5);
+        table.insert("Celestia", 25);
+        table.insert("Casper", 2);
+        table.insert("Akash", 15);
+
+        let pairs: Vec<(&str, i32)> = table.into_iter().collect();
+        assert_eq!(pairs, vec![("Celestia", 25), ("Casper", 2), ("Akash", 15)]);
+    }
+
+    #[test]
+    fn test_into_iter_by_ref_does_not_consume_the_table() {
+        let mut table = LinkedOpenAddressing::new(5);
+        table.insert("Celestia", 25);
+        table.insert("Casper", 2);
+
+        let pairs: Vec<(&&str, &i32)> = (&table).into_iter().collect();
+        assert_eq!(pairs, vec![(&"Celestia", &25), (&"Casper", &2)]);
+
+        // `table` is still usable since we only borrowed it.
+        assert_eq!(table.get(&"Celestia"), Some(&25));
+    }
+
+    #[test]
+    fn test_into_iter_mut_allows_updating_values_in_place() {
+        let mut table = LinkedOpenAddressing::new(5);
+        table.insert("Celestia", 25);
+        table.insert("Casper", 2);
+
+        for (_, value) in &mut table {
+            *value *= 10;
+        }
+
+        assert_eq!(table.get(&"Celestia"), Some(&250));
+        assert_eq!(table.get(&"Casper"), Some(&20));
+    }
 }
@@ -0,0 +1,132 @@
+//! A `HashTable` adapter over `std::collections::HashMap`, for benchmarking
+//! `LinkedOpenAddressing` against the standard library's own (growable, unordered) table.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{HashTable, InsertError};
+
+/// Adapts `std::collections::HashMap` to the [`HashTable`] trait.
+///
+/// Unlike `LinkedOpenAddressing`, this has no fixed capacity, so [`HashTable::try_insert`] never
+/// fails. `HashMap` also doesn't track insertion order, so [`HashTable::get_first`] and
+/// [`HashTable::get_last`] both just return *some* entry rather than the oldest/newest.
+#[derive(Debug, Default)]
+pub struct HashMapStore<K, V>(HashMap<K, V>);
+
+impl<K, V> HashMapStore<K, V> {
+    pub fn new() -> Self {
+        Self(HashMap::new())
+    }
+}
+
+impl<K, V> HashTable<K, V> for HashMapStore<K, V>
+where
+    K: Eq + Hash,
+{
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.0.insert(key, value)
+    }
+
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, InsertError> {
+        Ok(self.insert(key, value))
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        self.0.remove(key)
+    }
+
+    fn get(&self, key: &K) -> Option<&V> {
+        self.0.get(key)
+    }
+
+    fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        self.0.get_mut(key)
+    }
+
+    fn get_last(&self) -> Option<(&K, &V)> {
+        self.0.iter().next()
+    }
+
+    fn get_first(&self) -> Option<(&K, &V)> {
+        self.0.iter().next()
+    }
+
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a,
+    {
+        self.0.iter()
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.0.contains_key(key)
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn capacity(&self) -> usize {
+        self.0.capacity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert_and_get_round_trip() {
+        let mut store = HashMapStore::new();
+        assert_eq!(store.insert("a", 1), None);
+        assert_eq!(store.get(&"a"), Some(&1));
+    }
+
+    #[test]
+    fn try_insert_never_fails() {
+        let mut store = HashMapStore::new();
+        for i in 0..100 {
+            assert!(store.try_insert(i, i).is_ok());
+        }
+    }
+
+    #[test]
+    fn contains_key_and_len_reflect_the_store() {
+        let mut store = HashMapStore::new();
+        assert!(store.is_empty());
+
+        store.insert("a", 1);
+
+        assert!(store.contains_key(&"a"));
+        assert!(!store.contains_key(&"b"));
+        assert_eq!(store.len(), 1);
+        assert!(!store.is_empty());
+    }
+
+    #[test]
+    fn get_mut_updates_value_in_place() {
+        let mut store = HashMapStore::new();
+        store.insert("a", 1);
+
+        *store.get_mut(&"a").unwrap() += 1;
+
+        assert_eq!(store.get(&"a"), Some(&2));
+    }
+
+    #[test]
+    fn iter_visits_every_entry() {
+        let mut store = HashMapStore::new();
+        store.insert("a", 1);
+        store.insert("b", 2);
+
+        let mut seen: Vec<_> = store.iter().collect();
+        seen.sort();
+        assert_eq!(seen, vec![(&"a", &1), (&"b", &2)]);
+    }
+}
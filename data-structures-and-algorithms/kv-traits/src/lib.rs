@@ -0,0 +1,95 @@
+//! The `HashTable` trait shared by this workspace's key-value store implementations (the
+//! `hash-table` crate's `LinkedOpenAddressing`, and this crate's own `HashMapStore` adapter), so
+//! downstream crates can be written against the trait and benchmarked against either backend.
+
+mod hash_map_store;
+
+pub use hash_map_store::HashMapStore;
+
+use std::hash::Hash;
+
+/// Failure to insert into a fixed-capacity [`HashTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InsertError {
+    /// The table is at capacity and the key being inserted doesn't already exist in it.
+    TableFull,
+}
+
+impl std::fmt::Display for InsertError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::TableFull => write!(f, "hash table is at capacity"),
+        }
+    }
+}
+
+impl std::error::Error for InsertError {}
+
+/// A generic trait for hash-table-like data structures.
+///
+/// # Type Parameters
+/// - `K`: The key type (must implement `Eq` and `Hash`).
+/// - `V`: The value type.
+pub trait HashTable<K, V>
+where
+    K: Eq + Hash,
+{
+    /// Inserts or updates a key-value pair in the hash table.
+    ///
+    /// Returns `Some(old_value)` if the key existed and its value was replaced,
+    /// otherwise returns `None` if the key was newly inserted.
+    ///
+    /// Panics if the table is at a fixed capacity and is full; use [`HashTable::try_insert`] to
+    /// handle that case instead of panicking.
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+
+    /// Like [`HashTable::insert`], but returns [`InsertError::TableFull`] instead of panicking
+    /// when the table is at a fixed capacity and `key` doesn't already exist in it.
+    fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, InsertError>;
+
+    /// Removes a key-value pair from the hash table.
+    ///
+    /// Returns `Some(value)` if the key existed (and is removed),
+    /// or `None` if the key wasn’t found.
+    fn remove(&mut self, key: &K) -> Option<V>;
+
+    /// Retrieves a reference to the value for the given `key`, if it exists.
+    fn get(&self, key: &K) -> Option<&V>;
+
+    /// Retrieves a mutable reference to the value for the given `key`, if it exists.
+    ///
+    /// Lets callers update a value in place (e.g. bumping a counter) with a single probe,
+    /// instead of a [`HashTable::get`]-then-[`HashTable::insert`] round trip that probes twice
+    /// and clones the key.
+    fn get_mut(&mut self, key: &K) -> Option<&mut V>;
+
+    /// Returns a reference to the most recent key-value pair
+    /// that was either inserted or updated (and still present).
+    fn get_last(&self) -> Option<(&K, &V)>;
+
+    /// Returns a reference to the least recent key-value pair
+    /// that was inserted or updated (and still present).
+    fn get_first(&self) -> Option<(&K, &V)>;
+
+    /// Iterates over every entry currently in the table. Implementations that track insertion
+    /// order (like `LinkedOpenAddressing`) yield entries in that order; others (like
+    /// `HashMapStore`) yield them in an unspecified order.
+    fn iter<'a>(&'a self) -> impl Iterator<Item = (&'a K, &'a V)>
+    where
+        K: 'a,
+        V: 'a;
+
+    /// Returns whether `key` is present in the table, without borrowing its value.
+    fn contains_key(&self, key: &K) -> bool;
+
+    /// Returns the number of entries currently in the table.
+    fn len(&self) -> usize;
+
+    /// Returns whether the table has no entries.
+    fn is_empty(&self) -> bool;
+
+    /// Returns the table's current capacity. For a fixed-capacity implementation like
+    /// `LinkedOpenAddressing`, this is the hard limit on [`HashTable::len`]; for a growable one
+    /// like `HashMapStore`, it's how many entries can be held before the next reallocation.
+    fn capacity(&self) -> usize;
+}
@@ -0,0 +1,250 @@
+//! A fixed-capacity LRU cache with optional per-entry TTL, built on top of the `hash-table`
+//! crate's [`LinkedHashTable`].
+//!
+//! `LinkedOpenAddressing::remove` is unsound for heap-containing value types (it zero-initializes
+//! the removed slot — see `hash_table::linked_open_addressing::remove`), so, following the same
+//! pattern as `binance-options-client`'s `response_cache`, the table here never stores `V`
+//! directly. It only tracks `usize` slot handles into a side `Vec<Option<Entry<V>>>`, with freed
+//! slots recycled through `free_slots` so the cache's own bookkeeping doesn't grow unboundedly on
+//! eviction.
+//!
+//! `LinkedOpenAddressing` recycles its own removed nodes as well, so `capacity` limits live
+//! entries rather than being a lifetime budget on the underlying table. See the note on
+//! [`Cache::put`].
+
+use std::hash::{BuildHasher, Hash};
+use std::time::{Duration, Instant};
+
+use hash_table::{HashTable, LinkedHashTable};
+
+struct Entry<V> {
+    value: V,
+    inserted_at: Instant,
+}
+
+/// Hit/miss/eviction counts and current occupancy for a [`Cache`], returned by [`Cache::stats`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub len: usize,
+    pub capacity: usize,
+}
+
+/// A fixed-capacity, least-recently-used cache. Entries older than the configured TTL (if any)
+/// are treated as misses and evicted lazily, on the next `get` or `put` that touches them.
+pub struct Cache<K, V, S = std::collections::hash_map::RandomState>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    capacity: usize,
+    ttl: Option<Duration>,
+    /// Tracks recency and capacity; values are slot indices into `entries`, not cached values.
+    order: LinkedHashTable<K, usize, S>,
+    entries: Vec<Option<Entry<V>>>,
+    free_slots: Vec<usize>,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl<K, V> Cache<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Creates a cache holding at most `capacity` entries, with no TTL (entries live until
+    /// evicted for space). Panics if `capacity == 0`.
+    pub fn new(capacity: usize) -> Self {
+        Self::with_ttl(capacity, None::<Duration>)
+    }
+
+    /// Creates a cache holding at most `capacity` entries, each expiring `ttl` after it was last
+    /// written. Panics if `capacity == 0`.
+    pub fn with_ttl(capacity: usize, ttl: impl Into<Option<Duration>>) -> Self {
+        assert!(capacity > 0, "Cannot create a 0-capacity cache.");
+        Self {
+            capacity,
+            ttl: ttl.into(),
+            // One spare slot: `order` never holds more than `capacity` keys, so its probe always
+            // finds a free slot when looking up a missing key instead of panicking on a full table.
+            order: LinkedHashTable::new(capacity + 1),
+            entries: Vec::new(),
+            free_slots: Vec::new(),
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+}
+
+impl<K, V, S> Cache<K, V, S>
+where
+    K: Eq + Hash + Clone,
+    S: BuildHasher,
+{
+    fn is_expired(&self, entry: &Entry<V>) -> bool {
+        match self.ttl {
+            Some(ttl) => entry.inserted_at.elapsed() >= ttl,
+            None => false,
+        }
+    }
+
+    /// Drops the entry at `slot` and frees it for reuse, without touching hit/miss counters.
+    fn evict_slot(&mut self, key: &K, slot: usize) {
+        self.order.remove(key);
+        self.entries[slot] = None;
+        self.free_slots.push(slot);
+    }
+
+    /// Looks up `key`, counting a hit or a miss. An expired entry counts as a miss and is
+    /// evicted. Recency (for eviction purposes) is tracked by insertion and update order only,
+    /// not by reads — the same convention `binance-options-client`'s `response_cache` uses.
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let slot = match self.order.get(key) {
+            Some(&slot) => slot,
+            None => {
+                self.misses += 1;
+                return None;
+            }
+        };
+
+        let expired = match &self.entries[slot] {
+            Some(entry) => self.is_expired(entry),
+            None => true,
+        };
+
+        if expired {
+            self.misses += 1;
+            self.evict_slot(key, slot);
+            return None;
+        }
+
+        self.hits += 1;
+        self.entries[slot].as_ref().map(|entry| &entry.value)
+    }
+
+    /// Inserts or refreshes `key`, evicting the least-recently-inserted-or-updated entry first
+    /// if the cache is at capacity and `key` isn't already present.
+    ///
+    /// Note: `LinkedOpenAddressing` recycles a removed entry's node index on the next insert
+    /// (see its own "Node Recycling" note), so evicting an old key to make room for a new one
+    /// doesn't exhaust its node budget — `capacity` bounds this cache's live entry count, not a
+    /// lifetime total of distinct keys plus recency-refreshes.
+    pub fn put(&mut self, key: K, value: V) {
+        let entry = Entry {
+            value,
+            inserted_at: Instant::now(),
+        };
+
+        if let Some(&slot) = self.order.get(&key) {
+            // Removing and re-inserting moves `key` to the newest end of `order`, keeping its
+            // slot.
+            self.order.remove(&key);
+            self.order.insert(key, slot);
+            self.entries[slot] = Some(entry);
+            return;
+        }
+
+        if self.order.len() >= self.capacity
+            && let Some((oldest_key, &oldest_slot)) = self.order.get_first()
+        {
+            let oldest_key = oldest_key.clone();
+            self.evict_slot(&oldest_key, oldest_slot);
+            self.evictions += 1;
+        }
+
+        let slot = self.free_slots.pop().unwrap_or_else(|| {
+            self.entries.push(None);
+            self.entries.len() - 1
+        });
+        self.entries[slot] = Some(entry);
+        self.order.insert(key, slot);
+    }
+
+    /// Removes `key`, returning its value if it was present (and not expired).
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let &slot = self.order.get(key)?;
+        self.order.remove(key);
+        let entry = self.entries[slot].take();
+        self.free_slots.push(slot);
+        entry.filter(|entry| !self.is_expired(entry)).map(|entry| entry.value)
+    }
+
+    /// Number of entries currently stored, including any not-yet-evicted expired ones.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// A snapshot of hit/miss/eviction counts and current occupancy.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            len: self.order.len(),
+            capacity: self.capacity,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn a_miss_then_put_then_get_is_a_hit() {
+        let mut cache: Cache<String, i32> = Cache::new(2);
+
+        assert_eq!(cache.get(&"a".to_string()), None);
+        cache.put("a".to_string(), 1);
+        assert_eq!(cache.get(&"a".to_string()), Some(&1));
+
+        let stats = cache.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 1);
+    }
+
+    #[test]
+    fn an_entry_past_its_ttl_is_treated_as_a_miss() {
+        let mut cache: Cache<&str, i32> = Cache::with_ttl(2, Duration::from_millis(1));
+
+        cache.put("a", 1);
+        std::thread::sleep(Duration::from_millis(20));
+
+        assert_eq!(cache.get(&"a"), None);
+        assert_eq!(cache.stats().misses, 1);
+    }
+
+    #[test]
+    fn updating_an_existing_key_does_not_change_the_entry_count() {
+        let mut cache: Cache<&str, i32> = Cache::new(2);
+
+        cache.put("a", 1);
+        cache.put("a", 2);
+
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&"a"), Some(&2));
+    }
+
+    #[test]
+    fn removing_a_key_frees_its_slot_for_reuse() {
+        let mut cache: Cache<&str, i32> = Cache::new(2);
+
+        cache.put("a", 1);
+        assert_eq!(cache.remove(&"a"), Some(1));
+        assert_eq!(cache.len(), 0);
+
+        cache.put("b", 2);
+        assert_eq!(cache.get(&"b"), Some(&2));
+    }
+}
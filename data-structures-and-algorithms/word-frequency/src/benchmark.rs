@@ -0,0 +1,62 @@
+//! Comparing `LinkedHashTable` against `std::HashMap` for counting the same words.
+
+use hash_table::LinkedHashTable;
+
+use crate::counting::count_words;
+
+/// Wall time, probe stats, and a rough memory estimate for counting `words` with one
+/// table implementation.
+pub struct BenchmarkRun {
+    pub duration: std::time::Duration,
+    pub entries: usize,
+    pub memory_bytes: usize,
+    pub average_probe_length: f64,
+}
+
+/// Counts `words` with `LinkedHashTable` (capacity `table_capacity`) and with
+/// `std::collections::HashMap`, timing each.
+pub fn run_benchmark(words: &[String], table_capacity: usize) -> (BenchmarkRun, BenchmarkRun) {
+    let start = std::time::Instant::now();
+    let mut linked = LinkedHashTable::new(table_capacity);
+    count_words(words.to_vec(), &mut linked, None);
+    let linked_stats = linked.stats();
+    let linked_run = BenchmarkRun {
+        duration: start.elapsed(),
+        entries: linked_stats.len,
+        memory_bytes: linked_stats.memory_bytes,
+        average_probe_length: linked_stats.average_probe_length,
+    };
+
+    let start = std::time::Instant::now();
+    let mut map: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for word in words {
+        *map.entry(word.clone()).or_insert(0) += 1;
+    }
+    // std::HashMap doesn't expose its internal layout; approximate memory usage as
+    // capacity slots, each holding a String (24 bytes on the stack) plus heap bytes
+    // for its characters, a usize value, and one byte of SwissTable-style metadata.
+    let memory_bytes = map.capacity() * (std::mem::size_of::<String>() + std::mem::size_of::<usize>() + 1)
+        + map.keys().map(|key| key.len()).sum::<usize>();
+    let std_run = BenchmarkRun {
+        duration: start.elapsed(),
+        entries: map.len(),
+        memory_bytes,
+        average_probe_length: f64::NAN,
+    };
+
+    (linked_run, std_run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn both_table_kinds_count_the_same_words() {
+        let words: Vec<String> = ["a", "b", "a"].into_iter().map(String::from).collect();
+        let (linked_run, std_run) = run_benchmark(&words, 4);
+
+        assert_eq!(linked_run.entries, 2);
+        assert_eq!(std_run.entries, 2);
+    }
+}
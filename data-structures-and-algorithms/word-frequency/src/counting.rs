@@ -0,0 +1,44 @@
+//! Tallies word occurrences into any [`HashTable`] implementation.
+
+use hash_table::HashTable;
+
+/// Counts each word in `words`, incrementing its tally in `table`. Works with any
+/// `HashTable<String, usize>` implementation, so a CLI can count into a capacity-limited
+/// `LinkedHashTable` while a benchmark counts into the same table type under different load.
+///
+/// `on_progress`, if given, is called after every word with `(words_counted, total_words)`.
+pub fn count_words<T>(words: Vec<String>, table: &mut T, mut on_progress: Option<&mut dyn FnMut(u64, u64)>)
+where
+    T: HashTable<String, usize>,
+{
+    let total = words.len() as u64;
+    for (index, word) in words.into_iter().enumerate() {
+        let count = match table.get(&word) {
+            Some(&count) => count + 1,
+            None => 1,
+        };
+        table.insert(word, count);
+        if let Some(on_progress) = on_progress.as_mut() {
+            on_progress((index + 1) as u64, total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hash_table::LinkedHashTable;
+
+    #[test]
+    fn reports_progress_after_every_word() {
+        let mut table = LinkedHashTable::new(4);
+        let mut seen = Vec::new();
+        count_words(
+            vec!["a".into(), "b".into()],
+            &mut table,
+            Some(&mut |current, total| seen.push((current, total))),
+        );
+
+        assert_eq!(seen, vec![(1, 2), (2, 2)]);
+    }
+}
@@ -0,0 +1,83 @@
+//! Converts this crate's frequency results into Polars `DataFrame`s (the `polars` feature), so
+//! analysts can join, filter, and plot counts in a notebook without going through an
+//! intermediate CSV export. Gated behind the `polars` feature so callers that don't want the
+//! dependency don't pay for it.
+
+use polars::prelude::*;
+
+use crate::report::FrequencyStats;
+
+/// Converts a `(word, count)` frequency table (e.g. from [`crate::build_frequency_table`]) into
+/// a two-column `DataFrame`: `word` and `count`.
+pub fn frequency_table_to_dataframe(table: &[(String, usize)]) -> PolarsResult<DataFrame> {
+    let words: Vec<&str> = table.iter().map(|(word, _)| word.as_str()).collect();
+    let counts: Vec<u64> = table.iter().map(|(_, count)| *count as u64).collect();
+
+    df! {
+        "word" => words,
+        "count" => counts,
+    }
+}
+
+/// Converts corpus-level [`FrequencyStats`] into a one-row `DataFrame`: `zipf_slope` and
+/// `hapax_legomena`. Pair with [`percentile_cutoffs_to_dataframe`] for the percentile breakdown.
+pub fn stats_to_dataframe(stats: &FrequencyStats) -> PolarsResult<DataFrame> {
+    df! {
+        "zipf_slope" => [stats.zipf_slope],
+        "hapax_legomena" => [stats.hapax_legomena as u64],
+    }
+}
+
+/// Converts `stats.percentile_cutoffs` into a two-column `DataFrame`: `percentile` and
+/// `word_count` (how many of the most frequent words are needed to cover that percentage of all
+/// tokens).
+pub fn percentile_cutoffs_to_dataframe(stats: &FrequencyStats) -> PolarsResult<DataFrame> {
+    let percentiles: Vec<u32> = stats.percentile_cutoffs.iter().map(|(percentile, _)| *percentile as u32).collect();
+    let word_counts: Vec<u64> = stats.percentile_cutoffs.iter().map(|(_, count)| *count as u64).collect();
+
+    df! {
+        "percentile" => percentiles,
+        "word_count" => word_counts,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frequency_table_to_dataframe_has_one_row_per_word() {
+        let table = vec![("the".to_string(), 10), ("a".to_string(), 8)];
+        let frame = frequency_table_to_dataframe(&table).unwrap();
+
+        assert_eq!(frame.height(), 2);
+        assert_eq!(frame.column("word").unwrap().str().unwrap().get(0), Some("the"));
+        assert_eq!(frame.column("count").unwrap().u64().unwrap().get(0), Some(10));
+    }
+
+    #[test]
+    fn stats_to_dataframe_has_a_single_row() {
+        let stats = FrequencyStats {
+            zipf_slope: -0.95,
+            hapax_legomena: 3,
+            percentile_cutoffs: vec![(50, 2), (90, 5)],
+        };
+        let frame = stats_to_dataframe(&stats).unwrap();
+
+        assert_eq!(frame.height(), 1);
+        assert_eq!(frame.column("hapax_legomena").unwrap().u64().unwrap().get(0), Some(3));
+    }
+
+    #[test]
+    fn percentile_cutoffs_to_dataframe_has_one_row_per_cutoff() {
+        let stats = FrequencyStats {
+            zipf_slope: -0.95,
+            hapax_legomena: 3,
+            percentile_cutoffs: vec![(50, 2), (90, 5)],
+        };
+        let frame = percentile_cutoffs_to_dataframe(&stats).unwrap();
+
+        assert_eq!(frame.height(), 2);
+        assert_eq!(frame.column("percentile").unwrap().u32().unwrap().get(1), Some(90));
+    }
+}
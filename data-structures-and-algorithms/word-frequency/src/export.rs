@@ -0,0 +1,95 @@
+//! Writing a full frequency table to disk.
+
+use crate::report::normalize_weight;
+
+/// The on-disk format for a full frequency table export (`--export-all` on the CLI).
+/// Mirrors the CLI's `OutputFormat`, variant for variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ExportFormat {
+    #[default]
+    Text,
+    Json,
+    Wordcloud,
+}
+
+/// Writes the full `(word, count)` table to `path` in the given format, one entry at a
+/// time through a `BufWriter` so the formatted output is never buffered in memory all at once.
+pub fn export_frequency_table(path: &str, table: &[(String, usize)], format: ExportFormat) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let file = std::fs::File::create(path)?;
+    let mut writer = std::io::BufWriter::new(file);
+
+    match format {
+        ExportFormat::Json => {
+            writer.write_all(b"[")?;
+            for (i, (word, count)) in table.iter().enumerate() {
+                if i > 0 {
+                    writer.write_all(b",")?;
+                }
+                write!(writer, "{{\"word\":\"{}\",\"count\":{}}}", json_escape(word), count)?;
+            }
+            writer.write_all(b"]")?;
+        }
+        ExportFormat::Text => {
+            for (word, count) in table {
+                writeln!(writer, "{word}\t{count}")?;
+            }
+        }
+        ExportFormat::Wordcloud => {
+            let max_count = table.iter().map(|(_, count)| *count).max().unwrap_or(1);
+            for (word, count) in table {
+                writeln!(writer, "{word} {}", normalize_weight(*count, max_count))?;
+            }
+        }
+    }
+
+    writer.flush()
+}
+
+/// Escapes a string for embedding in the hand-rolled JSON this crate emits (`--format json`
+/// errors, `ExportFormat::Json`); that's the only JSON this crate produces, so a full
+/// serializer dependency isn't warranted.
+pub fn json_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_escape_handles_quotes_and_control_characters() {
+        assert_eq!(json_escape("a\"b\nc"), "a\\\"b\\nc");
+    }
+
+    #[test]
+    fn export_frequency_table_writes_tab_separated_text() {
+        let dir = std::env::temp_dir().join(format!("word-frequency-export-test-{:?}", std::thread::current().id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("out.txt");
+
+        export_frequency_table(
+            path.to_str().unwrap(),
+            &[("a".to_string(), 2), ("b".to_string(), 1)],
+            ExportFormat::Text,
+        )
+        .unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents, "a\t2\nb\t1\n");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,37 @@
+//! Counting, reporting, and exporting logic for word-frequency analysis, factored out of the
+//! `word-frequency` binary so other programs (a TUI, a long-running service) can reuse it
+//! without going through the CLI.
+//!
+//! `main.rs` keeps everything that's specific to being a command-line tool: argument parsing,
+//! config files, the on-disk result cache, progress bars, and colorized/chart terminal output.
+
+mod benchmark;
+mod counting;
+#[cfg(feature = "polars")]
+pub mod dataframe;
+mod export;
+mod report;
+#[cfg(feature = "storage")]
+pub mod storage;
+mod timing;
+
+pub use benchmark::{BenchmarkRun, run_benchmark};
+pub use counting::count_words;
+pub use export::{ExportFormat, export_frequency_table, json_escape};
+pub use report::{FrequencyStats, build_frequency_table, compute_frequency_stats, normalize_weight, render_chart};
+pub use timing::{PhaseTiming, TimingAccumulator};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hash_table::{HashTable, LinkedHashTable};
+
+    #[test]
+    fn count_words_tallies_repeated_words() {
+        let mut table = LinkedHashTable::new(4);
+        count_words(vec!["a".into(), "b".into(), "a".into()], &mut table, None);
+
+        assert_eq!(table.get(&"a".to_string()), Some(&2));
+        assert_eq!(table.get(&"b".to_string()), Some(&1));
+    }
+}
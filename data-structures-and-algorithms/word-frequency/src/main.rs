@@ -3,11 +3,148 @@
 //! This is a command-line tool that combines the hash-table and word-processor libraries
 //! to analyze word frequencies in text from files or URLs.
 
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use hash_table::{HashTable, LinkedHashTable};
+use log::{debug, info};
 use std::error::Error;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::path::PathBuf;
 use std::str::FromStr;
-use word_processor::{EstimationMethod, WordProcessorConfig, estimate_capacity, io, parse_text};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use word_frequency::{
+    ExportFormat, PhaseTiming, TimingAccumulator, build_frequency_table, compute_frequency_stats,
+    count_words, export_frequency_table, json_escape, normalize_weight, render_chart, run_benchmark,
+};
+use word_processor::{
+    EstimationMethod, WordProcessorConfig, estimate_capacity, io, parse_text_with_progress,
+};
+
+/// Broad failure categories this tool can report, each mapped to a distinct process
+/// exit code so scripts wrapping it can branch on failure kind without parsing error text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ErrorKind {
+    /// Reading/writing a local file failed
+    Io,
+    /// Fetching an input from a URL failed
+    Fetch,
+    /// Hash table capacity estimation failed (e.g. empty text, invalid factor)
+    Capacity,
+    /// The fixed-size hash table ran out of room for new keys
+    TableFull,
+    /// A config file (`--config`) couldn't be read or had no applicable values
+    Config,
+    /// Anything else (bad CLI arguments, invalid regex, etc.)
+    Other,
+}
+
+impl ErrorKind {
+    fn exit_code(self) -> u8 {
+        match self {
+            ErrorKind::Other => 1,
+            ErrorKind::Io => 2,
+            ErrorKind::Fetch => 3,
+            ErrorKind::Capacity => 4,
+            ErrorKind::TableFull => 5,
+            ErrorKind::Config => 6,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            ErrorKind::Io => "io",
+            ErrorKind::Fetch => "fetch",
+            ErrorKind::Capacity => "capacity",
+            ErrorKind::TableFull => "table-full",
+            ErrorKind::Config => "config",
+            ErrorKind::Other => "error",
+        }
+    }
+}
+
+/// This binary's error type: a classified, `Send`-safe error carrying enough information
+/// to pick an exit code and render a `--format json` error object. Replaces `Box<dyn Error>`
+/// so classification survives crossing the worker threads in `process_inputs_parallel`.
+#[derive(Debug, Clone)]
+struct CliError {
+    kind: ErrorKind,
+    message: String,
+}
+
+impl CliError {
+    fn new(kind: ErrorKind, message: impl Into<String>) -> Self {
+        Self { kind, message: message.into() }
+    }
+
+    /// Renders this error as a single-line JSON object for `--format json`.
+    fn to_json(&self) -> String {
+        format!(
+            r#"{{"error":"{}","kind":"{}","exit_code":{}}}"#,
+            json_escape(&self.message),
+            self.kind.as_str(),
+            self.kind.exit_code()
+        )
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl Error for CliError {}
+
+impl From<word_processor::WordProcessorError> for CliError {
+    fn from(err: word_processor::WordProcessorError) -> Self {
+        use word_processor::WordProcessorError;
+        use word_processor::error::IoError;
+        let kind = match &err {
+            WordProcessorError::Io(IoError::UrlFetchError(_)) => ErrorKind::Fetch,
+            WordProcessorError::Io(_) => ErrorKind::Io,
+            WordProcessorError::Capacity(_) => ErrorKind::Capacity,
+            WordProcessorError::Parser(_) | WordProcessorError::Other(_) => ErrorKind::Other,
+        };
+        CliError::new(kind, err.to_string())
+    }
+}
+
+impl From<std::io::Error> for CliError {
+    fn from(err: std::io::Error) -> Self {
+        CliError::new(ErrorKind::Io, err.to_string())
+    }
+}
+
+/// Output format, used both for error reporting and for rendering the frequency report
+/// (on `analyze`) and `--export-all`: `text` (default, human-readable), `json` (a single-line
+/// error object for errors; an array of `{"word","count"}` objects for exports), or
+/// `wordcloud` (`word weight` pairs, weight normalized to 0-100, for word-cloud generators).
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Wordcloud,
+}
+
+impl From<OutputFormat> for ExportFormat {
+    fn from(format: OutputFormat) -> Self {
+        match format {
+            OutputFormat::Text => ExportFormat::Text,
+            OutputFormat::Json => ExportFormat::Json,
+            OutputFormat::Wordcloud => ExportFormat::Wordcloud,
+        }
+    }
+}
+
+/// Extra report sections that can be requested with `--report` on `analyze`
+/// (currently just one; more can be added as variants over time).
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+enum ReportKind {
+    /// Longest and shortest distinct words, with their counts
+    Lengths,
+}
 
 /// Capacity configuration for the hash table
 #[derive(Debug, Clone)]
@@ -63,7 +200,7 @@ impl std::fmt::Display for CapacityConfig {
     }
 }
 
-/// Command line arguments for the Word Frequency Counter application
+/// Word Frequency Counter command line interface.
 #[derive(Parser, Debug)]
 #[clap(
     name = "Word Frequency Counter",
@@ -71,87 +208,716 @@ impl std::fmt::Display for CapacityConfig {
     version = "1.0",
     about = "Analyzes word frequency in text using a hash table"
 )]
-struct Args {
-    /// Input source: file path or URL
-    #[clap(short, long, value_parser, required(true))]
-    input: String,
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
 
-    /// Hash table capacity: a number for fixed size, 'auto' for full analysis,
-    /// or 'sample:SIZE' for sampling-based estimation
-    #[clap(short, long, value_parser, default_value = "auto")]
-    capacity: CapacityConfig,
+    /// Increase logging verbosity (-v for debug, -vv for trace)
+    #[clap(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
 
-    /// Treat words as case-sensitive (default: true)
-    #[clap(long, action, default_value = "true")]
-    case_sensitive: bool,
+    /// Decrease logging verbosity (-q for errors only, -qq to silence everything). On
+    /// `analyze`, -q also suppresses the decorative first/last/sample-word output so only
+    /// the frequency report is printed, for use in scripts.
+    #[clap(short, long, action = clap::ArgAction::Count, global = true)]
+    quiet: u8,
 
-    /// Include numbers as words (default: false)
-    #[clap(long, action, default_value = "false")]
+    /// Disable the progress bar (it's also skipped automatically when not attached to a terminal)
+    #[clap(long, action, global = true)]
+    no_progress: bool,
+
+    /// Disable colorized output (also respected via the NO_COLOR env var, and skipped
+    /// automatically when not attached to a terminal)
+    #[clap(long, action, global = true)]
+    no_color: bool,
+
+    /// Load persistent defaults from a TOML config file; flags given on the command
+    /// line always take precedence over values from the file. Defaults to
+    /// $XDG_CONFIG_HOME/word-frequency/config.toml (or ~/.config/word-frequency/config.toml)
+    /// when present.
+    #[clap(long, value_parser, global = true)]
+    config: Option<String>,
+
+    /// Output format: 'text' (default), 'json', or 'wordcloud'. Selects the `--export-all`
+    /// format and, with 'wordcloud', the report layout; with 'json', errors are printed as a
+    /// single-line object with `error`, `kind`, and `exit_code` fields, for scripts
+    #[clap(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+    format: OutputFormat,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Count word frequencies in a single input (file path or URL)
+    Analyze(AnalyzeArgs),
+
+    /// Compare word frequencies between two inputs
+    Compare(CompareArgs),
+}
+
+/// Arguments shared by both subcommands for controlling how text is tokenized.
+#[derive(clap::Args, Debug, Clone)]
+struct TokenizeArgs {
+    /// Treat words as case-insensitive (words are case-sensitive by default)
+    #[clap(long, action = clap::ArgAction::SetTrue)]
+    case_insensitive: bool,
+
+    /// Include numbers as words
+    #[clap(long, action = clap::ArgAction::SetTrue, overrides_with = "no_include_numbers")]
     include_numbers: bool,
 
-    /// Skip common stop words like "the", "a", "in" (default: false)
-    #[clap(long, action, default_value = "false")]
+    /// Exclude numbers as words (default)
+    #[clap(long, action = clap::ArgAction::SetTrue, hide = true)]
+    no_include_numbers: bool,
+
+    /// Skip common stop words like "the", "a", "in"
+    #[clap(long, action = clap::ArgAction::SetTrue, overrides_with = "no_skip_stop_words")]
     skip_stop_words: bool,
 
+    /// Keep stop words (default)
+    #[clap(long, action = clap::ArgAction::SetTrue, hide = true)]
+    no_skip_stop_words: bool,
+
     /// Strip punctuation from words (default: false)
     #[clap(long, action, default_value = "false")]
     keep_punctuation: bool,
 
-    /// Multiplier for capacity estimation
-    #[clap(long, default_value = "1.5", value_parser)]
-    capacity_factor: f32,
+    /// Load a custom stop-word list from PATH (one word per line) instead of using
+    /// the built-in English list. Only takes effect with --skip-stop-words.
+    #[clap(long, value_parser)]
+    stop_words: Option<String>,
+
+    /// Custom regex defining what counts as a word, instead of the default
+    /// whitespace-split tokenizer
+    #[clap(long, value_parser)]
+    pattern: Option<String>,
+
+    /// Strip the Project Gutenberg license header/footer before counting, so
+    /// boilerplate text doesn't skew the word frequencies
+    #[clap(long, action)]
+    strip_gutenberg: bool,
+
+    /// Drop words shorter than this many characters (e.g. "a", "I")
+    #[clap(long, value_parser)]
+    min_length: Option<usize>,
+
+    /// Drop words longer than this many characters (useful for OCR garbage)
+    #[clap(long, value_parser)]
+    max_length: Option<usize>,
 }
 
-fn main() -> Result<(), Box<dyn Error>> {
-    // Parse command line arguments
-    let args = Args::parse();
-
-    println!("Configuration:");
-    println!("  Input source: {}", args.input);
-    println!("  Hash table capacity: {}", args.capacity);
-    println!("  Capacity factor: {}", args.capacity_factor);
-    println!("  Case sensitive: {}", args.case_sensitive);
-    println!("  Include numbers: {}", args.include_numbers);
-    println!("  Skip stop words: {}", args.skip_stop_words);
-    println!("  Keep punctuation: {}", args.keep_punctuation);
-
-    println!("\nReading from: {}", args.input);
-    let text = if args.input.starts_with("http://") || args.input.starts_with("https://") {
-        io::fetch_from_url(&args.input)?
+impl TokenizeArgs {
+    /// Builds a `WordProcessorConfig` from these CLI flags, falling back to `file` for
+    /// any flag the user didn't pass explicitly. CLI flags always win: a negating flag
+    /// like `--no-include-numbers` overrides a config file's `include_numbers = true`.
+    fn to_config(&self, file: &FileConfig) -> Result<WordProcessorConfig, CliError> {
+        let case_insensitive = self.case_insensitive || file.case_insensitive.unwrap_or(false);
+        let include_numbers =
+            self.include_numbers || (file.include_numbers.unwrap_or(false) && !self.no_include_numbers);
+        let skip_stop_words =
+            self.skip_stop_words || (file.skip_stop_words.unwrap_or(false) && !self.no_skip_stop_words);
+        let keep_punctuation = self.keep_punctuation || file.keep_punctuation.unwrap_or(false);
+
+        let mut config = WordProcessorConfig::default()
+            .case_sensitive(!case_insensitive)
+            .include_numbers(include_numbers)
+            .skip_stop_words(skip_stop_words)
+            .strip_punctuation(!keep_punctuation);
+
+        if let Some(pattern) = self.pattern.clone().or_else(|| file.pattern.clone()) {
+            config = config.custom_pattern(pattern);
+        }
+
+        if let Some(path) = self.stop_words.clone().or_else(|| file.stop_words.clone()) {
+            let contents = io::read_from_file(&path)?;
+            let words = contents.lines().map(|line| line.trim().to_lowercase()).filter(|line| !line.is_empty());
+            config = config.custom_stop_words(words);
+        }
+
+        if let Some(min_length) = self.min_length.or(file.min_length) {
+            config = config.min_length(min_length);
+        }
+
+        if let Some(max_length) = self.max_length.or(file.max_length) {
+            config = config.max_length(max_length);
+        }
+
+        Ok(config)
+    }
+}
+
+/// Persistent defaults loaded from a config file (see `Cli::config` / `default_config_path`),
+/// applied wherever the corresponding CLI flag wasn't given explicitly.
+#[derive(Debug, Default)]
+struct FileConfig {
+    case_insensitive: Option<bool>,
+    include_numbers: Option<bool>,
+    skip_stop_words: Option<bool>,
+    keep_punctuation: Option<bool>,
+    pattern: Option<String>,
+    stop_words: Option<String>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    max_chars: Option<usize>,
+    jobs: Option<usize>,
+    capacity: Option<String>,
+    capacity_factor: Option<f32>,
+    min_count: Option<usize>,
+    top: Option<usize>,
+}
+
+impl FileConfig {
+    /// Parses a flat subset of TOML: `key = value` lines, `#` comments, and blank lines.
+    /// Covers the handful of scalar settings this tool persists without pulling in a
+    /// full TOML parser dependency.
+    fn parse(contents: &str) -> Self {
+        let mut file_config = FileConfig::default();
+        for line in contents.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let value = value.trim().trim_matches('"');
+            match key.trim() {
+                "case_insensitive" => file_config.case_insensitive = value.parse().ok(),
+                "include_numbers" => file_config.include_numbers = value.parse().ok(),
+                "skip_stop_words" => file_config.skip_stop_words = value.parse().ok(),
+                "keep_punctuation" => file_config.keep_punctuation = value.parse().ok(),
+                "pattern" => file_config.pattern = Some(value.to_string()),
+                "stop_words" => file_config.stop_words = Some(value.to_string()),
+                "min_length" => file_config.min_length = value.parse().ok(),
+                "max_length" => file_config.max_length = value.parse().ok(),
+                "max_chars" => file_config.max_chars = value.parse().ok(),
+                "jobs" => file_config.jobs = value.parse().ok(),
+                "capacity" => file_config.capacity = Some(value.to_string()),
+                "capacity_factor" => file_config.capacity_factor = value.parse().ok(),
+                "min_count" => file_config.min_count = value.parse().ok(),
+                "top" => file_config.top = value.parse().ok(),
+                _ => {}
+            }
+        }
+        file_config
+    }
+}
+
+/// Default config file location, honoring XDG_CONFIG_HOME:
+/// `$XDG_CONFIG_HOME/word-frequency/config.toml`, or `~/.config/word-frequency/config.toml`.
+fn default_config_path() -> PathBuf {
+    if let Some(xdg_config) = std::env::var_os("XDG_CONFIG_HOME") {
+        PathBuf::from(xdg_config).join("word-frequency").join("config.toml")
+    } else if let Some(home) = std::env::var_os("HOME") {
+        PathBuf::from(home).join(".config").join("word-frequency").join("config.toml")
     } else {
-        io::read_from_file(&args.input)?
+        PathBuf::from("word-frequency.toml")
+    }
+}
+
+/// Loads the config file at `explicit_path`, or the XDG default location if it exists
+/// and no explicit path was given. Missing files are not an error unless `explicit_path`
+/// was given and doesn't exist.
+fn load_file_config(explicit_path: Option<&str>) -> Result<FileConfig, CliError> {
+    let path = explicit_path.map(PathBuf::from).unwrap_or_else(default_config_path);
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => {
+            debug!("Loaded config file: {}", path.display());
+            Ok(FileConfig::parse(&contents))
+        }
+        Err(_) if explicit_path.is_none() => Ok(FileConfig::default()),
+        Err(err) => Err(CliError::new(
+            ErrorKind::Config,
+            format!("failed to read config file '{}': {err}", path.display()),
+        )),
+    }
+}
+
+/// Arguments shared by both subcommands for controlling result caching.
+#[derive(clap::Args, Debug)]
+struct CacheArgs {
+    /// Don't read from or write to the on-disk result cache
+    #[clap(long, action)]
+    no_cache: bool,
+}
+
+/// Arguments for the `analyze` subcommand.
+#[derive(clap::Args, Debug)]
+struct AnalyzeArgs {
+    /// Input source(s): file path(s) or URL(s). When more than one is given, they
+    /// are read and tokenized concurrently and their frequency tables are merged.
+    #[clap(short, long, value_parser, required(true), num_args = 1..)]
+    input: Vec<String>,
+
+    /// Number of worker threads to use when processing multiple inputs
+    /// (default: number of available CPUs)
+    #[clap(short, long, value_parser)]
+    jobs: Option<usize>,
+
+    /// Only process the first N characters of each input (after Gutenberg boilerplate
+    /// stripping, if enabled), to preview results on huge inputs quickly
+    #[clap(long, value_parser)]
+    max_chars: Option<usize>,
+
+    /// Hash table capacity: a number for fixed size, 'auto' for full analysis,
+    /// or 'sample:SIZE' for sampling-based estimation (default: auto)
+    #[clap(short, long, value_parser)]
+    capacity: Option<CapacityConfig>,
+
+    #[command(flatten)]
+    tokenize: TokenizeArgs,
+
+    /// Multiplier for capacity estimation (default: 1.5)
+    #[clap(long, value_parser)]
+    capacity_factor: Option<f32>,
+
+    /// Exclude words appearing fewer than this many times from the frequency report
+    /// (default: 1)
+    #[clap(long, value_parser)]
+    min_count: Option<usize>,
+
+    /// Number of entries to show in the frequency report (default: 20)
+    #[clap(long, value_parser)]
+    top: Option<usize>,
+
+    /// Word to exclude from the frequency report, without affecting total word
+    /// counts or hash table capacity; may be given multiple times
+    #[clap(long, value_parser)]
+    exclude: Vec<String>,
+
+    /// File of words (one per line) to exclude from the frequency report, same as `--exclude`
+    #[clap(long, value_parser)]
+    exclude_file: Option<String>,
+
+    /// Render the top words as an ASCII bar chart scaled to terminal width
+    #[clap(long, action)]
+    chart: bool,
+
+    /// Print the hash table's load factor, probe-length stats, and memory usage
+    #[clap(long, action)]
+    table_stats: bool,
+
+    /// Print the first `--top` words straight from the hash table, in insertion/update order
+    /// (i.e. `LinkedHashTable::iter()`'s order), instead of sorted by count
+    #[clap(long, action)]
+    insertion_order: bool,
+
+    /// Print a Zipf's-law slope, hapax legomena count, and percentile cut-offs
+    /// computed from the frequency table
+    #[clap(long, action)]
+    stats: bool,
+
+    /// Include an extra report section; currently only 'lengths' (longest/shortest
+    /// distinct words) is supported
+    #[clap(long, value_enum)]
+    report: Option<ReportKind>,
+
+    /// Write the entire (word, count) table -- not just the top N -- to PATH in the
+    /// format selected by --format, streamed to disk to avoid buffering huge vocabularies
+    #[clap(long, value_parser)]
+    export_all: Option<String>,
+
+    /// Count the same words with both LinkedHashTable and std::HashMap, reporting wall
+    /// time, probe stats, and memory usage side by side
+    #[clap(long, action)]
+    benchmark: bool,
+
+    /// Print per-phase timings (fetch, parse, capacity estimation, counting, reporting)
+    /// with throughput, measured the same way as binance-options-client's ParsingMetrics
+    #[clap(long, action)]
+    timings: bool,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+}
+
+/// Arguments for the `compare` subcommand.
+#[derive(clap::Args, Debug)]
+struct CompareArgs {
+    /// First input source: file path or URL
+    first: String,
+
+    /// Second input source: file path or URL
+    second: String,
+
+    #[command(flatten)]
+    tokenize: TokenizeArgs,
+
+    /// Number of entries to show in the "biggest relative frequency differences" report
+    /// (default: 20)
+    #[clap(long, value_parser)]
+    top: Option<usize>,
+
+    #[command(flatten)]
+    cache: CacheArgs,
+}
+
+/// Default worker count for `--jobs`: the number of available CPUs, or 1 if it can't be determined.
+fn default_jobs() -> usize {
+    std::thread::available_parallelism()
+        .map(|n| n.get())
+        .unwrap_or(1)
+}
+
+/// Builds a progress bar for a phase, or a hidden one if progress bars are disabled.
+fn make_progress_bar(enabled: bool, total: Option<u64>) -> indicatif::ProgressBar {
+    if !enabled {
+        return indicatif::ProgressBar::hidden();
+    }
+
+    let bar = match total {
+        Some(len) => indicatif::ProgressBar::new(len),
+        None => indicatif::ProgressBar::new_spinner(),
     };
+    bar.set_style(
+        indicatif::ProgressStyle::with_template("{msg} [{bar:40.cyan/blue}] {pos}/{len} ({eta})")
+            .unwrap_or_else(|_| indicatif::ProgressStyle::default_bar())
+            .progress_chars("=>-"),
+    );
+    bar
+}
+
+/// Decides whether to emit ANSI color codes: respects `--no-color`, the `NO_COLOR`
+/// env var convention, and whether stdout is actually a terminal.
+fn color_enabled(no_color_flag: bool) -> bool {
+    use std::io::IsTerminal;
+    !no_color_flag && std::env::var_os("NO_COLOR").is_none() && std::io::stdout().is_terminal()
+}
+
+/// Wraps `text` in the given ANSI SGR `code` when `enabled`, otherwise returns it unchanged.
+fn colorize(enabled: bool, code: &str, text: &str) -> String {
+    if enabled {
+        format!("\x1b[{code}m{text}\x1b[0m")
+    } else {
+        text.to_string()
+    }
+}
+
+/// Derives the log level from the net effect of `-v`/`-q` flags, defaulting to `Info`.
+fn log_level_filter(verbose: u8, quiet: u8) -> log::LevelFilter {
+    use log::LevelFilter::*;
+
+    let net = verbose as i16 - quiet as i16;
+    match net {
+        ..=-2 => Off,
+        -1 => Error,
+        0 => Info,
+        1 => Debug,
+        _ => Trace,
+    }
+}
+
+/// Directory holding cached (text, words) results, honoring XDG_CACHE_HOME.
+fn cache_dir() -> PathBuf {
+    if let Some(xdg_cache) = std::env::var_os("XDG_CACHE_HOME") {
+        PathBuf::from(xdg_cache).join("word-frequency")
+    } else if let Some(home) = std::env::var_os("HOME") {
+        PathBuf::from(home).join(".cache").join("word-frequency")
+    } else {
+        std::env::temp_dir().join("word-frequency-cache")
+    }
+}
+
+/// Builds a cache key from the input source (plus its file mtime/size, if local) and
+/// the tokenization settings that affect the resulting word list.
+fn cache_key(
+    input: &str,
+    config: &WordProcessorConfig,
+    strip_gutenberg: bool,
+    max_chars: Option<usize>,
+) -> String {
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    input.hash(&mut hasher);
+    strip_gutenberg.hash(&mut hasher);
+    max_chars.hash(&mut hasher);
+    if let Ok(metadata) = std::fs::metadata(input) {
+        metadata.len().hash(&mut hasher);
+        if let Ok(modified) = metadata.modified() {
+            modified.hash(&mut hasher);
+        }
+    }
+    config.case_sensitive.hash(&mut hasher);
+    config.include_numbers.hash(&mut hasher);
+    config.strip_punctuation.hash(&mut hasher);
+    config.skip_stop_words.hash(&mut hasher);
+    config.custom_pattern.hash(&mut hasher);
+    config.capacity_factor.to_bits().hash(&mut hasher);
+    config.min_length.hash(&mut hasher);
+    config.max_length.hash(&mut hasher);
+    if let Some(custom_stop_words) = &config.custom_stop_words {
+        let mut sorted: Vec<&String> = custom_stop_words.iter().collect();
+        sorted.sort();
+        sorted.hash(&mut hasher);
+    }
+    format!("{:016x}", hasher.finish())
+}
 
-    let word_processor_config = WordProcessorConfig::default()
-        .case_sensitive(args.case_sensitive)
-        .include_numbers(args.include_numbers)
-        .skip_stop_words(args.skip_stop_words)
-        .strip_punctuation(!args.keep_punctuation)
-        .capacity_factor(args.capacity_factor);
+/// Loads a previously cached `(text, words)` pair for `input`, if present.
+fn load_cached_input(
+    input: &str,
+    config: &WordProcessorConfig,
+    strip_gutenberg: bool,
+    max_chars: Option<usize>,
+) -> Option<(String, Vec<String>)> {
+    let dir = cache_dir();
+    let key = cache_key(input, config, strip_gutenberg, max_chars);
+    let text = std::fs::read_to_string(dir.join(format!("{key}.text"))).ok()?;
+    let words_blob = std::fs::read_to_string(dir.join(format!("{key}.words"))).ok()?;
+    let words = words_blob.lines().map(String::from).collect();
+    Some((text, words))
+}
 
-    // Parse text into words
-    println!("Parsing text into words...");
-    let words = parse_text(&text, &word_processor_config);
-    println!("Found {} words in total", words.len());
+/// Best-effort write of `(text, words)` to the on-disk cache for `input`. Failures
+/// (e.g. a read-only cache dir) are silently ignored, since caching is an optimization.
+fn store_cached_input(
+    input: &str,
+    config: &WordProcessorConfig,
+    strip_gutenberg: bool,
+    max_chars: Option<usize>,
+    text: &str,
+    words: &[String],
+) {
+    let dir = cache_dir();
+    if std::fs::create_dir_all(&dir).is_err() {
+        return;
+    }
+    let key = cache_key(input, config, strip_gutenberg, max_chars);
+    let _ = std::fs::write(dir.join(format!("{key}.text")), text);
+    let _ = std::fs::write(dir.join(format!("{key}.words")), words.join("\n"));
+}
 
-    println!("Determining required hash table capacity...");
-    let capacity = match args.capacity {
+/// Reads and tokenizes `input`, transparently using the on-disk cache unless `no_cache`.
+fn read_and_parse_cached(
+    input: &str,
+    config: &WordProcessorConfig,
+    strip_gutenberg: bool,
+    max_chars: Option<usize>,
+    no_cache: bool,
+    show_progress: bool,
+    timings: Option<&TimingAccumulator>,
+) -> Result<(String, Vec<String>), CliError> {
+    if !no_cache {
+        if let Some(cached) = load_cached_input(input, config, strip_gutenberg, max_chars) {
+            debug!("Cache hit for '{}'", input);
+            return Ok(cached);
+        }
+    }
+
+    let fetch_start = std::time::Instant::now();
+    let text = read_input(input, show_progress)?;
+    if let Some(timings) = timings {
+        timings
+            .fetch_nanos
+            .fetch_add(fetch_start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    }
+    let text = if strip_gutenberg {
+        io::strip_gutenberg_boilerplate(&text)
+    } else {
+        text
+    };
+    let text = match max_chars {
+        Some(max_chars) => truncate_chars(&text, max_chars),
+        None => text,
+    };
+    let parse_start = std::time::Instant::now();
+    let words = parse_words(&text, config, show_progress)?;
+    if let Some(timings) = timings {
+        timings
+            .parse_nanos
+            .fetch_add(parse_start.elapsed().as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    if !no_cache {
+        store_cached_input(input, config, strip_gutenberg, max_chars, &text, &words);
+    }
+
+    Ok((text, words))
+}
+
+/// Truncates `text` to at most `max_chars` characters, respecting UTF-8 character boundaries.
+fn truncate_chars(text: &str, max_chars: usize) -> String {
+    text.chars().take(max_chars).collect()
+}
+
+/// Reads text from a file path or URL, reporting download progress for URLs.
+fn read_input(source: &str, show_progress: bool) -> Result<String, CliError> {
+    info!("Reading from: {}", source);
+    if source.starts_with("http://") || source.starts_with("https://") {
+        let download_bar = make_progress_bar(show_progress, None);
+        download_bar.set_message("Downloading");
+        let text = io::fetch_from_url_with_progress(
+            source,
+            Some(&mut |_phase, current, total| {
+                if let Some(total) = total {
+                    download_bar.set_length(total);
+                }
+                download_bar.set_position(current);
+            }),
+        )?;
+        download_bar.finish_and_clear();
+        Ok(text)
+    } else {
+        Ok(io::read_from_file(source)?)
+    }
+}
+
+/// Parses `text` into words, showing a progress bar over the tokenization pass.
+fn parse_words(
+    text: &str,
+    config: &WordProcessorConfig,
+    show_progress: bool,
+) -> Result<Vec<String>, CliError> {
+    let parse_bar = make_progress_bar(show_progress, None);
+    parse_bar.set_message("Parsing");
+    let words = parse_text_with_progress(
+        text,
+        config,
+        Some(&mut |_phase, current, total| {
+            if let Some(total) = total {
+                parse_bar.set_length(total);
+            }
+            parse_bar.set_position(current);
+        }),
+    )?;
+    parse_bar.finish_and_clear();
+    Ok(words)
+}
+
+/// One `(input, text, words)` triple produced by [`process_inputs_parallel`].
+type ProcessedInput = (String, String, Vec<String>);
+
+/// Per-input knobs shared by every worker thread in [`process_inputs_parallel`], bundled up so
+/// the function itself doesn't have to take them one by one.
+struct InputProcessingOptions {
+    strip_gutenberg: bool,
+    max_chars: Option<usize>,
+    no_cache: bool,
+    show_progress: bool,
+}
+
+/// Reads and tokenizes `inputs` across up to `jobs` worker threads, returning
+/// `(input, text, words)` triples in the original input order.
+fn process_inputs_parallel(
+    inputs: &[String],
+    config: &WordProcessorConfig,
+    options: &InputProcessingOptions,
+    jobs: usize,
+    timings: Option<&TimingAccumulator>,
+) -> Result<Vec<ProcessedInput>, CliError> {
+    let worker_count = jobs.max(1).min(inputs.len().max(1));
+    let next_index = AtomicUsize::new(0);
+    let results: Mutex<Vec<Option<Result<ProcessedInput, CliError>>>> =
+        Mutex::new((0..inputs.len()).map(|_| None).collect());
+
+    std::thread::scope(|scope| {
+        for _ in 0..worker_count {
+            scope.spawn(|| {
+                loop {
+                    let index = next_index.fetch_add(1, Ordering::SeqCst);
+                    if index >= inputs.len() {
+                        break;
+                    }
+                    let outcome = read_and_parse_cached(
+                        &inputs[index],
+                        config,
+                        options.strip_gutenberg,
+                        options.max_chars,
+                        options.no_cache,
+                        options.show_progress,
+                        timings,
+                    )
+                    .map(|(text, words)| (inputs[index].clone(), text, words));
+                    results.lock().unwrap()[index] = Some(outcome);
+                }
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|slot| slot.expect("every input is claimed exactly once"))
+        .collect()
+}
+
+fn run_analyze(
+    args: AnalyzeArgs,
+    file_config: &FileConfig,
+    format: OutputFormat,
+    show_progress: bool,
+    color: bool,
+    quiet: bool,
+) -> Result<(), CliError> {
+    let jobs = args.jobs.or(file_config.jobs).unwrap_or_else(default_jobs);
+    let capacity = args
+        .capacity
+        .or_else(|| file_config.capacity.as_deref().and_then(|s| s.parse().ok()))
+        .unwrap_or(CapacityConfig::Auto);
+    let capacity_factor = args.capacity_factor.or(file_config.capacity_factor).unwrap_or(1.5);
+    let min_count = args.min_count.or(file_config.min_count).unwrap_or(1);
+    let top = args.top.or(file_config.top).unwrap_or(20);
+    let max_chars = args.max_chars.or(file_config.max_chars);
+
+    let word_processor_config = args.tokenize.to_config(file_config)?.capacity_factor(capacity_factor);
+
+    info!("Configuration:");
+    info!("  Input source(s): {}", args.input.join(", "));
+    info!("  Jobs: {}", jobs);
+    info!("  Hash table capacity: {}", capacity);
+    info!("  Capacity factor: {}", capacity_factor);
+    info!("  Case sensitive: {}", word_processor_config.case_sensitive);
+    info!("  Include numbers: {}", word_processor_config.include_numbers);
+    info!("  Skip stop words: {}", word_processor_config.skip_stop_words);
+    info!("  Keep punctuation: {}", !word_processor_config.strip_punctuation);
+
+    // Read and tokenize every input, across up to `jobs` worker threads when
+    // more than one input is given.
+    let per_input_progress = show_progress && args.input.len() == 1;
+    let timing_accumulator = args.timings.then(TimingAccumulator::default);
+    let processed = process_inputs_parallel(
+        &args.input,
+        &word_processor_config,
+        &InputProcessingOptions {
+            strip_gutenberg: args.tokenize.strip_gutenberg,
+            max_chars,
+            no_cache: args.cache.no_cache,
+            show_progress: per_input_progress,
+        },
+        jobs,
+        timing_accumulator.as_ref(),
+    )?;
+
+    let text = processed
+        .iter()
+        .map(|(_, text, _)| text.as_str())
+        .collect::<Vec<_>>()
+        .join("\n");
+    let words: Vec<String> = processed
+        .into_iter()
+        .flat_map(|(_, _, words)| words)
+        .collect();
+    let total_words = words.len();
+    info!("Found {} words in total", total_words);
+
+    debug!("Determining required hash table capacity...");
+    let capacity_start = std::time::Instant::now();
+    let capacity = match capacity {
         CapacityConfig::Fixed(size) => {
-            println!("Using fixed capacity: {}", size);
+            debug!("Using fixed capacity: {}", size);
             size
         }
         CapacityConfig::Auto => {
-            println!("Calculating capacity using full text analysis...");
+            debug!("Calculating capacity using full text analysis...");
             let capacity = estimate_capacity(
                 &text,
                 &word_processor_config,
                 EstimationMethod::FullAnalysis,
             )?;
-            println!("Calculated capacity needed: {}", capacity);
+            debug!("Calculated capacity needed: {}", capacity);
             capacity
         }
         CapacityConfig::Sampling(sample_size) => {
-            println!(
+            debug!(
                 "Estimating capacity using sampling ({} chars)...",
                 sample_size
             );
@@ -160,42 +926,456 @@ fn main() -> Result<(), Box<dyn Error>> {
                 &word_processor_config,
                 EstimationMethod::Sampling(sample_size),
             )?;
-            println!("Estimated capacity: {}", estimated);
+            debug!("Estimated capacity: {}", estimated);
             estimated
         }
     };
+    let capacity_timing = PhaseTiming::new("capacity estimation", capacity_start.elapsed(), total_words);
+
+    debug!("Building frequency report...");
+    let frequency_table = build_frequency_table(&words);
+
+    if let Some(path) = &args.export_all {
+        debug!("Exporting full frequency table to '{}'...", path);
+        export_frequency_table(path, &frequency_table, format.into())?;
+    }
+
+    // The table is fixed-size: check it can actually hold every unique word up front,
+    // rather than discovering this mid-count (the underlying open-addressing table isn't
+    // safe to probe for an absent key once it's 100% full).
+    if frequency_table.len() > capacity {
+        return Err(CliError::new(
+            ErrorKind::TableFull,
+            format!(
+                "hash table capacity ({capacity}) is too small for {} unique words; increase --capacity or --capacity-factor",
+                frequency_table.len()
+            ),
+        ));
+    }
 
-    println!("Creating hash table...");
+    if args.benchmark {
+        debug!("Running benchmark...");
+        let (linked_run, std_run) = run_benchmark(&words, capacity);
+        println!("\nBenchmark (LinkedHashTable vs. std::HashMap, {total_words} words):");
+        println!(
+            "  LinkedHashTable: {:>8.3?} | {} entries | ~{} bytes | avg probe {:.2}",
+            linked_run.duration, linked_run.entries, linked_run.memory_bytes, linked_run.average_probe_length
+        );
+        println!(
+            "  std::HashMap:    {:>8.3?} | {} entries | ~{} bytes",
+            std_run.duration, std_run.entries, std_run.memory_bytes
+        );
+    }
+
+    debug!("Creating hash table...");
     let mut hash_table = LinkedHashTable::new(capacity);
 
     // Count word frequencies
-    println!("Counting word frequencies...");
-    for word in words {
-        let count = match hash_table.get(&word) {
-            Some(&count) => count + 1,
-            None => 1,
-        };
-        hash_table.insert(word, count);
-    }
+    debug!("Counting word frequencies...");
+    let counting_start = std::time::Instant::now();
+    let count_bar = make_progress_bar(show_progress, Some(words.len() as u64));
+    count_bar.set_message("Counting");
+    count_words(
+        words,
+        &mut hash_table,
+        Some(&mut |current, _total| count_bar.set_position(current)),
+    );
+    count_bar.finish_and_clear();
+    let counting_timing = PhaseTiming::new("counting", counting_start.elapsed(), total_words);
 
-    // Show first and last processed words (demonstrating O(1) operations)
-    if let Some((word, count)) = hash_table.get_first() {
-        println!("\nFirst processed word: '{}' (count: {})", word, count);
+    if args.table_stats {
+        let stats = hash_table.stats();
+        println!("\nHash table stats:");
+        println!("  Capacity: {}", stats.capacity);
+        println!("  Entries: {}", stats.len);
+        println!("  Tombstones: {}", stats.tombstones);
+        println!("  Load factor: {:.2}%", stats.load_factor * 100.0);
+        println!("  Average probe length: {:.2}", stats.average_probe_length);
+        println!("  Max probe length: {}", stats.max_probe_length);
+        println!("  Memory usage: {} bytes", stats.memory_bytes);
     }
-    if let Some((word, count)) = hash_table.get_last() {
+
+    if args.stats {
+        let stats = compute_frequency_stats(&frequency_table, total_words);
+        println!("\nFrequency stats:");
+        println!("  Zipf rank-frequency slope: {:.3}", stats.zipf_slope);
         println!(
-            "Most recently processed word: '{}' (count: {})",
-            word, count
+            "  Hapax legomena (words occurring once): {}",
+            stats.hapax_legomena
         );
+        for (percentile, words_needed) in &stats.percentile_cutoffs {
+            println!(
+                "  Top {} words cover {}% of all tokens",
+                words_needed, percentile
+            );
+        }
+    }
+
+    if args.insertion_order {
+        println!("\nFirst {top} words in insertion/update order:");
+        for (word, count) in hash_table.iter().take(top) {
+            println!("  {word}: {count}");
+        }
+    }
+
+    // Show first and last processed words (demonstrating O(1) operations). Skipped in
+    // --quiet mode, which prints only the final report for use in scripts.
+    if !quiet {
+        if let Some((word, count)) = hash_table.get_first() {
+            println!(
+                "\nFirst processed word: '{}' (count: {})",
+                colorize(color, "36", word),
+                colorize(color, "32", &count.to_string())
+            );
+        }
+        if let Some((word, count)) = hash_table.get_last() {
+            println!(
+                "Most recently processed word: '{}' (count: {})",
+                colorize(color, "36", word),
+                colorize(color, "32", &count.to_string())
+            );
+        }
+    }
+
+    // A 100%-full table (no empty slots or tombstones left) isn't safe to probe for an
+    // absent key (see the preflight check above), so skip these demo lookups in that case.
+    let table_is_full = {
+        let stats = hash_table.stats();
+        stats.len >= stats.capacity
+    };
+    if !quiet && !table_is_full {
+        println!("\nChecking specific words:");
+        if let Some(&count) = hash_table.get(&"Cities,".into()) {
+            println!(
+                "'{}' appears {} times",
+                colorize(color, "36", "Cities"),
+                colorize(color, "32", &count.to_string())
+            );
+        }
+        if let Some(&count) = hash_table.get(&"eBooks".into()) {
+            println!(
+                "'{}' appears {} times",
+                colorize(color, "36", "eBooks"),
+                colorize(color, "32", &count.to_string())
+            );
+        }
+    }
+
+    let mut exclude_words: std::collections::HashSet<String> =
+        args.exclude.iter().map(|word| word.to_lowercase()).collect();
+    if let Some(path) = &args.exclude_file {
+        let contents = io::read_from_file(path)?;
+        exclude_words.extend(contents.lines().map(|line| line.trim().to_lowercase()).filter(|line| !line.is_empty()));
+    }
+
+    let top_entries: Vec<(String, usize)> = frequency_table
+        .iter()
+        .filter(|(_, count)| *count >= min_count)
+        .filter(|(word, _)| !exclude_words.contains(&word.to_lowercase()))
+        .take(top)
+        .cloned()
+        .collect();
+
+    let reporting_start = std::time::Instant::now();
+    println!("\nTop {} words (min count: {}):", top, min_count);
+    if args.chart {
+        let width = terminal_size::terminal_size()
+            .map(|(terminal_size::Width(w), _)| w as usize)
+            .unwrap_or(80);
+        print!("{}", render_chart(&top_entries, width));
+    } else if format == OutputFormat::Wordcloud {
+        let max_count = top_entries.iter().map(|(_, count)| *count).max().unwrap_or(1);
+        for (word, count) in &top_entries {
+            println!("{word} {}", normalize_weight(*count, max_count));
+        }
+    } else {
+        let rank_width = top_entries.len().to_string().len();
+        let word_width = top_entries.iter().map(|(w, _)| w.chars().count()).max().unwrap_or(0);
+        for (rank, (word, count)) in top_entries.iter().enumerate() {
+            let rank_str = colorize(color, "1;33", &format!("{:>rank_width$}.", rank + 1));
+            let count_str = colorize(color, "32", &count.to_string());
+            println!(
+                "{} {:<word_width$} {} occurrences",
+                rank_str, word, count_str
+            );
+        }
+    }
+
+    if args.report == Some(ReportKind::Lengths) {
+        let filtered: Vec<(String, usize)> = frequency_table
+            .iter()
+            .filter(|(_, count)| *count >= min_count)
+            .filter(|(word, _)| !exclude_words.contains(&word.to_lowercase()))
+            .cloned()
+            .collect();
+
+        let mut by_length = filtered.clone();
+        by_length.sort_by(|a, b| b.0.chars().count().cmp(&a.0.chars().count()).then_with(|| a.0.cmp(&b.0)));
+        let longest: Vec<(String, usize)> = by_length.iter().take(top).cloned().collect();
+
+        by_length.sort_by(|a, b| a.0.chars().count().cmp(&b.0.chars().count()).then_with(|| a.0.cmp(&b.0)));
+        let shortest: Vec<(String, usize)> = by_length.into_iter().take(top).collect();
+
+        println!("\nLongest words:");
+        for (rank, (word, count)) in longest.iter().enumerate() {
+            println!(
+                "{}. '{}' ({} chars, {} occurrences)",
+                rank + 1,
+                colorize(color, "36", word),
+                word.chars().count(),
+                colorize(color, "32", &count.to_string())
+            );
+        }
+
+        println!("\nShortest words:");
+        for (rank, (word, count)) in shortest.iter().enumerate() {
+            println!(
+                "{}. '{}' ({} chars, {} occurrences)",
+                rank + 1,
+                colorize(color, "36", word),
+                word.chars().count(),
+                colorize(color, "32", &count.to_string())
+            );
+        }
     }
+    let reporting_timing = PhaseTiming::new("reporting", reporting_start.elapsed(), top_entries.len());
+
+    if let Some(timing_accumulator) = &timing_accumulator {
+        let fetch_timing =
+            PhaseTiming::from_nanos("fetch", timing_accumulator.fetch_nanos.load(Ordering::Relaxed), text.len());
+        let parse_timing =
+            PhaseTiming::from_nanos("parse", timing_accumulator.parse_nanos.load(Ordering::Relaxed), total_words);
 
-    println!("\nChecking specific words:");
-    if let Some(&count) = hash_table.get(&"Cities,".into()) {
-        println!("'Cities' appears {} times", count);
+        println!("\nTimings:");
+        for timing in [
+            &fetch_timing,
+            &parse_timing,
+            &capacity_timing,
+            &counting_timing,
+            &reporting_timing,
+        ] {
+            println!(
+                "  {:<20} {:>10.3} ms | {:>8} items | {:.6} ms/item",
+                format!("{}:", timing.phase),
+                timing.total_time_ms,
+                timing.items,
+                timing.time_per_item_ms
+            );
+        }
     }
-    if let Some(&count) = hash_table.get(&"eBooks".into()) {
-        println!("'eBooks' appears {} times", count);
+
+    Ok(())
+}
+
+fn run_compare(
+    args: CompareArgs,
+    file_config: &FileConfig,
+    show_progress: bool,
+    color: bool,
+) -> Result<(), CliError> {
+    let top = args.top.or(file_config.top).unwrap_or(20);
+    let config = args.tokenize.to_config(file_config)?;
+
+    let (_, first_words) = read_and_parse_cached(
+        &args.first,
+        &config,
+        args.tokenize.strip_gutenberg,
+        None,
+        args.cache.no_cache,
+        show_progress,
+        None,
+    )?;
+    let (_, second_words) = read_and_parse_cached(
+        &args.second,
+        &config,
+        args.tokenize.strip_gutenberg,
+        None,
+        args.cache.no_cache,
+        show_progress,
+        None,
+    )?;
+
+    let first_table = build_frequency_table(&first_words);
+    let second_table = build_frequency_table(&second_words);
+
+    let first_counts: std::collections::HashMap<&str, usize> = first_table
+        .iter()
+        .map(|(word, count)| (word.as_str(), *count))
+        .collect();
+    let second_counts: std::collections::HashMap<&str, usize> = second_table
+        .iter()
+        .map(|(word, count)| (word.as_str(), *count))
+        .collect();
+
+    let mut unique_to_first: Vec<&str> = first_counts
+        .keys()
+        .filter(|word| !second_counts.contains_key(*word))
+        .copied()
+        .collect();
+    unique_to_first.sort_unstable();
+
+    let mut unique_to_second: Vec<&str> = second_counts
+        .keys()
+        .filter(|word| !first_counts.contains_key(*word))
+        .copied()
+        .collect();
+    unique_to_second.sort_unstable();
+
+    let mut shared: Vec<&str> = first_counts
+        .keys()
+        .filter(|word| second_counts.contains_key(*word))
+        .copied()
+        .collect();
+    shared.sort_unstable();
+
+    println!("Comparing '{}' and '{}'", args.first, args.second);
+    println!(
+        "\nVocabulary: {} words only in '{}', {} words only in '{}', {} shared",
+        unique_to_first.len(),
+        args.first,
+        unique_to_second.len(),
+        args.second,
+        shared.len()
+    );
+
+    // Relative frequency (per 1000 words) makes differing document lengths comparable.
+    let first_total = first_words.len().max(1) as f64;
+    let second_total = second_words.len().max(1) as f64;
+
+    let mut differences: Vec<(&str, f64, f64, f64)> = shared
+        .iter()
+        .map(|&word| {
+            let first_rate = first_counts[word] as f64 / first_total * 1000.0;
+            let second_rate = second_counts[word] as f64 / second_total * 1000.0;
+            (word, first_rate, second_rate, (first_rate - second_rate).abs())
+        })
+        .collect();
+    differences.sort_by(|a, b| b.3.partial_cmp(&a.3).unwrap_or(std::cmp::Ordering::Equal));
+
+    println!("\nBiggest relative frequency differences (per 1000 words):");
+    let word_width = differences
+        .iter()
+        .take(top)
+        .map(|(word, ..)| word.chars().count())
+        .max()
+        .unwrap_or(0);
+    for (rank, (word, first_rate, second_rate, _)) in differences.iter().take(top).enumerate() {
+        let rank_str = colorize(color, "1;33", &format!("{}.", rank + 1));
+        println!(
+            "  {} {:<word_width$} {:.2} vs {:.2}",
+            rank_str, word, first_rate, second_rate
+        );
     }
 
     Ok(())
 }
+
+fn main() -> std::process::ExitCode {
+    // Parse command line arguments
+    let cli = Cli::parse();
+    let format = cli.format;
+
+    env_logger::Builder::new()
+        .filter_level(log_level_filter(cli.verbose, cli.quiet))
+        .format_timestamp(None)
+        .format_target(false)
+        .init();
+
+    let result = run(cli);
+
+    match result {
+        Ok(()) => std::process::ExitCode::SUCCESS,
+        Err(err) => {
+            match format {
+                OutputFormat::Json => eprintln!("{}", err.to_json()),
+                OutputFormat::Text | OutputFormat::Wordcloud => eprintln!("Error: {}", err),
+            }
+            std::process::ExitCode::from(err.kind.exit_code())
+        }
+    }
+}
+
+/// Runs the selected subcommand, after resolving the config file that pre-populates defaults.
+fn run(cli: Cli) -> Result<(), CliError> {
+    let file_config = load_file_config(cli.config.as_deref())?;
+    let show_progress = !cli.no_progress;
+    let color = color_enabled(cli.no_color);
+    let quiet = cli.quiet > 0;
+
+    match cli.command {
+        Command::Analyze(args) => run_analyze(args, &file_config, cli.format, show_progress, color, quiet),
+        Command::Compare(args) => run_compare(args, &file_config, show_progress, color),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_error_kind_maps_to_a_distinct_nonzero_exit_code() {
+        let kinds = [
+            ErrorKind::Io,
+            ErrorKind::Fetch,
+            ErrorKind::Capacity,
+            ErrorKind::TableFull,
+            ErrorKind::Config,
+            ErrorKind::Other,
+        ];
+
+        let codes: Vec<u8> = kinds.iter().map(|kind| kind.exit_code()).collect();
+        assert!(codes.iter().all(|code| *code != 0), "exit code 0 is reserved for success");
+
+        let mut unique_codes = codes.clone();
+        unique_codes.sort_unstable();
+        unique_codes.dedup();
+        assert_eq!(unique_codes.len(), codes.len(), "exit codes must be distinct so scripts can branch on them");
+    }
+
+    #[test]
+    fn to_json_embeds_the_kind_message_and_matching_exit_code() {
+        let err = CliError::new(ErrorKind::Fetch, "connection refused");
+        assert_eq!(
+            err.to_json(),
+            r#"{"error":"connection refused","kind":"fetch","exit_code":3}"#
+        );
+    }
+
+    #[test]
+    fn to_json_escapes_quotes_and_backslashes_in_the_message() {
+        let err = CliError::new(ErrorKind::Other, r#"bad input: "quoted" \ path"#);
+        assert_eq!(
+            err.to_json(),
+            r#"{"error":"bad input: \"quoted\" \\ path","kind":"error","exit_code":1}"#
+        );
+    }
+
+    #[test]
+    fn url_fetch_errors_classify_as_fetch_not_io() {
+        use word_processor::WordProcessorError;
+        use word_processor::error::IoError;
+
+        let err: CliError = WordProcessorError::Io(IoError::UrlFetchError("timed out".to_string())).into();
+        assert_eq!(err.kind, ErrorKind::Fetch);
+        assert_eq!(err.kind.exit_code(), 3);
+    }
+
+    #[test]
+    fn other_io_errors_classify_as_io() {
+        use word_processor::WordProcessorError;
+        use word_processor::error::IoError;
+        use std::io;
+
+        let err: CliError =
+            WordProcessorError::Io(IoError::FileReadError(io::Error::new(io::ErrorKind::NotFound, "missing"))).into();
+        assert_eq!(err.kind, ErrorKind::Io);
+        assert_eq!(err.kind.exit_code(), 2);
+    }
+
+    #[test]
+    fn std_io_errors_classify_as_io() {
+        let err: CliError = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied").into();
+        assert_eq!(err.kind, ErrorKind::Io);
+        assert_eq!(err.kind.exit_code(), 2);
+    }
+}
@@ -0,0 +1,126 @@
+//! Turning a frequency table into human- or machine-facing reports.
+
+/// Builds a descending-by-count frequency table from the parsed words, breaking ties
+/// alphabetically for stable output.
+pub fn build_frequency_table(words: &[String]) -> Vec<(String, usize)> {
+    let mut counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    for word in words {
+        *counts.entry(word.as_str()).or_insert(0) += 1;
+    }
+
+    let mut table: Vec<(String, usize)> =
+        counts.into_iter().map(|(word, count)| (word.to_string(), count)).collect();
+    table.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    table
+}
+
+/// Normalizes `count` to a 0-100 weight relative to `max_count`, for word-cloud output.
+pub fn normalize_weight(count: usize, max_count: usize) -> u32 {
+    if max_count == 0 {
+        return 0;
+    }
+    ((count as f64 / max_count as f64) * 100.0).round() as u32
+}
+
+/// Renders `entries` as a terminal bar chart, one bar per line, scaled so the longest
+/// bar (plus its label and count) fits within `terminal_width` columns.
+pub fn render_chart(entries: &[(String, usize)], terminal_width: usize) -> String {
+    let Some(max_count) = entries.iter().map(|(_, count)| *count).max() else {
+        return String::new();
+    };
+    let label_width = entries.iter().map(|(word, _)| word.len()).max().unwrap_or(0);
+    // Reserve space for "<label> | " and " (<count>)".
+    let reserved = label_width + 3 + 2 + max_count.to_string().len() + 2;
+    let max_bar_width = terminal_width.saturating_sub(reserved).max(1);
+
+    let mut output = String::new();
+    for (word, count) in entries {
+        let bar_len = ((*count as f64 / max_count as f64) * max_bar_width as f64).round() as usize;
+        let bar_len = bar_len.max(1);
+        output.push_str(&format!(
+            "{:>width$} | {} ({})\n",
+            word,
+            "#".repeat(bar_len),
+            count,
+            width = label_width
+        ));
+    }
+    output
+}
+
+/// Zipf/percentile statistics computed from a descending-by-count frequency table.
+#[derive(Debug)]
+pub struct FrequencyStats {
+    /// Least-squares slope of log(count) against log(rank); ideal Zipfian text is close to -1.
+    pub zipf_slope: f64,
+    /// Number of words that occur exactly once.
+    pub hapax_legomena: usize,
+    /// `(percentile, word_count)` pairs: how many of the most frequent words are needed
+    /// to cover that percentage of all tokens.
+    pub percentile_cutoffs: Vec<(u8, usize)>,
+}
+
+/// Computes Zipf's-law slope, hapax legomena count, and percentile cut-offs from a
+/// descending-by-count `frequency_table` covering `total_words` tokens in total.
+pub fn compute_frequency_stats(frequency_table: &[(String, usize)], total_words: usize) -> FrequencyStats {
+    // Least-squares fit of log(count) = slope * log(rank) + intercept.
+    let points: Vec<(f64, f64)> = frequency_table
+        .iter()
+        .enumerate()
+        .map(|(i, (_, count))| (((i + 1) as f64).ln(), (*count as f64).ln()))
+        .collect();
+    let n = points.len() as f64;
+    let zipf_slope = if points.len() < 2 {
+        0.0
+    } else {
+        let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+        let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+        let numerator: f64 = points.iter().map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        let denominator: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+        if denominator == 0.0 { 0.0 } else { numerator / denominator }
+    };
+
+    let hapax_legomena = frequency_table.iter().filter(|(_, count)| *count == 1).count();
+
+    let mut percentile_cutoffs = Vec::new();
+    for &percentile in &[50u8, 90u8] {
+        let target = (total_words as f64 * percentile as f64 / 100.0).ceil() as usize;
+        let mut cumulative = 0;
+        let mut words_needed = 0;
+        for (_, count) in frequency_table {
+            cumulative += count;
+            words_needed += 1;
+            if cumulative >= target {
+                break;
+            }
+        }
+        percentile_cutoffs.push((percentile, words_needed));
+    }
+
+    FrequencyStats {
+        zipf_slope,
+        hapax_legomena,
+        percentile_cutoffs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_frequency_table_sorts_by_count_then_alphabetically() {
+        let words: Vec<String> =
+            ["b", "a", "a", "c", "c", "c"].into_iter().map(String::from).collect();
+        assert_eq!(
+            build_frequency_table(&words),
+            vec![("c".to_string(), 3), ("a".to_string(), 2), ("b".to_string(), 1)]
+        );
+    }
+
+    #[test]
+    fn normalize_weight_scales_to_a_0_100_range() {
+        assert_eq!(normalize_weight(5, 10), 50);
+        assert_eq!(normalize_weight(0, 0), 0);
+    }
+}
@@ -0,0 +1,166 @@
+//! Optional SQLite persistence for analysis runs (the `storage` feature), so vocabulary and
+//! Zipf statistics can be compared across runs of the same source — e.g. chapter by chapter of
+//! the same book — instead of living only in one process's stdout. Gated behind the `storage`
+//! feature so callers that don't want the `rusqlite` dependency don't pay for it.
+
+use rusqlite::{Connection, params};
+
+use crate::report::FrequencyStats;
+
+/// One previously recorded analysis run, as stored by [`record_run`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredRun {
+    pub id: i64,
+    pub source: String,
+    pub recorded_at: String,
+    pub total_words: usize,
+    pub unique_words: usize,
+    pub zipf_slope: f64,
+    pub hapax_legomena: usize,
+}
+
+/// Opens (creating if needed) a SQLite database at `path` with the schema [`record_run`] and
+/// [`runs_for_source`] expect. Pass `":memory:"` for a throwaway in-process database.
+pub fn open(path: &str) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    init_schema(&conn)?;
+    Ok(conn)
+}
+
+fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS runs (
+            id INTEGER PRIMARY KEY,
+            source TEXT NOT NULL,
+            recorded_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+            total_words INTEGER NOT NULL,
+            unique_words INTEGER NOT NULL,
+            zipf_slope REAL NOT NULL,
+            hapax_legomena INTEGER NOT NULL
+        );
+        CREATE TABLE IF NOT EXISTS run_words (
+            run_id INTEGER NOT NULL REFERENCES runs(id),
+            word TEXT NOT NULL,
+            count INTEGER NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS run_words_run_id_idx ON run_words(run_id);
+        ",
+    )
+}
+
+/// Persists one analysis run — its vocabulary size and Zipf/hapax statistics, plus `top_words`
+/// (typically the same report the CLI's `--top N` already prints) for later inspection.
+///
+/// Returns the new run's id.
+pub fn record_run(
+    conn: &Connection,
+    source: &str,
+    total_words: usize,
+    unique_words: usize,
+    stats: &FrequencyStats,
+    top_words: &[(String, usize)],
+) -> rusqlite::Result<i64> {
+    conn.execute(
+        "INSERT INTO runs (source, total_words, unique_words, zipf_slope, hapax_legomena)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![
+            source,
+            total_words as i64,
+            unique_words as i64,
+            stats.zipf_slope,
+            stats.hapax_legomena as i64,
+        ],
+    )?;
+    let run_id = conn.last_insert_rowid();
+
+    let mut insert_word =
+        conn.prepare("INSERT INTO run_words (run_id, word, count) VALUES (?1, ?2, ?3)")?;
+    for (word, count) in top_words {
+        insert_word.execute(params![run_id, word, *count as i64])?;
+    }
+
+    Ok(run_id)
+}
+
+/// Every run recorded for `source`, oldest first — e.g. to chart vocabulary growth across
+/// chapters of the same book.
+pub fn runs_for_source(conn: &Connection, source: &str) -> rusqlite::Result<Vec<StoredRun>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, source, recorded_at, total_words, unique_words, zipf_slope, hapax_legomena
+         FROM runs WHERE source = ?1 ORDER BY id ASC",
+    )?;
+    stmt.query_map(params![source], |row| {
+        Ok(StoredRun {
+            id: row.get(0)?,
+            source: row.get(1)?,
+            recorded_at: row.get(2)?,
+            total_words: row.get::<_, i64>(3)? as usize,
+            unique_words: row.get::<_, i64>(4)? as usize,
+            zipf_slope: row.get(5)?,
+            hapax_legomena: row.get::<_, i64>(6)? as usize,
+        })
+    })?
+    .collect()
+}
+
+/// The top words recorded for a given run, in the order they were inserted (which `record_run`
+/// preserves from `top_words`, i.e. most frequent first).
+pub fn top_words_for_run(conn: &Connection, run_id: i64) -> rusqlite::Result<Vec<(String, usize)>> {
+    let mut stmt = conn.prepare("SELECT word, count FROM run_words WHERE run_id = ?1 ORDER BY rowid ASC")?;
+    stmt.query_map(params![run_id], |row| Ok((row.get(0)?, row.get::<_, i64>(1)? as usize)))?
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats() -> FrequencyStats {
+        FrequencyStats {
+            zipf_slope: -0.95,
+            hapax_legomena: 3,
+            percentile_cutoffs: vec![(50, 2), (90, 5)],
+        }
+    }
+
+    #[test]
+    fn a_recorded_run_can_be_read_back_by_source() {
+        let conn = open(":memory:").unwrap();
+        let top_words = vec![("the".to_string(), 10), ("a".to_string(), 8)];
+
+        record_run(&conn, "chapter1.txt", 100, 40, &stats(), &top_words).unwrap();
+        record_run(&conn, "chapter2.txt", 120, 50, &stats(), &top_words).unwrap();
+
+        let runs = runs_for_source(&conn, "chapter1.txt").unwrap();
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].source, "chapter1.txt");
+        assert_eq!(runs[0].total_words, 100);
+        assert_eq!(runs[0].unique_words, 40);
+    }
+
+    #[test]
+    fn runs_for_the_same_source_come_back_oldest_first() {
+        let conn = open(":memory:").unwrap();
+        let top_words: Vec<(String, usize)> = Vec::new();
+
+        record_run(&conn, "book.txt", 10, 5, &stats(), &top_words).unwrap();
+        record_run(&conn, "book.txt", 20, 8, &stats(), &top_words).unwrap();
+
+        let runs = runs_for_source(&conn, "book.txt").unwrap();
+        assert_eq!(runs.len(), 2);
+        assert!(runs[0].id < runs[1].id);
+        assert_eq!(runs[0].total_words, 10);
+        assert_eq!(runs[1].total_words, 20);
+    }
+
+    #[test]
+    fn top_words_are_returned_in_their_recorded_order() {
+        let conn = open(":memory:").unwrap();
+        let top_words = vec![("the".to_string(), 10), ("a".to_string(), 8)];
+
+        let run_id = record_run(&conn, "book.txt", 100, 40, &stats(), &top_words).unwrap();
+
+        assert_eq!(top_words_for_run(&conn, run_id).unwrap(), top_words);
+    }
+}
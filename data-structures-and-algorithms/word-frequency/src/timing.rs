@@ -0,0 +1,49 @@
+//! Per-phase timing, measured the same way as `binance-options-client`'s `ParsingMetrics`.
+
+use std::sync::atomic::AtomicU64;
+
+/// Timing for one phase of analysis: wall-clock duration in milliseconds plus a
+/// per-item throughput figure.
+pub struct PhaseTiming {
+    pub phase: &'static str,
+    pub total_time_ms: f64,
+    pub items: usize,
+    pub time_per_item_ms: f64,
+}
+
+impl PhaseTiming {
+    pub fn new(phase: &'static str, duration: std::time::Duration, items: usize) -> Self {
+        let total_time_ms = duration.as_secs_f64() * 1000.0;
+        let item_count = items.max(1);
+        PhaseTiming {
+            phase,
+            total_time_ms,
+            items,
+            time_per_item_ms: total_time_ms / item_count as f64,
+        }
+    }
+
+    pub fn from_nanos(phase: &'static str, nanos: u64, items: usize) -> Self {
+        Self::new(phase, std::time::Duration::from_nanos(nanos), items)
+    }
+}
+
+/// Accumulates per-phase wall time across worker threads, since fetching and parsing can
+/// happen concurrently, one pair of phases per input.
+#[derive(Default)]
+pub struct TimingAccumulator {
+    pub fetch_nanos: AtomicU64,
+    pub parse_nanos: AtomicU64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phase_timing_computes_per_item_time() {
+        let timing = PhaseTiming::new("counting", std::time::Duration::from_millis(100), 10);
+        assert_eq!(timing.total_time_ms, 100.0);
+        assert_eq!(timing.time_per_item_ms, 10.0);
+    }
+}
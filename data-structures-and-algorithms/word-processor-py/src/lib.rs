@@ -0,0 +1,119 @@
+//! Python bindings (via PyO3) for `word-processor`'s tokenizer and capacity estimator, plus
+//! `word-frequency`'s counter, so data-science users can reuse the fast Rust tokenizer from a
+//! notebook instead of re-implementing it in Python.
+//!
+//! Built as a `cdylib` for `pip install` via maturin; the `rlib` crate type is kept alongside it
+//! so `cargo test` can still exercise this crate directly.
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use word_processor::{EstimationMethod, WordProcessorConfig, estimate_capacity, parse_text};
+
+/// Mirrors [`WordProcessorConfig`], exposed to Python as an object that notebooks build with
+/// keyword arguments and pass to [`tokenize`] or [`estimate_table_capacity`]. Its settings are
+/// fixed at construction; no getters or setters are exposed.
+#[pyclass(name = "WordProcessorConfig")]
+#[derive(Clone)]
+struct PyWordProcessorConfig {
+    inner: WordProcessorConfig,
+}
+
+#[pymethods]
+impl PyWordProcessorConfig {
+    #[new]
+    #[pyo3(signature = (
+        case_sensitive = true,
+        include_numbers = false,
+        custom_pattern = None,
+        strip_punctuation = true,
+        skip_stop_words = false,
+        capacity_factor = 1.5,
+        min_length = None,
+        max_length = None,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        case_sensitive: bool,
+        include_numbers: bool,
+        custom_pattern: Option<String>,
+        strip_punctuation: bool,
+        skip_stop_words: bool,
+        capacity_factor: f32,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+    ) -> Self {
+        Self {
+            inner: WordProcessorConfig {
+                case_sensitive,
+                include_numbers,
+                custom_pattern,
+                strip_punctuation,
+                skip_stop_words,
+                custom_stop_words: None,
+                capacity_factor,
+                min_length,
+                max_length,
+            },
+        }
+    }
+}
+
+fn config_or_default(config: Option<&PyWordProcessorConfig>) -> WordProcessorConfig {
+    config.map(|c| c.inner.clone()).unwrap_or_default()
+}
+
+/// Tokenizes `text` into words according to `config` (or the default config if omitted).
+#[pyfunction]
+#[pyo3(signature = (text, config = None))]
+fn tokenize(text: &str, config: Option<&PyWordProcessorConfig>) -> PyResult<Vec<String>> {
+    parse_text(text, &config_or_default(config)).map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Estimates the hash table capacity needed to hold every unique word in `text`. Pass
+/// `sample_size` to estimate from a character sample instead of analyzing the full text.
+#[pyfunction]
+#[pyo3(signature = (text, config = None, sample_size = None))]
+fn estimate_table_capacity(
+    text: &str,
+    config: Option<&PyWordProcessorConfig>,
+    sample_size: Option<usize>,
+) -> PyResult<usize> {
+    let method = match sample_size {
+        Some(size) => EstimationMethod::Sampling(size),
+        None => EstimationMethod::FullAnalysis,
+    };
+    estimate_capacity(text, &config_or_default(config), method)
+        .map_err(|err| PyValueError::new_err(err.to_string()))
+}
+
+/// Tokenizes `text` and tallies occurrences of each word, returning a `{word: count}` dict.
+/// Internally counts into a [`hash_table::LinkedHashTable`] sized via [`estimate_capacity`],
+/// matching how the `word-frequency` CLI counts the same text.
+#[pyfunction]
+#[pyo3(signature = (text, config = None))]
+fn count_word_frequencies(
+    text: &str,
+    config: Option<&PyWordProcessorConfig>,
+) -> PyResult<std::collections::HashMap<String, usize>> {
+    let config = config_or_default(config);
+    let words = parse_text(text, &config).map_err(|err| PyValueError::new_err(err.to_string()))?;
+    let capacity = estimate_capacity(text, &config, EstimationMethod::FullAnalysis)
+        .map_err(|err| PyValueError::new_err(err.to_string()))?;
+
+    let mut table = hash_table::LinkedHashTable::new(capacity.max(1));
+    word_frequency::count_words(words, &mut table, None);
+
+    Ok(hash_table::HashTable::iter(&table)
+        .map(|(word, count)| (word.clone(), *count))
+        .collect())
+}
+
+#[pymodule]
+fn word_processor_py(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyWordProcessorConfig>()?;
+    m.add_function(wrap_pyfunction!(tokenize, m)?)?;
+    m.add_function(wrap_pyfunction!(estimate_table_capacity, m)?)?;
+    m.add_function(wrap_pyfunction!(count_word_frequencies, m)?)?;
+    Ok(())
+}
@@ -0,0 +1,127 @@
+//! `wasm-bindgen` bindings for `word-processor`'s tokenizer and `word-frequency`'s reporting
+//! logic, so a static web page can run frequency analysis on pasted or uploaded text entirely
+//! client-side, without a server round-trip.
+//!
+//! Built as a `cdylib` for `wasm-pack build --target web`; the `rlib` crate type is kept
+//! alongside it so `cargo test` can still exercise this crate directly.
+
+use wasm_bindgen::prelude::*;
+
+use word_frequency::{build_frequency_table, compute_frequency_stats};
+use word_processor::{WordProcessorConfig, parse_text};
+
+/// Mirrors [`WordProcessorConfig`], exposed to JS as a mutable object so a page can build one
+/// with its form fields before calling [`parse`], [`top_n`], or [`analyze`].
+#[wasm_bindgen]
+#[derive(Clone)]
+pub struct WasmWordProcessorConfig {
+    inner: WordProcessorConfig,
+}
+
+#[wasm_bindgen]
+impl WasmWordProcessorConfig {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        Self {
+            inner: WordProcessorConfig::default(),
+        }
+    }
+
+    #[wasm_bindgen(js_name = caseSensitive)]
+    pub fn set_case_sensitive(&mut self, value: bool) {
+        self.inner.case_sensitive = value;
+    }
+
+    #[wasm_bindgen(js_name = includeNumbers)]
+    pub fn set_include_numbers(&mut self, value: bool) {
+        self.inner.include_numbers = value;
+    }
+
+    #[wasm_bindgen(js_name = stripPunctuation)]
+    pub fn set_strip_punctuation(&mut self, value: bool) {
+        self.inner.strip_punctuation = value;
+    }
+
+    #[wasm_bindgen(js_name = skipStopWords)]
+    pub fn set_skip_stop_words(&mut self, value: bool) {
+        self.inner.skip_stop_words = value;
+    }
+
+    #[wasm_bindgen(js_name = minLength)]
+    pub fn set_min_length(&mut self, value: Option<usize>) {
+        self.inner.min_length = value;
+    }
+
+    #[wasm_bindgen(js_name = maxLength)]
+    pub fn set_max_length(&mut self, value: Option<usize>) {
+        self.inner.max_length = value;
+    }
+}
+
+impl Default for WasmWordProcessorConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn config_or_default(config: Option<&WasmWordProcessorConfig>) -> WordProcessorConfig {
+    config.map(|c| c.inner.clone()).unwrap_or_default()
+}
+
+/// One `(word, count)` entry of a frequency table, exposed to JS with `word`/`count` getters.
+#[wasm_bindgen(getter_with_clone)]
+#[derive(Clone)]
+pub struct WordCount {
+    pub word: String,
+    pub count: usize,
+}
+
+/// The results of [`analyze`]: vocabulary size, Zipf/hapax statistics, and the top words.
+#[wasm_bindgen(getter_with_clone)]
+pub struct AnalysisResult {
+    pub total_words: usize,
+    pub unique_words: usize,
+    pub zipf_slope: f64,
+    pub hapax_legomena: usize,
+    pub top_words: Vec<WordCount>,
+}
+
+/// Tokenizes `text` into words according to `config` (or the default config if omitted).
+#[wasm_bindgen]
+pub fn parse(text: &str, config: Option<WasmWordProcessorConfig>) -> Result<Vec<String>, JsValue> {
+    parse_text(text, &config_or_default(config.as_ref())).map_err(|err| JsValue::from_str(&err.to_string()))
+}
+
+/// Tokenizes `text` and returns its `n` most frequent words, descending by count.
+#[wasm_bindgen(js_name = topN)]
+pub fn top_n(text: &str, n: usize, config: Option<WasmWordProcessorConfig>) -> Result<Vec<WordCount>, JsValue> {
+    let words = parse_text(text, &config_or_default(config.as_ref())).map_err(|err| JsValue::from_str(&err.to_string()))?;
+    Ok(build_frequency_table(&words)
+        .into_iter()
+        .take(n)
+        .map(|(word, count)| WordCount { word, count })
+        .collect())
+}
+
+/// Tokenizes `text` and computes vocabulary size plus Zipf/hapax statistics, along with its
+/// `top_n` most frequent words.
+#[wasm_bindgen]
+pub fn analyze(text: &str, top_n: usize, config: Option<WasmWordProcessorConfig>) -> Result<AnalysisResult, JsValue> {
+    let words = parse_text(text, &config_or_default(config.as_ref())).map_err(|err| JsValue::from_str(&err.to_string()))?;
+    let total_words = words.len();
+    let table = build_frequency_table(&words);
+    let unique_words = table.len();
+    let stats = compute_frequency_stats(&table, total_words);
+
+    Ok(AnalysisResult {
+        total_words,
+        unique_words,
+        zipf_slope: stats.zipf_slope,
+        hapax_legomena: stats.hapax_legomena,
+        top_words: table
+            .into_iter()
+            .take(top_n)
+            .map(|(word, count)| WordCount { word, count })
+            .collect(),
+    })
+}
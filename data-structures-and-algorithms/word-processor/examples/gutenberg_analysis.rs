@@ -25,7 +25,7 @@ fn analyze_text(text: &str) -> Result<WordStats, WordProcessorError> {
 
     println!("Parsing text into words...");
     // Parse the text into words
-    let words = parse_text(text, &config);
+    let words = parse_text(text, &config)?;
 
     println!("Calculating unique words...");
     // Count word frequencies
@@ -20,7 +20,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         .strip_punctuation(true); // Remove surrounding punctuation
 
     println!("Parsing text into words...");
-    let words = parse_text(&text, &config);
+    let words = parse_text(&text, &config)?;
     println!("Found {} total words", words.len());
 
     // Count word frequencies
@@ -92,17 +92,20 @@ pub fn estimate_capacity(
 
     // Perform estimation using the selected method
     match method {
-        EstimationMethod::FullAnalysis => Ok(estimate_capacity_full(text, config)),
+        EstimationMethod::FullAnalysis => estimate_capacity_full(text, config),
         EstimationMethod::Sampling(sample_size) => {
-            Ok(estimate_capacity_sample(text, config, sample_size))
+            estimate_capacity_sample(text, config, sample_size)
         }
     }
 }
 
 /// Estimates capacity by analyzing the full text
-fn estimate_capacity_full(text: &str, config: &WordProcessorConfig) -> usize {
+fn estimate_capacity_full(
+    text: &str,
+    config: &WordProcessorConfig,
+) -> Result<usize, WordProcessorError> {
     // Parse the entire text to get actual words according to config
-    let words = parser::parse_text(text, config);
+    let words = parser::parse_text(text, config)?;
 
     // Count unique words
     let unique_words = count_unique_words(&words);
@@ -111,23 +114,27 @@ fn estimate_capacity_full(text: &str, config: &WordProcessorConfig) -> usize {
     let capacity = (unique_words as f32 * config.capacity_factor).ceil() as usize;
 
     // Ensure we return at least 1
-    capacity.max(1)
+    Ok(capacity.max(1))
 }
 
 /// Estimates capacity by analyzing a sample of the text
-fn estimate_capacity_sample(text: &str, config: &WordProcessorConfig, sample_size: usize) -> usize {
+fn estimate_capacity_sample(
+    text: &str,
+    config: &WordProcessorConfig,
+    sample_size: usize,
+) -> Result<usize, WordProcessorError> {
     // Get a representative sample of the text
     let sample = get_text_sample(text, sample_size);
 
     // Parse the sample to get words
-    let sample_words = parser::parse_text(&sample, config);
+    let sample_words = parser::parse_text(&sample, config)?;
 
     // Count unique words in the sample
     let unique_words_in_sample = count_unique_words(&sample_words);
 
     // If sample has no words, return minimum capacity
     if sample_words.is_empty() {
-        return 1;
+        return Ok(1);
     }
 
     // Calculate unique-to-total word ratio in the sample
@@ -144,7 +151,7 @@ fn estimate_capacity_sample(text: &str, config: &WordProcessorConfig, sample_siz
     let capacity = (estimated_unique_words * config.capacity_factor).ceil() as usize;
 
     // Ensure we return at least 1
-    capacity.max(1)
+    Ok(capacity.max(1))
 }
 
 /// Takes a sample of the given text for analysis
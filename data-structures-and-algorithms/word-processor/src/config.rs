@@ -21,10 +21,20 @@ pub struct WordProcessorConfig {
     /// Whether to skip stop words. Default: false.
     pub skip_stop_words: bool,
 
+    /// Custom stop word list to use instead of the built-in English list.
+    /// Only consulted when `skip_stop_words` is true. Default: None (use the built-in list).
+    pub custom_stop_words: Option<std::collections::HashSet<String>>,
+
     /// A factor to compute the hash table capacity:
     /// capacity = (word_count * capacity_factor).ceil().
     /// Default = 1.5
     pub capacity_factor: f32,
+
+    /// If set, drop words shorter than this many characters. Default: None (no minimum).
+    pub min_length: Option<usize>,
+
+    /// If set, drop words longer than this many characters. Default: None (no maximum).
+    pub max_length: Option<usize>,
 }
 
 impl Default for WordProcessorConfig {
@@ -35,7 +45,10 @@ impl Default for WordProcessorConfig {
             custom_pattern: None,
             strip_punctuation: true,
             skip_stop_words: false,
+            custom_stop_words: None,
             capacity_factor: 1.5,
+            min_length: None,
+            max_length: None,
         }
     }
 }
@@ -73,9 +86,27 @@ impl WordProcessorConfig {
         self
     }
 
+    /// Use a custom stop word list instead of the built-in English list
+    pub fn custom_stop_words(mut self, words: impl IntoIterator<Item = String>) -> Self {
+        self.custom_stop_words = Some(words.into_iter().collect());
+        self
+    }
+
     /// Set the capacity factor for hash table sizing
     pub fn capacity_factor(mut self, value: f32) -> Self {
         self.capacity_factor = value;
         self
     }
+
+    /// Set the minimum word length to keep (inclusive)
+    pub fn min_length(mut self, value: usize) -> Self {
+        self.min_length = Some(value);
+        self
+    }
+
+    /// Set the maximum word length to keep (inclusive)
+    pub fn max_length(mut self, value: usize) -> Self {
+        self.max_length = Some(value);
+        self
+    }
 }
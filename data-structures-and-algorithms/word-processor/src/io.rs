@@ -70,20 +70,56 @@ pub fn fetch_gutenberg_book() -> Result<String, WordProcessorError> {
 /// Requires the "url" feature to be enabled.
 #[cfg(feature = "url")]
 pub fn fetch_from_url(url: &str) -> Result<String, WordProcessorError> {
-    // When the "url" feature is enabled, this function will use reqwest
-    // to fetch the content from the given URL
+    fetch_from_url_with_progress(url, None)
+}
+
+/// Fetches text from a URL, optionally reporting download progress.
+///
+/// # Arguments
+/// * `url` - The URL to fetch text from
+/// * `on_progress` - Called periodically with `(Phase::Download, bytes_read, content_length)`.
+///   `content_length` is `None` if the server didn't send a `Content-Length` header.
+///
+/// # Returns
+/// The fetched content as a String, or an error if fetching fails
+///
+/// # Note
+/// Requires the "url" feature to be enabled.
+#[cfg(feature = "url")]
+pub fn fetch_from_url_with_progress(
+    url: &str,
+    mut on_progress: Option<&mut crate::progress::ProgressCallback<'_>>,
+) -> Result<String, WordProcessorError> {
+    use crate::progress::Phase;
     use reqwest::blocking::Client;
+    use std::io::Read;
 
     let client = Client::new();
-    client
+    let mut response = client
         .get(url)
         .send()
-        .map_err(|err| IoError::UrlFetchError(err.to_string()).into())
-        .and_then(|response| {
-            response
-                .text()
-                .map_err(|err| IoError::UrlFetchError(err.to_string()).into())
-        })
+        .map_err(|err| IoError::UrlFetchError(err.to_string()))?;
+
+    let total = response.content_length();
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 8192];
+    let mut downloaded = 0u64;
+
+    loop {
+        let n = response
+            .read(&mut chunk)
+            .map_err(|err| IoError::UrlFetchError(err.to_string()))?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        downloaded += n as u64;
+        if let Some(callback) = on_progress.as_deref_mut() {
+            callback(Phase::Download, downloaded, total);
+        }
+    }
+
+    String::from_utf8(buf).map_err(|err| IoError::UrlFetchError(err.to_string()).into())
 }
 
 /// Not-enabled placeholder for fetch_from_url when the "url" feature is disabled.
@@ -98,6 +134,70 @@ pub fn fetch_from_url(_url: &str) -> Result<String, WordProcessorError> {
     .into())
 }
 
+/// Not-enabled placeholder for fetch_from_url_with_progress when the "url" feature is disabled.
+///
+/// # Returns
+/// Always returns an error indicating the feature is not enabled.
+#[cfg(not(feature = "url"))]
+pub fn fetch_from_url_with_progress(
+    _url: &str,
+    _on_progress: Option<&mut crate::progress::ProgressCallback<'_>>,
+) -> Result<String, WordProcessorError> {
+    Err(IoError::UrlFetchError(
+        "URL feature not enabled. Add the 'url' feature to Cargo.toml".to_string(),
+    )
+    .into())
+}
+
+/// Strips Project Gutenberg license boilerplate from the given text.
+///
+/// Project Gutenberg texts wrap the actual book content between a header and
+/// footer of the form `*** START OF ... PROJECT GUTENBERG EBOOK ... ***` and
+/// `*** END OF ... PROJECT GUTENBERG EBOOK ... ***` (the marker text varies in
+/// case and wording across older and newer releases). This function locates
+/// those markers and returns only the text between them.
+///
+/// If no markers are found, the original text is returned unchanged.
+///
+/// # Arguments
+/// * `text` - The raw text, possibly wrapped in Project Gutenberg boilerplate
+///
+/// # Returns
+/// The text with the header and footer boilerplate removed, if present
+pub fn strip_gutenberg_boilerplate(text: &str) -> String {
+    let is_marker = |line: &str, keyword: &str| {
+        let upper = line.to_uppercase();
+        upper.contains(keyword) && upper.contains("PROJECT GUTENBERG")
+    };
+
+    let start = text
+        .lines()
+        .position(|line| is_marker(line, "START OF"))
+        .map(|line_index| {
+            text.lines()
+                .take(line_index + 1)
+                .map(|line| line.len() + 1)
+                .sum::<usize>()
+        });
+
+    let end = text
+        .lines()
+        .position(|line| is_marker(line, "END OF"))
+        .map(|line_index| {
+            text.lines()
+                .take(line_index)
+                .map(|line| line.len() + 1)
+                .sum::<usize>()
+        });
+
+    match (start, end) {
+        (Some(start), Some(end)) if start <= end && end <= text.len() => {
+            text[start..end].trim().to_string()
+        }
+        _ => text.to_string(),
+    }
+}
+
 /// Reads text from a string path, file path, or URL.
 ///
 /// This function is a convenience wrapper that attempts to interpret the input
@@ -174,6 +274,28 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_strip_gutenberg_boilerplate() {
+        let text = "Some license header\nmore header text\n\
+            *** START OF THE PROJECT GUTENBERG EBOOK A TALE OF TWO CITIES ***\n\
+            It was the best of times, it was the worst of times.\n\
+            *** END OF THE PROJECT GUTENBERG EBOOK A TALE OF TWO CITIES ***\n\
+            Some license footer";
+
+        let stripped = strip_gutenberg_boilerplate(text);
+
+        assert_eq!(
+            stripped,
+            "It was the best of times, it was the worst of times."
+        );
+    }
+
+    #[test]
+    fn test_strip_gutenberg_boilerplate_no_markers() {
+        let text = "Just plain text with no Gutenberg markers at all.";
+        assert_eq!(strip_gutenberg_boilerplate(text), text);
+    }
+
     // URL tests would go here if the feature is enabled
     #[cfg(feature = "url")]
     #[test]
@@ -16,12 +16,14 @@ mod config;
 pub mod error;
 pub mod io;
 mod parser;
+pub mod progress;
 
 // Re-export the main structs and functions
 pub use capacity::{EstimationMethod, estimate_capacity};
 pub use config::WordProcessorConfig;
 pub use error::WordProcessorError;
-pub use parser::parse_text;
+pub use parser::{parse_text, parse_text_with_progress};
+pub use progress::{Phase, ProgressCallback};
 
 #[cfg(test)]
 mod tests {
@@ -30,7 +32,7 @@ mod tests {
     #[test]
     fn sanity_check() {
         let conf = WordProcessorConfig::default();
-        let words = parse_text("Hello World!", &conf);
+        let words = parse_text("Hello World!", &conf).unwrap();
         assert_eq!(words, vec!["Hello", "World"]);
     }
 }
@@ -1,6 +1,8 @@
 //! This module handles parsing text into words based on configuration settings.
 
 use crate::config::WordProcessorConfig;
+use crate::error::{ParserError, WordProcessorError};
+use crate::progress::Phase;
 
 /// Parses text into words according to the provided configuration.
 ///
@@ -9,27 +11,59 @@ use crate::config::WordProcessorConfig;
 /// * `config` - Configuration that controls parsing behavior
 ///
 /// # Returns
-/// A vector of parsed words
-pub fn parse_text(text: &str, config: &WordProcessorConfig) -> Vec<String> {
-    // Split by whitespace as the basic tokenization strategy
-    let tokens = text.split_whitespace();
+/// A vector of parsed words, or an error if `config.custom_pattern` is an invalid regex
+pub fn parse_text(text: &str, config: &WordProcessorConfig) -> Result<Vec<String>, WordProcessorError> {
+    parse_text_with_progress(text, config, None)
+}
+
+/// Parses text into words, optionally reporting tokenization progress.
+///
+/// # Arguments
+/// * `text` - The text to parse
+/// * `config` - Configuration that controls parsing behavior
+/// * `on_progress` - Called periodically with `(Phase::Parsing, tokens_processed, Some(total_tokens))`
+///
+/// # Returns
+/// A vector of parsed words, or an error if `config.custom_pattern` is an invalid regex
+///
+/// # Errors
+/// Returns `WordProcessorError::Parser(ParserError::InvalidPattern)` if `config.custom_pattern`
+/// is set but fails to compile as a regex.
+pub fn parse_text_with_progress(
+    text: &str,
+    config: &WordProcessorConfig,
+    mut on_progress: Option<&mut crate::progress::ProgressCallback<'_>>,
+) -> Result<Vec<String>, WordProcessorError> {
+    let tokens: Vec<&str> = match &config.custom_pattern {
+        Some(pattern) => {
+            let regex = regex::Regex::new(pattern)
+                .map_err(|err| ParserError::InvalidPattern(err.to_string()))?;
+            regex.find_iter(text).map(|m| m.as_str()).collect()
+        }
+        None => text.split_whitespace().collect(),
+    };
+    let total = tokens.len() as u64;
 
     // Process each token according to config
     let mut result = Vec::new();
-    for token in tokens {
+    for (i, token) in tokens.into_iter().enumerate() {
         // Process the token based on configuration
         let processed = process_token(token, config);
 
         // Add to results if we have a valid token
         if let Some(word) = processed {
             // Skip stop words if configured
-            if !config.skip_stop_words || !is_stop_word(&word) {
+            if !config.skip_stop_words || !is_stop_word(&word, config) {
                 result.push(word);
             }
         }
+
+        if let Some(callback) = on_progress.as_deref_mut() {
+            callback(Phase::Parsing, i as u64 + 1, Some(total));
+        }
     }
 
-    result
+    Ok(result)
 }
 
 /// Process a single token according to configuration
@@ -51,6 +85,19 @@ fn process_token(token: &str, config: &WordProcessorConfig) -> Option<String> {
         return None;
     }
 
+    // Apply word length filters
+    let char_count = token.chars().count();
+    if let Some(min_length) = config.min_length
+        && char_count < min_length
+    {
+        return None;
+    }
+    if let Some(max_length) = config.max_length
+        && char_count > max_length
+    {
+        return None;
+    }
+
     // Apply case sensitivity
     let token = if !config.case_sensitive {
         token.to_lowercase()
@@ -94,8 +141,9 @@ fn is_punctuation(c: char) -> bool {
     c.is_ascii_punctuation()
 }
 
-/// Check if a word is a common stop word
-fn is_stop_word(word: &str) -> bool {
+/// Check if a word is a stop word, consulting `config.custom_stop_words` if set
+/// and falling back to the built-in English list otherwise.
+fn is_stop_word(word: &str, config: &WordProcessorConfig) -> bool {
     // Common English stop words
     static STOP_WORDS: [&str; 25] = [
         "the", "and", "a", "an", "in", "on", "at", "of", "to", "for", "with", "by", "as", "is",
@@ -103,7 +151,10 @@ fn is_stop_word(word: &str) -> bool {
     ];
 
     let lower_word = word.to_lowercase();
-    STOP_WORDS.contains(&lower_word.as_str())
+    match &config.custom_stop_words {
+        Some(custom) => custom.contains(&lower_word),
+        None => STOP_WORDS.contains(&lower_word.as_str()),
+    }
 }
 
 #[cfg(test)]
@@ -116,7 +167,7 @@ mod tests {
         let text = "Hello, World! This is a test.";
         let config = WordProcessorConfig::default();
 
-        let words = parse_text(text, &config);
+        let words = parse_text(text, &config).unwrap();
         assert_eq!(words, vec!["Hello", "World", "This", "is", "a", "test"]);
     }
 
@@ -127,7 +178,48 @@ mod tests {
         let mut config = WordProcessorConfig::default();
         config.case_sensitive = false;
 
-        let words = parse_text(text, &config);
+        let words = parse_text(text, &config).unwrap();
         assert_eq!(words, vec!["hello", "world"]);
     }
+
+    #[test]
+    fn test_custom_stop_words() {
+        let text = "the quick brown fox";
+        let config = WordProcessorConfig::default()
+            .skip_stop_words(true)
+            .custom_stop_words(["quick".to_string(), "fox".to_string()]);
+
+        let words = parse_text(text, &config).unwrap();
+        assert_eq!(words, vec!["the", "brown"]);
+    }
+
+    #[test]
+    fn test_custom_pattern() {
+        let text = "order-123 order-456 skip-this";
+        let config = WordProcessorConfig::default().custom_pattern(r"order-\d+");
+
+        let words = parse_text(text, &config).unwrap();
+        assert_eq!(words, vec!["order-123", "order-456"]);
+    }
+
+    #[test]
+    fn test_length_filters() {
+        let text = "a cat sitting elephant";
+        let config = WordProcessorConfig::default().min_length(3).max_length(7);
+
+        let words = parse_text(text, &config).unwrap();
+        assert_eq!(words, vec!["cat", "sitting"]);
+    }
+
+    #[test]
+    fn test_invalid_custom_pattern() {
+        let text = "anything";
+        let config = WordProcessorConfig::default().custom_pattern("(unclosed");
+
+        let result = parse_text(text, &config);
+        assert!(matches!(
+            result,
+            Err(WordProcessorError::Parser(ParserError::InvalidPattern(_)))
+        ));
+    }
 }
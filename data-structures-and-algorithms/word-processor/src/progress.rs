@@ -0,0 +1,19 @@
+//! Progress reporting primitives.
+//!
+//! Long-running phases (downloading, parsing, counting) accept an optional
+//! callback so callers like the `word-frequency` CLI can drive a progress bar
+//! without this crate depending on any particular UI library.
+
+/// A phase of the word-processing pipeline that can report progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    /// Downloading text from a URL.
+    Download,
+    /// Tokenizing text into words.
+    Parsing,
+}
+
+/// Called with the phase, the amount of work done so far, and the total
+/// amount of work if known (e.g. a `Content-Length` header, or the token
+/// count of the input text).
+pub type ProgressCallback<'a> = dyn FnMut(Phase, u64, Option<u64>) + 'a;
@@ -0,0 +1,68 @@
+//! `POST /analyze`: runs `word-processor`/`word-frequency` over either inline text or a URL,
+//! and returns the frequency report as JSON.
+
+use axum::Json;
+use errors::ResultExt;
+use serde::{Deserialize, Serialize};
+use word_processor::{WordProcessorConfig, parse_text};
+
+use crate::error::ServiceError;
+use crate::safe_fetch;
+
+#[derive(Debug, Deserialize)]
+pub struct AnalyzeRequest {
+    /// Text to analyze directly. Mutually exclusive with `source`.
+    pub text: Option<String>,
+    /// A URL to fetch text from before analyzing. Mutually exclusive with `text`.
+    pub source: Option<String>,
+    /// How many of the most frequent words to include in the response. Default: 20.
+    #[serde(default = "default_top_n")]
+    pub top_n: usize,
+}
+
+fn default_top_n() -> usize {
+    20
+}
+
+#[derive(Debug, Serialize)]
+pub struct WordCount {
+    pub word: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AnalyzeResponse {
+    pub total_words: usize,
+    pub unique_words: usize,
+    pub zipf_slope: f64,
+    pub hapax_legomena: usize,
+    pub top_words: Vec<WordCount>,
+}
+
+pub async fn analyze(Json(request): Json<AnalyzeRequest>) -> Result<Json<AnalyzeResponse>, ServiceError> {
+    let text = match (request.text, request.source) {
+        (Some(text), None) => text,
+        (None, Some(source)) => safe_fetch::fetch_text(&source).await?,
+        _ => return Err(ServiceError::MissingInput),
+    };
+
+    let config = WordProcessorConfig::default();
+    let words = parse_text(&text, &config).context("tokenizing text")?;
+    let total_words = words.len();
+
+    let table = word_frequency::build_frequency_table(&words);
+    let unique_words = table.len();
+    let stats = word_frequency::compute_frequency_stats(&table, total_words);
+
+    Ok(Json(AnalyzeResponse {
+        total_words,
+        unique_words,
+        zipf_slope: stats.zipf_slope,
+        hapax_legomena: stats.hapax_legomena,
+        top_words: table
+            .into_iter()
+            .take(request.top_n)
+            .map(|(word, count)| WordCount { word, count })
+            .collect(),
+    }))
+}
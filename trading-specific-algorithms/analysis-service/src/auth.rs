@@ -0,0 +1,29 @@
+//! A minimal shared-secret gate for every route: this service binds to `0.0.0.0` by default and
+//! has no other authentication, so an unset key must fail closed rather than default open.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::extract::State;
+use axum::http::{Request, StatusCode};
+use axum::middleware::Next;
+use axum::response::Response;
+
+/// Header carrying the shared secret configured via `ANALYSIS_SERVICE_API_KEY`.
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// Rejects the request with `401 Unauthorized` unless it carries an `X-Api-Key` header matching
+/// `expected_key`.
+pub async fn require_api_key(
+    State(expected_key): State<Arc<String>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let provided = request.headers().get(API_KEY_HEADER).and_then(|value| value.to_str().ok());
+
+    if provided == Some(expected_key.as_str()) {
+        Ok(next.run(request).await)
+    } else {
+        Err(StatusCode::UNAUTHORIZED)
+    }
+}
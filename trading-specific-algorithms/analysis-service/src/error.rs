@@ -0,0 +1,49 @@
+//! The service's error type and its mapping onto HTTP responses.
+
+use axum::Json;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ServiceError {
+    #[error("request must set exactly one of `text` or `source`")]
+    MissingInput,
+    /// `source` isn't an `http(s)` URL, or resolves to a loopback/private/link-local/multicast
+    /// address; see [`crate::safe_fetch`].
+    #[error("source URL {0} is not allowed")]
+    UnsafeSource(String),
+    #[error("fetching source text: {0}")]
+    Fetch(String),
+    #[error("source response exceeded the maximum allowed size")]
+    ResponseTooLarge,
+    #[error("no quoted expiries found for underlying {0}")]
+    NoExpiries(String),
+    #[error("failed to build options chain: {0}")]
+    Chain(#[source] binance_options_client::OptionSymbolParseError),
+    /// Wraps a `word-processor` or `binance-options-client` failure via the shared `errors`
+    /// crate, so this service doesn't need its own variant (and HTTP status mapping) for every
+    /// error type those crates expose.
+    #[error(transparent)]
+    App(#[from] errors::AppError),
+}
+
+#[derive(Serialize)]
+struct ErrorBody {
+    error: String,
+}
+
+impl IntoResponse for ServiceError {
+    fn into_response(self) -> Response {
+        let status = match &self {
+            Self::MissingInput | Self::UnsafeSource(_) | Self::NoExpiries(_) => StatusCode::BAD_REQUEST,
+            Self::ResponseTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            Self::Fetch(_) | Self::Chain(_) => StatusCode::BAD_GATEWAY,
+            Self::App(err) => match err.code() {
+                "word_processor" => StatusCode::UNPROCESSABLE_ENTITY,
+                _ => StatusCode::BAD_GATEWAY,
+            },
+        };
+        (status, Json(ErrorBody { error: self.to_string() })).into_response()
+    }
+}
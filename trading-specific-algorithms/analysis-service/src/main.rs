@@ -0,0 +1,40 @@
+//! A small HTTP front door onto this repo's assessment crates, so non-Rust teams can reach
+//! `word-processor`/`word-frequency` and `binance-options-client` without a Rust toolchain.
+//!
+//! - `POST /analyze` — word frequency analysis over inline text or a fetched URL.
+//! - `GET /options/chain/{underlying}` — one expiry's strikes from the live options chain.
+//!
+//! Every route requires an `X-Api-Key` header matching `ANALYSIS_SERVICE_API_KEY`; see [`auth`].
+
+mod analyze;
+mod auth;
+mod error;
+mod options;
+mod safe_fetch;
+
+use std::sync::Arc;
+
+use axum::Router;
+use axum::middleware;
+use axum::routing::{get, post};
+
+const DEFAULT_ADDR: &str = "0.0.0.0:3000";
+
+#[tokio::main]
+async fn main() {
+    env_logger::init();
+
+    let api_key = Arc::new(std::env::var("ANALYSIS_SERVICE_API_KEY").expect(
+        "ANALYSIS_SERVICE_API_KEY must be set; this service has no authentication otherwise",
+    ));
+
+    let app = Router::new()
+        .route("/analyze", post(analyze::analyze))
+        .route("/options/chain/:underlying", get(options::chain))
+        .layer(middleware::from_fn_with_state(api_key, auth::require_api_key));
+
+    let addr = std::env::var("ANALYSIS_SERVICE_ADDR").unwrap_or_else(|_| DEFAULT_ADDR.to_string());
+    let listener = tokio::net::TcpListener::bind(&addr).await.expect("failed to bind listener");
+    log::info!("analysis-service listening on {addr}");
+    axum::serve(listener, app).await.expect("server error");
+}
@@ -0,0 +1,59 @@
+//! `GET /options/chain/{underlying}`: fetches the live ticker list and returns one expiry's
+//! strikes as JSON.
+
+use axum::Json;
+use axum::extract::{Path, Query};
+use binance_options_client::{BinanceOptionsClient, OptionTicker, OptionsChain};
+use errors::ResultExt;
+use serde::{Deserialize, Serialize};
+
+use crate::error::ServiceError;
+
+#[derive(Debug, Deserialize)]
+pub struct ChainQuery {
+    /// Which expiry to return, by index into the underlying's expiries (chronological,
+    /// 0-based); defaults to the nearest expiry.
+    pub expiry_index: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct StrikeRow {
+    pub strike: String,
+    pub call: Option<OptionTicker>,
+    pub put: Option<OptionTicker>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ChainResponse {
+    pub underlying: String,
+    pub expiry: (u32, u32, u32),
+    pub strikes: Vec<StrikeRow>,
+}
+
+pub async fn chain(
+    Path(underlying): Path<String>,
+    Query(query): Query<ChainQuery>,
+) -> Result<Json<ChainResponse>, ServiceError> {
+    let client = BinanceOptionsClient::builder().build().context("building binance options client")?;
+    let tickers = client.get_ticker(None).await.context("fetching ticker data")?;
+    let chain = OptionsChain::build(tickers).map_err(ServiceError::Chain)?;
+
+    let expiries = chain.expiries(&underlying);
+    let expiry = match query.expiry_index {
+        Some(index) => expiries.get(index).copied(),
+        None => chain.nearest_expiry(&underlying),
+    }
+    .ok_or_else(|| ServiceError::NoExpiries(underlying.clone()))?;
+
+    let strikes = chain
+        .strikes(&underlying, expiry)
+        .into_iter()
+        .map(|(strike, pair)| StrikeRow {
+            strike: strike.to_string(),
+            call: pair.call.clone(),
+            put: pair.put.clone(),
+        })
+        .collect();
+
+    Ok(Json(ChainResponse { underlying, expiry, strikes }))
+}
@@ -0,0 +1,130 @@
+//! Fetches text from a caller-supplied URL, hardened for use behind `POST /analyze`, where the
+//! URL comes from any caller holding the service's API key.
+//!
+//! `word_processor::io::fetch_from_url` is fine for a local CLI user pointing it at a book URL
+//! they chose themselves, but here `source` comes straight from the request body of a service
+//! bound to `0.0.0.0`. Reusing it as-is would let any caller use this service as an open proxy
+//! to probe internal hosts (including the cloud metadata endpoint at 169.254.169.254) or exhaust
+//! memory with an unbounded response. This module resolves the host first and refuses to fetch
+//! if any resolved address is loopback/private/link-local/multicast, and caps the body size.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use futures_util::StreamExt;
+
+use crate::error::ServiceError;
+
+/// Maximum response body accepted from a fetched `source` URL.
+const MAX_BODY_BYTES: usize = 10 * 1024 * 1024;
+
+/// Fetches `url`'s body as text. Rejects non-`http(s)` schemes, URLs whose host resolves to a
+/// disallowed address, and responses larger than [`MAX_BODY_BYTES`].
+pub async fn fetch_text(url: &str) -> Result<String, ServiceError> {
+    let parsed = reqwest::Url::parse(url).map_err(|_| ServiceError::UnsafeSource(url.to_string()))?;
+
+    if parsed.scheme() != "http" && parsed.scheme() != "https" {
+        return Err(ServiceError::UnsafeSource(url.to_string()));
+    }
+
+    let host = parsed.host_str().ok_or_else(|| ServiceError::UnsafeSource(url.to_string()))?;
+    let port = parsed.port_or_known_default().unwrap_or(80);
+
+    let mut resolved = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|_| ServiceError::UnsafeSource(url.to_string()))?
+        .peekable();
+
+    if resolved.peek().is_none() {
+        return Err(ServiceError::UnsafeSource(url.to_string()));
+    }
+    for addr in resolved {
+        if is_disallowed(addr.ip()) {
+            return Err(ServiceError::UnsafeSource(url.to_string()));
+        }
+    }
+
+    // Redirects are not followed: their targets would skip the address check above.
+    let client = reqwest::Client::builder()
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+        .map_err(|err| ServiceError::Fetch(err.to_string()))?;
+    let response =
+        client.get(parsed).send().await.map_err(|err| ServiceError::Fetch(err.to_string()))?;
+
+    let mut body = Vec::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|err| ServiceError::Fetch(err.to_string()))?;
+        if body.len() + chunk.len() > MAX_BODY_BYTES {
+            return Err(ServiceError::ResponseTooLarge);
+        }
+        body.extend_from_slice(&chunk);
+    }
+
+    String::from_utf8(body).map_err(|_| ServiceError::Fetch("response was not valid UTF-8".to_string()))
+}
+
+/// True for loopback, private, link-local (including the 169.254.169.254 cloud metadata
+/// endpoint), multicast, unspecified, and other non-globally-routable addresses.
+fn is_disallowed(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_disallowed_v4(v4),
+        IpAddr::V6(v6) => is_disallowed_v6(v6),
+    }
+}
+
+fn is_disallowed_v4(ip: Ipv4Addr) -> bool {
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+}
+
+fn is_disallowed_v6(ip: Ipv6Addr) -> bool {
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        || ip.is_unique_local()
+        || ip.is_unicast_link_local()
+        // An IPv4-mapped IPv6 address (::ffff:a.b.c.d) inherits whatever restrictions apply to
+        // the IPv4 address it maps to.
+        || ip.to_ipv4_mapped().is_some_and(is_disallowed_v4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loopback_and_private_v4_addresses_are_disallowed() {
+        assert!(is_disallowed(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))));
+        assert!(is_disallowed(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 5))));
+        assert!(is_disallowed(IpAddr::V4(Ipv4Addr::new(172, 16, 0, 1))));
+        assert!(is_disallowed(IpAddr::V4(Ipv4Addr::new(192, 168, 1, 1))));
+    }
+
+    #[test]
+    fn the_cloud_metadata_address_is_disallowed_as_link_local() {
+        assert!(is_disallowed(IpAddr::V4(Ipv4Addr::new(169, 254, 169, 254))));
+    }
+
+    #[test]
+    fn loopback_and_unique_local_v6_addresses_are_disallowed() {
+        assert!(is_disallowed(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+        assert!(is_disallowed(IpAddr::V6(Ipv6Addr::new(0xfd00, 0, 0, 0, 0, 0, 0, 1))));
+    }
+
+    #[test]
+    fn an_ipv4_mapped_v6_address_inherits_the_v4_check() {
+        let mapped = Ipv4Addr::new(10, 0, 0, 1).to_ipv6_mapped();
+        assert!(is_disallowed(IpAddr::V6(mapped)));
+    }
+
+    #[test]
+    fn a_public_v4_address_is_allowed() {
+        assert!(!is_disallowed(IpAddr::V4(Ipv4Addr::new(93, 184, 216, 34))));
+    }
+}
@@ -1,24 +1,141 @@
+use crate::circuit_breaker::{CircuitBreaker, CircuitHealth};
 use crate::error::BinanceOptionsClientError;
-use crate::model::{OptionTicker, ParsingMetrics};
+use crate::model::{
+    AccountInfo, CancelAllOrdersResponse, ExerciseRecord, IndexPrice, ListenKeyResponse,
+    MarkPrice, OptionContract, OptionPosition, OptionTicker, OrderBook, OrderResponse,
+    ParsingMetrics, ServerTime,
+};
+use crate::rate_limit::{self, RateLimiter, RateLimitStatus};
+use crate::retry::{self, RetryPolicy};
+use crate::ticker_tracker::TickerTracker;
 use log::{debug, error, info, warn};
 use reqwest::{Client, Method};
 use serde::de::DeserializeOwned;
-use std::time::Instant;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::{Duration, Instant};
 
-/// Base URL for the Binance Options API.
+/// Base URL for the production Binance Options API.
 const BASE_URL: &str = "https://eapi.binance.com";
+/// Base URL for the Binance Options testnet, used for paper trading and integration tests.
+const TESTNET_BASE_URL: &str = "https://testnet.binanceops.com";
 /// Endpoint for retrieving ticker data.
 const TICKER_ENDPOINT: &str = "/eapi/v1/ticker";
+/// Endpoint for retrieving order book depth.
+const DEPTH_ENDPOINT: &str = "/eapi/v1/depth";
+/// Endpoint for retrieving mark price and Greeks.
+const MARK_ENDPOINT: &str = "/eapi/v1/mark";
+/// Endpoint for retrieving the underlying index price.
+const INDEX_ENDPOINT: &str = "/eapi/v1/index";
+/// Endpoint for retrieving historical exercise (settlement) records.
+const EXERCISE_HISTORY_ENDPOINT: &str = "/eapi/v1/exerciseHistory";
+/// Endpoint for retrieving signed account information.
+const ACCOUNT_ENDPOINT: &str = "/eapi/v1/account";
+/// Endpoint for placing, querying, and cancelling orders.
+const ORDER_ENDPOINT: &str = "/eapi/v1/order";
+/// Endpoint for cancelling all open orders for a symbol.
+const CANCEL_ALL_ORDERS_ENDPOINT: &str = "/eapi/v1/allOpenOrders";
+/// Endpoint for retrieving currently open orders.
+const OPEN_ORDERS_ENDPOINT: &str = "/eapi/v1/openOrders";
+/// Endpoint for retrieving historical (filled/cancelled/expired) orders.
+const ORDER_HISTORY_ENDPOINT: &str = "/eapi/v1/historyOrders";
+/// Endpoint for retrieving signed position information.
+const POSITION_ENDPOINT: &str = "/eapi/v1/position";
+/// Endpoint for retrieving the server's current time.
+const SERVER_TIME_ENDPOINT: &str = "/eapi/v1/time";
+/// Endpoint for managing a user data stream listen key.
+const USER_DATA_STREAM_ENDPOINT: &str = "/eapi/v1/userDataStream";
+
+/// Process-wide counter backing [`next_request_id`].
+static REQUEST_ID_COUNTER: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
+
+/// Generates a request ID unique within this process, for correlating the log lines and any
+/// resulting [`BinanceOptionsClientError`] of one logical request (including its retries) with
+/// each other. A plain incrementing counter rather than a UUID, since the ID only needs to be
+/// unique among requests made by this process, not globally.
+pub(crate) fn next_request_id() -> String {
+    format!("req-{}", REQUEST_ID_COUNTER.fetch_add(1, Ordering::Relaxed))
+}
 
 /// Client for interacting with the Binance Options API.
 pub struct BinanceOptionsClient {
-    /// The underlying HTTP client.
+    /// The underlying HTTP client, used directly by endpoints (like `get_ticker_stream`) that
+    /// need the raw byte stream of a response rather than `HttpTransport`'s buffered body.
     client: Client,
-    /// The base URL for API requests.
+    /// Performs the request/response cycle for `send_request`. A real `reqwest`-backed
+    /// transport unless overridden via [`ClientBuilder::transport`].
+    transport: Box<dyn HttpTransport>,
+    /// The base URL for API requests. Ignored in favor of `base_url_pool`'s current URL when
+    /// one is configured.
     base_url: String,
+    /// Ordered base URLs with automatic failover, for deployments that configure alternates to
+    /// `base_url`. `None` means every request targets `base_url` unconditionally.
+    base_url_pool: Option<std::sync::Arc<crate::failover::BaseUrlPool>>,
+    /// API key, sent as the `X-MBX-APIKEY` header on API-key-authenticated endpoints.
+    api_key: Option<String>,
+    /// API secret, used to HMAC-SHA256 sign the query string of signed endpoints.
+    secret_key: Option<String>,
+    /// `recvWindow` (in milliseconds) sent with signed requests. `None` lets Binance use its
+    /// own default.
+    recv_window: Option<u64>,
+    /// Offset (in milliseconds, server minus local) applied to signed request timestamps,
+    /// populated by [`BinanceOptionsClient::sync_clock`]. Zero until a sync has run.
+    clock_offset_ms: AtomicI64,
+    /// Client-side request-weight rate limiter. `None` disables rate limiting.
+    rate_limiter: Option<RateLimiter>,
+    /// Requests-per-interval pacer, independent of `rate_limiter`'s weight accounting. Shared
+    /// (via `Arc`) rather than owned outright, so it can be paced across several client
+    /// instances running behind the same IP. `None` disables pacing.
+    throttle: Option<std::sync::Arc<crate::throttle::RequestThrottle>>,
+    /// Retry policy applied to transient failures in `send_request`. `None` disables retries.
+    retry_policy: Option<RetryPolicy>,
+    /// Circuit breaker guarding `send_request` against a failing or banning endpoint.
+    /// `None` disables circuit breaking.
+    circuit_breaker: Option<CircuitBreaker>,
+    /// Most recently observed value across all `X-MBX-USED-WEIGHT*` response headers,
+    /// surfaced via [`BinanceOptionsClient::rate_limit_status`]. Zero until a response with
+    /// one of those headers has been seen.
+    last_used_weight: std::sync::atomic::AtomicU32,
+    /// Interceptors run around every request attempt, in registration order. See
+    /// [`RequestInterceptor`].
+    interceptors: Vec<Box<dyn RequestInterceptor>>,
+    /// Validates a request's `symbol` parameter against a cached `exchangeInfo` contract list
+    /// before sending it. `None` disables local validation (every symbol is sent as-is).
+    symbol_validator: Option<std::sync::Arc<crate::symbol_validation::SymbolValidator>>,
+}
+
+/// A summary of a completed request attempt, passed to [`RequestInterceptor::after`]. Doesn't
+/// carry the response body, since by the time interceptors run it's already been consumed (and
+/// may not exist at all, on a network error).
+#[derive(Debug, Clone)]
+pub struct ResponseSummary {
+    /// The HTTP status code, if the request reached the server at all. `None` on a network
+    /// error (e.g. connection refused, DNS failure, timeout) before any response arrived.
+    pub status: Option<reqwest::StatusCode>,
+    /// How long the attempt took, from just before sending to just after the response (or
+    /// error) was received.
+    pub elapsed: Duration,
+}
+
+/// Observes or mutates individual request attempts around [`BinanceOptionsClient::send_request`],
+/// without modifying its retry/signing logic. Register via [`ClientBuilder::interceptor`].
+///
+/// Both methods run once per attempt, i.e. once per retry, not once per `send_request` call.
+/// Useful for adding custom headers, request/response logging, latency measurement, and test
+/// instrumentation.
+pub trait RequestInterceptor: Send + Sync {
+    /// Called just before each attempt is sent. Mutate `request` to add headers or otherwise
+    /// adjust the outgoing request.
+    fn before(&self, _request: &mut Request) {}
+
+    /// Called just after each attempt completes, given the request that was sent and a summary
+    /// of its response (or lack of one, on a network error).
+    fn after(&self, _request: &Request, _response: &ResponseSummary) {}
 }
 
 /// Represents an HTTP request to the Binance Options API.
+#[derive(Clone)]
 pub struct Request {
     /// The API endpoint path.
     pub path: String,
@@ -30,6 +147,145 @@ pub struct Request {
     pub requires_api_key: bool,
     /// Indicates if a signature is required.
     pub requires_signature: bool,
+    /// Per-request timeout override. `None` uses the client's configured timeout.
+    pub timeout: Option<Duration>,
+    /// Extra headers to send with the request, beyond `X-MBX-APIKEY`. Populated by endpoint
+    /// builders only rarely; mainly a hook for `RequestInterceptor::before`.
+    pub headers: Vec<(String, String)>,
+}
+
+impl Request {
+    /// Overrides the timeout for this specific request, regardless of the client's configured
+    /// timeout. Useful for giving a short timeout to latency-sensitive polling (e.g. ticker
+    /// prices) and a longer one to slow, large queries (e.g. order history).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a header to be sent with this request, alongside any set by the client itself
+    /// (e.g. `X-MBX-APIKEY`).
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// A future boxed for storage behind a trait object, matching the pattern used for stream
+/// erasure elsewhere in this crate (e.g. [`crate::ws_supervisor`]).
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A fully-resolved HTTP call: signing, credentials, and interceptors have already been
+/// applied, so an [`HttpTransport`] only has to perform it and report back what came back.
+pub struct HttpCall {
+    /// Absolute URL (base URL plus endpoint path).
+    pub url: String,
+    /// HTTP method to use.
+    pub method: Method,
+    /// Query parameters, including any signature/timestamp already appended.
+    pub params: Vec<(String, String)>,
+    /// Headers to send, including `X-MBX-APIKEY` if the endpoint required one.
+    pub headers: Vec<(String, String)>,
+    /// Per-call timeout override.
+    pub timeout: Option<Duration>,
+}
+
+/// A transport-agnostic summary of an HTTP response: status, headers, and the full body read
+/// into a `String`. Deliberately small so test doubles are easy to construct.
+pub struct HttpResponse {
+    /// The HTTP status code.
+    pub status: reqwest::StatusCode,
+    /// Response headers (e.g. `X-MBX-USED-WEIGHT-1M`, `Retry-After`).
+    pub headers: reqwest::header::HeaderMap,
+    /// The full response body.
+    pub body: String,
+}
+
+/// Performs the actual network call behind [`BinanceOptionsClient::send_request`]. The default
+/// transport (used unless [`ClientBuilder::transport`] overrides it) sends the call over
+/// `reqwest`; tests can install their own to inject canned responses and error cases without
+/// network access, which the crate's benchmarks and the `get_ticker_stream`/`get_ticker_raw`
+/// byte-streaming endpoints can't currently avoid.
+pub trait HttpTransport: Send + Sync {
+    /// Performs `call` and returns its response, or a `BinanceOptionsClientError::Network` (or
+    /// any other variant a fake transport wants to simulate) on failure.
+    fn send<'a>(&'a self, call: &'a HttpCall) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>>;
+}
+
+/// Lets a boxed transport (as stored on [`ClientBuilder`]/[`BinanceOptionsClient`]) be wrapped
+/// by another [`HttpTransport`], e.g. [`crate::response_cache::CachingTransport`], without the
+/// wrapper needing to know it's holding a trait object.
+impl HttpTransport for Box<dyn HttpTransport> {
+    fn send<'a>(
+        &'a self,
+        call: &'a HttpCall,
+    ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+        (**self).send(call)
+    }
+}
+
+/// The default [`HttpTransport`], backed by a real `reqwest::Client`. Exposed so wrappers like
+/// [`crate::record_replay::RecordingTransport`] can sit in front of it explicitly, rather than
+/// only being installable via [`ClientBuilder::transport`] in place of it.
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    /// Wraps an already-configured `reqwest::Client`.
+    pub fn new(client: Client) -> Self {
+        Self { client }
+    }
+}
+
+impl HttpTransport for ReqwestTransport {
+    fn send<'a>(
+        &'a self,
+        call: &'a HttpCall,
+    ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+        Box::pin(async move {
+            let mut request_builder = match call.method {
+                Method::GET => self.client.get(&call.url),
+                Method::POST => self.client.post(&call.url),
+                Method::PUT => self.client.put(&call.url),
+                Method::DELETE => self.client.delete(&call.url),
+                _ => {
+                    return Err(BinanceOptionsClientError::Unknown(
+                        "Unsupported HTTP method".to_string(),
+                    ));
+                }
+            };
+
+            for (name, value) in &call.headers {
+                request_builder = request_builder.header(name, value);
+            }
+
+            if !call.params.is_empty() {
+                request_builder = request_builder.query(&call.params);
+            }
+
+            if let Some(timeout) = call.timeout {
+                request_builder = request_builder.timeout(timeout);
+            }
+
+            let response = request_builder
+                .send()
+                .await
+                .map_err(BinanceOptionsClientError::Network)?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response
+                .text()
+                .await
+                .map_err(BinanceOptionsClientError::Network)?;
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
 }
 
 /// Builder for constructing a ticker request.
@@ -60,6 +316,16 @@ impl Default for TickerRequest {
     }
 }
 
+/// The error from fetching one symbol's ticker in [`BinanceOptionsClient::get_tickers_for`],
+/// paired with the symbol that failed.
+#[derive(Debug)]
+pub struct TickerFetchError {
+    /// The symbol whose request failed.
+    pub symbol: String,
+    /// The underlying error.
+    pub error: BinanceOptionsClientError,
+}
+
 impl From<TickerRequest> for Request {
     /// Converts a `TickerRequest` into a generic `Request` using the pre-defined ticker endpoint.
     fn from(request: TickerRequest) -> Self {
@@ -75,223 +341,2421 @@ impl From<TickerRequest> for Request {
             params,
             requires_api_key: false,
             requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
         }
     }
 }
 
-impl BinanceOptionsClient {
-    /// Creates a new instance of `BinanceOptionsClient`.
-    pub fn new() -> Self {
-        info!(
-            "Creating new BinanceOptionsClient with base URL: {}",
-            BASE_URL
-        );
-        Self {
-            client: Client::new(),
-            base_url: BASE_URL.to_string(),
-        }
-    }
+/// Builder for constructing an order book depth request.
+pub struct DepthRequest {
+    symbol: String,
+    limit: Option<u32>,
+}
 
-    /// Sends an HTTP request to the Binance Options API and returns the deserialized response.
+impl DepthRequest {
+    /// Creates a new depth request for the given symbol.
     ///
-    /// # Type Parameters
-    ///
-    /// * `T` - The type into which the response will be deserialized.
-    ///
-    /// # Errors
+    /// # Arguments
     ///
-    /// Returns a `BinanceOptionsClientError` if the network request fails,
-    /// the response status is unsuccessful, or JSON parsing fails.
-    pub async fn send_request<T: DeserializeOwned>(
-        &self,
-        request: Request,
-    ) -> Result<T, BinanceOptionsClientError> {
-        let url = format!("{}{}", self.base_url, request.path);
-        debug!(
-            "Sending request to: {} with method: {:?}",
-            url, request.method
-        );
-
-        if !request.params.is_empty() {
-            debug!("Request parameters: {:?}", request.params);
+    /// * `symbol` - A string slice representing the ticker symbol.
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            limit: None,
         }
+    }
 
-        let mut request_builder = match request.method {
-            Method::GET => self.client.get(&url),
-            Method::POST => self.client.post(&url),
-            Method::PUT => self.client.put(&url),
-            Method::DELETE => self.client.delete(&url),
-            _ => {
-                error!("Unsupported HTTP method: {:?}", request.method);
-                return Err(BinanceOptionsClientError::Unknown(
-                    "Unsupported HTTP method".to_string(),
-                ));
-            }
-        };
-
-        if !request.params.is_empty() {
-            request_builder = request_builder.query(&request.params);
-        }
+    /// Sets the maximum number of bid/ask levels to return.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
 
-        let response = match request_builder.send().await {
-            Ok(resp) => resp,
-            Err(e) => {
-                error!("Network error: {}", e);
-                return Err(BinanceOptionsClientError::Network(e));
-            }
-        };
+impl From<DepthRequest> for Request {
+    /// Converts a `DepthRequest` into a generic `Request` using the pre-defined depth endpoint.
+    fn from(request: DepthRequest) -> Self {
+        let mut params = vec![("symbol".to_owned(), request.symbol)];
 
-        if !response.status().is_success() {
-            warn!("Request failed with status: {}", response.status());
-            return Err(BinanceOptionsClientError::from_response(response).await);
+        if let Some(limit) = request.limit {
+            params.push(("limit".to_owned(), limit.to_string()));
         }
 
-        let text = match response.text().await {
-            Ok(t) => t,
-            Err(e) => {
-                error!("Failed to get response text: {}", e);
-                return Err(BinanceOptionsClientError::Network(e));
-            }
-        };
+        Request {
+            path: DEPTH_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params,
+            requires_api_key: false,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
 
-        debug!(
-            "Received response (first 200 chars): {}",
-            text.chars().take(200).collect::<String>()
-        );
+/// Builder for constructing a mark price request.
+pub struct MarkRequest {
+    symbol: String,
+}
 
-        let data = match serde_json::from_str(&text) {
-            Ok(d) => d,
-            Err(e) => {
-                error!("JSON parse error: {}", e);
-                error!("JSON data: {}", text);
-                return Err(BinanceOptionsClientError::JsonParse(e));
-            }
-        };
+impl MarkRequest {
+    /// Creates a new mark price request for the given symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - A string slice representing the ticker symbol.
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+        }
+    }
+}
 
-        info!("Request completed successfully");
-        Ok(data)
+impl From<MarkRequest> for Request {
+    /// Converts a `MarkRequest` into a generic `Request` using the pre-defined mark endpoint.
+    fn from(request: MarkRequest) -> Self {
+        Request {
+            path: MARK_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params: vec![("symbol".to_owned(), request.symbol)],
+            requires_api_key: false,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
+        }
     }
+}
 
-    /// Retrieves raw ticker data as a JSON string from the Binance Options API.
+/// Builder for constructing an underlying index price request.
+pub struct IndexRequest {
+    underlying: String,
+}
+
+impl IndexRequest {
+    /// Creates a new index price request for the given underlying asset.
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// A string containing the raw JSON response.
-    pub async fn get_ticker_raw(
-        &self,
-        symbol: Option<&str>,
-    ) -> Result<String, BinanceOptionsClientError> {
-        info!(
-            "Getting raw ticker data{}",
-            symbol.map_or(String::new(), |s| format!(" for symbol: {}", s))
-        );
+    /// * `underlying` - A string slice representing the underlying asset (e.g. "BTCUSDT").
+    pub fn new(underlying: &str) -> Self {
+        Self {
+            underlying: underlying.to_owned(),
+        }
+    }
+}
 
-        // Start with a TickerRequest.
-        let mut ticker_req = TickerRequest::new();
-        if let Some(s) = symbol {
-            ticker_req = ticker_req.symbol(s);
+impl From<IndexRequest> for Request {
+    /// Converts an `IndexRequest` into a generic `Request` using the pre-defined index endpoint.
+    fn from(request: IndexRequest) -> Self {
+        Request {
+            path: INDEX_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params: vec![("underlying".to_owned(), request.underlying)],
+            requires_api_key: false,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
         }
+    }
+}
 
-        // Convert to Request (which has `path` and `params` fields).
-        let req: Request = ticker_req.into();
+/// Builder for constructing a server time request.
+#[derive(Default)]
+pub struct ServerTimeRequest;
 
-        let url = format!("{}{}", self.base_url, req.path);
-        let mut request_builder = self.client.get(&url);
-        if !req.params.is_empty() {
-            request_builder = request_builder.query(&req.params);
-        }
+impl ServerTimeRequest {
+    /// Creates a new server time request.
+    pub fn new() -> Self {
+        Self
+    }
+}
 
-        let response = request_builder
-            .send()
-            .await
-            .map_err(BinanceOptionsClientError::Network)?;
-        if !response.status().is_success() {
-            return Err(BinanceOptionsClientError::from_response(response).await);
+impl From<ServerTimeRequest> for Request {
+    /// Converts a `ServerTimeRequest` into a generic `Request` using the pre-defined server
+    /// time endpoint.
+    fn from(_: ServerTimeRequest) -> Self {
+        Request {
+            path: SERVER_TIME_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params: vec![],
+            requires_api_key: false,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
         }
-        let text = response
-            .text()
-            .await
-            .map_err(BinanceOptionsClientError::Network)?;
-        Ok(text)
     }
+}
 
-    /// Parses ticker JSON data using the specified parsing strategy (default is streaming)
-    /// and measures performance metrics.
+/// Builder for constructing a historical exercise records request.
+pub struct ExerciseHistoryRequest {
+    underlying: String,
+    start: Option<i64>,
+    end: Option<i64>,
+}
+
+impl ExerciseHistoryRequest {
+    /// Creates a new exercise history request for the given underlying asset.
     ///
     /// # Arguments
     ///
-    /// * `json_data` - A string slice containing JSON ticker data.
-    /// * `strategy` - An optional parsing strategy. If `None` is provided, streaming is used.
-    ///
-    /// # Returns
-    ///
-    /// A tuple containing a vector of `OptionTicker` entries and the associated parsing metrics.
-    pub fn parse_ticker_with_metrics(
-        &self,
-        json_data: &str,
-        strategy: Option<crate::parser::ParsingStrategy>,
-    ) -> Result<(Vec<OptionTicker>, ParsingMetrics), BinanceOptionsClientError> {
-        info!(
-            "Parsing ticker data using selected strategy (default is streaming) and measuring performance"
-        );
-        let start = Instant::now();
+    /// * `underlying` - A string slice representing the underlying asset (e.g. "BTCUSDT").
+    pub fn new(underlying: &str) -> Self {
+        Self {
+            underlying: underlying.to_owned(),
+            start: None,
+            end: None,
+        }
+    }
 
-        // Delegate to the parser module.
-        let tickers = crate::parser::parse_ticker(json_data, strategy)?;
+    /// Sets the start of the time range, as a millisecond timestamp.
+    pub fn start(mut self, start: i64) -> Self {
+        self.start = Some(start);
+        self
+    }
 
-        let duration = start.elapsed();
-        let entry_count = tickers.len().max(1);
-        let total_time_ms = duration.as_secs_f64() * 1000.0;
-        let time_per_entry_ms = total_time_ms / entry_count as f64;
+    /// Sets the end of the time range, as a millisecond timestamp.
+    pub fn end(mut self, end: i64) -> Self {
+        self.end = Some(end);
+        self
+    }
+}
 
-        let metrics = ParsingMetrics {
-            time_per_entry_ms,
-            entries_parsed: entry_count,
-            total_time_ms,
-        };
+impl From<ExerciseHistoryRequest> for Request {
+    /// Converts an `ExerciseHistoryRequest` into a generic `Request` using the pre-defined
+    /// exercise history endpoint.
+    fn from(request: ExerciseHistoryRequest) -> Self {
+        let mut params = vec![("underlying".to_owned(), request.underlying)];
 
-        info!(
-            "Parsed {} ticker entries in {:.3} ms ({:.6} ms per entry)",
-            entry_count, total_time_ms, time_per_entry_ms
-        );
+        if let Some(start) = request.start {
+            params.push(("startTime".to_owned(), start.to_string()));
+        }
+        if let Some(end) = request.end {
+            params.push(("endTime".to_owned(), end.to_string()));
+        }
 
-        Ok((tickers, metrics))
+        Request {
+            path: EXERCISE_HISTORY_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params,
+            requires_api_key: false,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
+        }
     }
+}
 
-    /// Parses ticker JSON data using the specified parsing strategy (default is streaming).
-    ///
-    /// # Arguments
-    ///
-    /// * `json_data` - A string slice containing JSON ticker data.
-    /// * `strategy` - An optional parsing strategy. If `None` is provided, streaming is used.
-    ///
-    /// # Returns
-    ///
-    /// A vector of `OptionTicker` entries.
-    pub fn parse_ticker(
-        &self,
-        json_data: &str,
-        strategy: Option<crate::parser::ParsingStrategy>,
-    ) -> Result<Vec<OptionTicker>, BinanceOptionsClientError> {
-        info!("Parsing ticker data using selected strategy (default is streaming)");
-
-        // Delegate to the parser module.
-        let tickers = crate::parser::parse_ticker(json_data, strategy)?;
-
-        info!("Parsed {} ticker entries", tickers.len());
+/// Builder for constructing a signed account information request.
+#[derive(Default)]
+pub struct AccountRequest;
 
-        Ok(tickers)
+impl AccountRequest {
+    /// Creates a new account information request.
+    pub fn new() -> Self {
+        Self
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{Method, Request, TickerRequest};
-    use crate::api::TICKER_ENDPOINT;
+impl From<AccountRequest> for Request {
+    /// Converts an `AccountRequest` into a generic `Request` using the pre-defined account
+    /// endpoint, flagged as requiring both an API key and a signature.
+    fn from(_: AccountRequest) -> Self {
+        Request {
+            path: ACCOUNT_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params: vec![],
+            requires_api_key: true,
+            requires_signature: true,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
 
-    #[test]
-    fn ticker_request_convert_to_request_test() {
-        let request: Request = TickerRequest::new().symbol("BTC-200730-9000-C").into();
+/// Builder for constructing a signed position information request.
+pub struct PositionRequest {
+    symbol: Option<String>,
+}
+
+impl PositionRequest {
+    /// Creates a new position request without any symbol filter.
+    pub fn new() -> Self {
+        Self { symbol: None }
+    }
+
+    /// Restricts the request to the position for a single symbol.
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+}
+
+impl Default for PositionRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<PositionRequest> for Request {
+    /// Converts a `PositionRequest` into a generic `Request` using the pre-defined position
+    /// endpoint, flagged as requiring both an API key and a signature.
+    fn from(request: PositionRequest) -> Self {
+        let mut params = vec![];
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        Request {
+            path: POSITION_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params,
+            requires_api_key: true,
+            requires_signature: true,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Builder for constructing a create-listen-key request.
+#[derive(Default)]
+pub struct CreateListenKeyRequest;
+
+impl CreateListenKeyRequest {
+    /// Creates a new create-listen-key request.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl From<CreateListenKeyRequest> for Request {
+    /// Converts a `CreateListenKeyRequest` into a generic `Request` using the pre-defined user
+    /// data stream endpoint, flagged as requiring an API key but not a signature.
+    fn from(_: CreateListenKeyRequest) -> Self {
+        Request {
+            path: USER_DATA_STREAM_ENDPOINT.to_owned(),
+            method: Method::POST,
+            params: vec![],
+            requires_api_key: true,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Builder for constructing a keep-alive request for an existing listen key.
+pub struct KeepAliveListenKeyRequest {
+    listen_key: String,
+}
+
+impl KeepAliveListenKeyRequest {
+    /// Creates a new keep-alive request for the given listen key.
+    pub fn new(listen_key: &str) -> Self {
+        Self {
+            listen_key: listen_key.to_owned(),
+        }
+    }
+}
+
+impl From<KeepAliveListenKeyRequest> for Request {
+    /// Converts a `KeepAliveListenKeyRequest` into a generic `Request` using the pre-defined
+    /// user data stream endpoint, flagged as requiring an API key but not a signature.
+    fn from(request: KeepAliveListenKeyRequest) -> Self {
+        Request {
+            path: USER_DATA_STREAM_ENDPOINT.to_owned(),
+            method: Method::PUT,
+            params: vec![("listenKey".to_owned(), request.listen_key)],
+            requires_api_key: true,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Builder for constructing a close request for an existing listen key.
+pub struct CloseListenKeyRequest {
+    listen_key: String,
+}
+
+impl CloseListenKeyRequest {
+    /// Creates a new close request for the given listen key.
+    pub fn new(listen_key: &str) -> Self {
+        Self {
+            listen_key: listen_key.to_owned(),
+        }
+    }
+}
+
+impl From<CloseListenKeyRequest> for Request {
+    /// Converts a `CloseListenKeyRequest` into a generic `Request` using the pre-defined user
+    /// data stream endpoint, flagged as requiring an API key but not a signature.
+    fn from(request: CloseListenKeyRequest) -> Self {
+        Request {
+            path: USER_DATA_STREAM_ENDPOINT.to_owned(),
+            method: Method::DELETE,
+            params: vec![("listenKey".to_owned(), request.listen_key)],
+            requires_api_key: true,
+            requires_signature: false,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Order side, as accepted by the `side` parameter of the order placement endpoint, and as
+/// returned in the `side` field of order responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum OrderSide {
+    /// Buy (open a long position or close a short one).
+    #[serde(rename = "BUY")]
+    Buy,
+    /// Sell (open a short position or close a long one).
+    #[serde(rename = "SELL")]
+    Sell,
+}
+
+impl OrderSide {
+    fn as_param(self) -> &'static str {
+        match self {
+            OrderSide::Buy => "BUY",
+            OrderSide::Sell => "SELL",
+        }
+    }
+}
+
+/// Order type, as accepted by the `type` parameter of the order placement endpoint, and as
+/// returned in the `type` field of order responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum OrderType {
+    /// A limit order, executed at `price` or better.
+    #[serde(rename = "LIMIT")]
+    Limit,
+    /// A market order, executed immediately at the best available price.
+    #[serde(rename = "MARKET")]
+    Market,
+}
+
+impl OrderType {
+    fn as_param(self) -> &'static str {
+        match self {
+            OrderType::Limit => "LIMIT",
+            OrderType::Market => "MARKET",
+        }
+    }
+}
+
+/// Time-in-force policy for a limit order, and as returned in the `timeInForce` field of order
+/// responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+pub enum TimeInForce {
+    /// Good-till-cancelled: remains open until filled or cancelled.
+    #[serde(rename = "GTC")]
+    GoodTillCancelled,
+    /// Immediate-or-cancel: fills what it can immediately, cancels the rest.
+    #[serde(rename = "IOC")]
+    ImmediateOrCancel,
+    /// Fill-or-kill: fills entirely and immediately, or is cancelled.
+    #[serde(rename = "FOK")]
+    FillOrKill,
+}
+
+impl TimeInForce {
+    fn as_param(self) -> &'static str {
+        match self {
+            TimeInForce::GoodTillCancelled => "GTC",
+            TimeInForce::ImmediateOrCancel => "IOC",
+            TimeInForce::FillOrKill => "FOK",
+        }
+    }
+}
+
+/// Builder for constructing a new order placement request.
+pub struct NewOrderRequest {
+    symbol: String,
+    side: OrderSide,
+    order_type: OrderType,
+    quantity: String,
+    price: Option<String>,
+    time_in_force: Option<TimeInForce>,
+    client_order_id: Option<String>,
+}
+
+impl NewOrderRequest {
+    /// Creates a new order request with the required fields.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The option symbol to trade.
+    /// * `side` - Buy or sell.
+    /// * `order_type` - Limit or market.
+    /// * `quantity` - The order quantity, as a decimal string.
+    pub fn new(symbol: &str, side: OrderSide, order_type: OrderType, quantity: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            side,
+            order_type,
+            quantity: quantity.into(),
+            price: None,
+            time_in_force: None,
+            client_order_id: None,
+        }
+    }
+
+    /// Sets the limit price. Required for `OrderType::Limit` orders.
+    pub fn price(mut self, price: impl Into<String>) -> Self {
+        self.price = Some(price.into());
+        self
+    }
+
+    /// Sets the time-in-force policy. Only meaningful for `OrderType::Limit` orders.
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Sets a client-supplied order ID, for idempotent order tracking.
+    pub fn client_order_id(mut self, client_order_id: impl Into<String>) -> Self {
+        self.client_order_id = Some(client_order_id.into());
+        self
+    }
+
+    /// Validates `price` (if set) against `contract`'s `tickSize`, `quantity` against its
+    /// `stepSize`, and the notional (`price * quantity`, when a price is set) against its
+    /// `minNotional`, so avoidable rejections are caught before the request ever reaches the
+    /// exchange. `contract` should be the entry for this order's underlying from a recent
+    /// [`crate::model::ExchangeInfo`], with its [`crate::model::ContractFilters`] populated.
+    pub fn validate(&self, contract: &OptionContract) -> Result<(), OrderValidationError> {
+        let filters = contract
+            .filters
+            .as_ref()
+            .ok_or(OrderValidationError::MissingFilters)?;
+
+        let quantity = parse_decimal_field("quantity", &self.quantity)?;
+        check_multiple_of("quantity", "stepSize", quantity, &filters.step_size)?;
+
+        if let Some(price) = &self.price {
+            let price = parse_decimal_field("price", price)?;
+            check_multiple_of("price", "tickSize", price, &filters.tick_size)?;
+
+            let min_notional = parse_decimal_field("minNotional", &filters.min_notional)?;
+            let notional = price * quantity;
+            if notional < min_notional {
+                return Err(OrderValidationError::BelowMinNotional {
+                    notional,
+                    min_notional,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Error returned by [`NewOrderRequest::validate`].
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum OrderValidationError {
+    /// The contract being validated against has no [`crate::model::ContractFilters`] to check.
+    #[error("contract has no filters to validate against")]
+    MissingFilters,
+    /// A decimal-string field on the order or its contract filters wasn't a valid number.
+    #[error("field {field:?} is not a valid decimal: {value:?}")]
+    InvalidDecimal {
+        /// The name of the offending field, e.g. `"price"`.
+        field: &'static str,
+        /// The unparseable value.
+        value: String,
+    },
+    /// `price` isn't a multiple of the contract's `tickSize`.
+    #[error("price {price} is not a multiple of tick size {tick_size}")]
+    PriceNotOnTick {
+        /// The order's price.
+        price: rust_decimal::Decimal,
+        /// The contract's tick size.
+        tick_size: rust_decimal::Decimal,
+    },
+    /// `quantity` isn't a multiple of the contract's `stepSize`.
+    #[error("quantity {quantity} is not a multiple of step size {step_size}")]
+    QuantityNotOnStep {
+        /// The order's quantity.
+        quantity: rust_decimal::Decimal,
+        /// The contract's step size.
+        step_size: rust_decimal::Decimal,
+    },
+    /// `price * quantity` is below the contract's `minNotional`.
+    #[error("notional {notional} is below the minimum of {min_notional}")]
+    BelowMinNotional {
+        /// The order's notional value.
+        notional: rust_decimal::Decimal,
+        /// The contract's minimum notional.
+        min_notional: rust_decimal::Decimal,
+    },
+}
+
+fn parse_decimal_field(
+    field: &'static str,
+    value: &str,
+) -> Result<rust_decimal::Decimal, OrderValidationError> {
+    value
+        .parse()
+        .map_err(|_| OrderValidationError::InvalidDecimal {
+            field,
+            value: value.to_owned(),
+        })
+}
+
+fn check_multiple_of(
+    value_field: &'static str,
+    increment_field: &'static str,
+    value: rust_decimal::Decimal,
+    increment: &str,
+) -> Result<(), OrderValidationError> {
+    let increment = parse_decimal_field(increment_field, increment)?;
+    if !increment.is_zero() && (value % increment) != rust_decimal::Decimal::ZERO {
+        return Err(match value_field {
+            "price" => OrderValidationError::PriceNotOnTick {
+                price: value,
+                tick_size: increment,
+            },
+            _ => OrderValidationError::QuantityNotOnStep {
+                quantity: value,
+                step_size: increment,
+            },
+        });
+    }
+    Ok(())
+}
+
+impl From<NewOrderRequest> for Request {
+    /// Converts a `NewOrderRequest` into a generic `Request` using the pre-defined order
+    /// endpoint, flagged as requiring both an API key and a signature.
+    fn from(request: NewOrderRequest) -> Self {
+        let mut params = vec![
+            ("symbol".to_owned(), request.symbol),
+            ("side".to_owned(), request.side.as_param().to_owned()),
+            ("type".to_owned(), request.order_type.as_param().to_owned()),
+            ("quantity".to_owned(), request.quantity),
+        ];
+
+        if let Some(price) = request.price {
+            params.push(("price".to_owned(), price));
+        }
+        if let Some(time_in_force) = request.time_in_force {
+            params.push(("timeInForce".to_owned(), time_in_force.as_param().to_owned()));
+        }
+        if let Some(client_order_id) = request.client_order_id {
+            params.push(("clientOrderId".to_owned(), client_order_id));
+        }
+
+        Request {
+            path: ORDER_ENDPOINT.to_owned(),
+            method: Method::POST,
+            params,
+            requires_api_key: true,
+            requires_signature: true,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Builder for constructing a cancel-order request.
+pub struct CancelOrderRequest {
+    symbol: String,
+    order_id: i64,
+}
+
+impl CancelOrderRequest {
+    /// Creates a new cancel-order request for the given symbol and order ID.
+    pub fn new(symbol: &str, order_id: i64) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            order_id,
+        }
+    }
+}
+
+impl From<CancelOrderRequest> for Request {
+    /// Converts a `CancelOrderRequest` into a generic `Request` using the pre-defined order
+    /// endpoint, flagged as requiring both an API key and a signature.
+    fn from(request: CancelOrderRequest) -> Self {
+        Request {
+            path: ORDER_ENDPOINT.to_owned(),
+            method: Method::DELETE,
+            params: vec![
+                ("symbol".to_owned(), request.symbol),
+                ("orderId".to_owned(), request.order_id.to_string()),
+            ],
+            requires_api_key: true,
+            requires_signature: true,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Builder for constructing a cancel-all-open-orders request.
+pub struct CancelAllOrdersRequest {
+    symbol: String,
+}
+
+impl CancelAllOrdersRequest {
+    /// Creates a new cancel-all-open-orders request for the given symbol.
+    pub fn new(symbol: &str) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+        }
+    }
+}
+
+impl From<CancelAllOrdersRequest> for Request {
+    /// Converts a `CancelAllOrdersRequest` into a generic `Request` using the pre-defined
+    /// cancel-all-orders endpoint, flagged as requiring both an API key and a signature.
+    fn from(request: CancelAllOrdersRequest) -> Self {
+        Request {
+            path: CANCEL_ALL_ORDERS_ENDPOINT.to_owned(),
+            method: Method::DELETE,
+            params: vec![("symbol".to_owned(), request.symbol)],
+            requires_api_key: true,
+            requires_signature: true,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Builder for constructing a currently-open-orders request.
+pub struct OpenOrdersRequest {
+    symbol: Option<String>,
+}
+
+impl OpenOrdersRequest {
+    /// Creates a new open-orders request without any symbol filter.
+    pub fn new() -> Self {
+        Self { symbol: None }
+    }
+
+    /// Restricts the request to open orders for a single symbol.
+    pub fn symbol(mut self, symbol: &str) -> Self {
+        self.symbol = Some(symbol.to_owned());
+        self
+    }
+}
+
+impl Default for OpenOrdersRequest {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<OpenOrdersRequest> for Request {
+    /// Converts an `OpenOrdersRequest` into a generic `Request` using the pre-defined open
+    /// orders endpoint, flagged as requiring both an API key and a signature.
+    fn from(request: OpenOrdersRequest) -> Self {
+        let mut params = vec![];
+
+        if let Some(symbol) = request.symbol {
+            params.push(("symbol".to_owned(), symbol));
+        }
+
+        Request {
+            path: OPEN_ORDERS_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params,
+            requires_api_key: true,
+            requires_signature: true,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Time-range and result-size bounds shared by endpoints that return historical records.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Pagination {
+    start: Option<i64>,
+    end: Option<i64>,
+    limit: Option<u32>,
+}
+
+impl Pagination {
+    /// Creates an unbounded pagination (no time range or limit).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the start of the time range, as a millisecond timestamp.
+    pub fn start(mut self, start: i64) -> Self {
+        self.start = Some(start);
+        self
+    }
+
+    /// Sets the end of the time range, as a millisecond timestamp.
+    pub fn end(mut self, end: i64) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Caps the number of records returned.
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+}
+
+/// Scales `interval` by a pseudo-random fraction in `[0.9, 1.1)`, seeded from the current time to
+/// avoid pulling in a dependency on `rand` (the same trick [`RetryPolicy::delay_for_attempt`]
+/// uses for backoff jitter). Spreads out clients that all start polling at the same moment.
+fn jittered_interval(interval: Duration) -> Duration {
+    let jitter_seed = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .subsec_nanos();
+    let jitter_fraction = 0.9 + f64::from(jitter_seed % 1_000) / 1_000.0 * 0.2;
+    interval.mul_f64(jitter_fraction)
+}
+
+/// Repeatedly calls `fetch` with an advancing millisecond-timestamp cursor, yielding each item
+/// of every returned page until a page comes back empty. `cursor_after` extracts the timestamp
+/// of the last item of a page; the next fetch starts one millisecond past it, so that item isn't
+/// fetched again.
+///
+/// Backs [`BinanceOptionsClient::get_order_history_stream`] and
+/// [`BinanceOptionsClient::get_exercise_history_stream`]. This client has no endpoint with a
+/// `fromId` cursor (only time-range pagination), so that's the only cursor style implemented
+/// here.
+fn paginate_by_time<T, Fetch, Fut, CursorAfter>(
+    start: Option<i64>,
+    fetch: Fetch,
+    cursor_after: CursorAfter,
+) -> impl futures_util::Stream<Item = Result<T, BinanceOptionsClientError>>
+where
+    Fetch: Fn(Option<i64>) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, BinanceOptionsClientError>>,
+    CursorAfter: Fn(&T) -> i64,
+{
+    let queued: std::collections::VecDeque<T> = std::collections::VecDeque::new();
+    futures_util::stream::unfold(
+        Some((start, queued, fetch, cursor_after)),
+        |state| async move {
+            let (mut cursor, mut queued, fetch, cursor_after) = state?;
+            loop {
+                if let Some(item) = queued.pop_front() {
+                    return Some((Ok(item), Some((cursor, queued, fetch, cursor_after))));
+                }
+                match fetch(cursor).await {
+                    Ok(page) if page.is_empty() => return None,
+                    Ok(page) => {
+                        cursor = Some(cursor_after(page.last().expect("just checked non-empty")) + 1);
+                        queued.extend(page);
+                    }
+                    Err(error) => return Some((Err(error), None)),
+                }
+            }
+        },
+    )
+}
+
+/// Builder for constructing a historical order request.
+pub struct OrderHistoryRequest {
+    symbol: String,
+    pagination: Pagination,
+}
+
+impl OrderHistoryRequest {
+    /// Creates a new order history request for the given symbol.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The option symbol to fetch order history for.
+    /// * `pagination` - The time range and result-size bounds to apply.
+    pub fn new(symbol: &str, pagination: Pagination) -> Self {
+        Self {
+            symbol: symbol.to_owned(),
+            pagination,
+        }
+    }
+}
+
+impl From<OrderHistoryRequest> for Request {
+    /// Converts an `OrderHistoryRequest` into a generic `Request` using the pre-defined order
+    /// history endpoint, flagged as requiring both an API key and a signature.
+    fn from(request: OrderHistoryRequest) -> Self {
+        let mut params = vec![("symbol".to_owned(), request.symbol)];
+
+        if let Some(start) = request.pagination.start {
+            params.push(("startTime".to_owned(), start.to_string()));
+        }
+        if let Some(end) = request.pagination.end {
+            params.push(("endTime".to_owned(), end.to_string()));
+        }
+        if let Some(limit) = request.pagination.limit {
+            params.push(("limit".to_owned(), limit.to_string()));
+        }
+
+        Request {
+            path: ORDER_HISTORY_ENDPOINT.to_owned(),
+            method: Method::GET,
+            params,
+            requires_api_key: true,
+            requires_signature: true,
+            timeout: None,
+            headers: Vec::new(),
+        }
+    }
+}
+
+/// Which Binance Options network a client targets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Network {
+    /// The production options API.
+    #[default]
+    Mainnet,
+    /// The options testnet, for paper trading and integration tests.
+    Testnet,
+}
+
+impl Network {
+    fn base_url(self) -> &'static str {
+        match self {
+            Network::Mainnet => BASE_URL,
+            Network::Testnet => TESTNET_BASE_URL,
+        }
+    }
+}
+
+/// Builder for constructing a [`BinanceOptionsClient`] with custom credentials, networking
+/// configuration, or an injected `reqwest::Client`.
+pub struct ClientBuilder {
+    base_url: String,
+    base_url_pool: Option<std::sync::Arc<crate::failover::BaseUrlPool>>,
+    api_key: Option<String>,
+    secret_key: Option<String>,
+    timeout: Option<std::time::Duration>,
+    connect_timeout: Option<std::time::Duration>,
+    user_agent: Option<String>,
+    client: Option<Client>,
+    recv_window: Option<u64>,
+    rate_limit: Option<u32>,
+    retry_policy: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreaker>,
+    interceptors: Vec<Box<dyn RequestInterceptor>>,
+    transport: Option<Box<dyn HttpTransport>>,
+    response_cache: Option<(usize, Duration)>,
+    compression: bool,
+    throttle: Option<std::sync::Arc<crate::throttle::RequestThrottle>>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    tcp_keepalive: Option<Duration>,
+    http2_prior_knowledge: bool,
+    symbol_validator: Option<std::sync::Arc<crate::symbol_validation::SymbolValidator>>,
+}
+
+impl ClientBuilder {
+    /// Creates a new builder targeting the production Binance Options API, with no credentials.
+    pub fn new() -> Self {
+        Self {
+            base_url: BASE_URL.to_string(),
+            base_url_pool: None,
+            api_key: None,
+            secret_key: None,
+            timeout: None,
+            user_agent: None,
+            connect_timeout: None,
+            client: None,
+            recv_window: None,
+            rate_limit: None,
+            retry_policy: None,
+            circuit_breaker: None,
+            interceptors: Vec::new(),
+            transport: None,
+            response_cache: None,
+            compression: true,
+            throttle: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            tcp_keepalive: None,
+            http2_prior_knowledge: false,
+            symbol_validator: None,
+        }
+    }
+
+    /// Sets the API key, sent as the `X-MBX-APIKEY` header on API-key-authenticated endpoints.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the API secret, used to HMAC-SHA256 sign the query string of signed endpoints.
+    pub fn secret_key(mut self, secret_key: impl Into<String>) -> Self {
+        self.secret_key = Some(secret_key.into());
+        self
+    }
+
+    /// Reads `BINANCE_API_KEY` and `BINANCE_API_SECRET` from the environment, using whichever
+    /// of the two are present as credentials.
+    pub fn credentials_from_env(mut self) -> Self {
+        if let Ok(api_key) = std::env::var("BINANCE_API_KEY") {
+            self.api_key = Some(api_key);
+        }
+        if let Ok(secret_key) = std::env::var("BINANCE_API_SECRET") {
+            self.secret_key = Some(secret_key);
+        }
+        self
+    }
+
+    /// Overrides the base URL (defaults to the production Binance Options API).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Selects the base URL from a known [`Network`] (mainnet or testnet).
+    pub fn network(mut self, network: Network) -> Self {
+        self.base_url = network.base_url().to_string();
+        self
+    }
+
+    /// Configures an ordered list of base URLs with automatic failover, overriding
+    /// [`Self::base_url`]/[`Self::network`]. Every request targets the pool's current URL;
+    /// after `failure_threshold` consecutive failures against it, the pool advances to the next
+    /// URL (wrapping back to the first after the last), so an always-on collector stays up
+    /// through an outage of a single endpoint.
+    pub fn base_url_pool(mut self, urls: Vec<String>, failure_threshold: u32) -> Self {
+        self.base_url_pool = Some(std::sync::Arc::new(crate::failover::BaseUrlPool::new(
+            urls,
+            failure_threshold,
+        )));
+        self
+    }
+
+    /// Validates a request's `symbol` parameter against `validator`'s cached `exchangeInfo`
+    /// contract list before sending it, failing locally with
+    /// `BinanceOptionsClientError::UnknownSymbol` instead of spending a round trip to learn the
+    /// same thing from Binance's `-1121`. Pass an `Arc` so the same validator (and its cache)
+    /// can be shared and refreshed across several client instances.
+    pub fn symbol_validator(
+        mut self,
+        validator: std::sync::Arc<crate::symbol_validation::SymbolValidator>,
+    ) -> Self {
+        self.symbol_validator = Some(validator);
+        self
+    }
+
+    /// Sets the timeout applied to every request (time to receive the full response).
+    pub fn timeout(mut self, timeout: std::time::Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout for establishing the underlying TCP/TLS connection.
+    pub fn connect_timeout(mut self, connect_timeout: std::time::Duration) -> Self {
+        self.connect_timeout = Some(connect_timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Supplies a pre-configured `reqwest::Client`, overriding `timeout`, `user_agent`,
+    /// `compression`, and the connection pool/keep-alive settings below.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Caps the number of idle connections per host kept open in the pool (`reqwest`'s default
+    /// is unbounded). Lowering this trades p99 latency on bursty polling workloads (a fresh
+    /// connection to re-establish after an idle period) for fewer sockets held open against an
+    /// endpoint you call rarely.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how long an idle pooled connection is kept before being closed (`reqwest` defaults
+    /// to 90 seconds). A polling loop with a period shorter than this keeps its connection warm
+    /// between requests, avoiding a new TCP/TLS handshake's latency on every poll.
+    pub fn pool_idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Enables TCP keepalive with the given interval on every socket, so a silently-dropped
+    /// connection (e.g. a NAT timeout) is detected and replaced instead of hanging a request
+    /// until the client's own timeout fires.
+    pub fn tcp_keepalive(mut self, keepalive: Duration) -> Self {
+        self.tcp_keepalive = Some(keepalive);
+        self
+    }
+
+    /// Skips the usual HTTP/1.1-then-upgrade negotiation and assumes the server speaks HTTP/2
+    /// directly. Only set this against an endpoint known to support HTTP/2 with prior knowledge
+    /// (plaintext HTTP/2, or an ALPN-less connection); the wrong setting here will fail every
+    /// request rather than silently falling back.
+    pub fn http2_prior_knowledge(mut self, enabled: bool) -> Self {
+        self.http2_prior_knowledge = enabled;
+        self
+    }
+
+    /// Whether to negotiate and transparently decompress gzip/deflate responses. Enabled by
+    /// default, which materially reduces transfer time for large responses like the full
+    /// ticker list. `send`/`get_ticker_raw`/`get_ticker_stream` all see the decompressed body
+    /// either way; disabling this only stops the client from advertising `Accept-Encoding` in
+    /// the first place, for talking to a proxy or fixture server that mishandles it.
+    pub fn compression(mut self, enabled: bool) -> Self {
+        self.compression = enabled;
+        self
+    }
+
+    /// Sets the `recvWindow` (in milliseconds) sent with signed requests. If unset, Binance
+    /// applies its own default.
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    /// Enables client-side request-weight rate limiting, using Binance's documented default
+    /// per-minute weight limit.
+    pub fn rate_limited(mut self) -> Self {
+        self.rate_limit = Some(rate_limit::DEFAULT_WEIGHT_LIMIT_PER_MINUTE);
+        self
+    }
+
+    /// Enables client-side request-weight rate limiting with a custom per-minute weight limit,
+    /// e.g. one seeded from a live `exchangeInfo` response.
+    pub fn rate_limit(mut self, weight_per_minute: u32) -> Self {
+        self.rate_limit = Some(weight_per_minute);
+        self
+    }
+
+    /// Enables retrying transient failures (network errors, 5xx responses, and 429s) according
+    /// to `retry_policy`. Non-retryable API errors always surface immediately.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Installs a circuit breaker that fast-fails `send_request` once too many consecutive
+    /// failures (or a ban response) have been observed, protecting the rest of the trading
+    /// system from hammering a failing endpoint. Current state is exposed via
+    /// [`BinanceOptionsClient::health`].
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Paces outgoing requests against `throttle`, independent of [`Self::rate_limit`]'s
+    /// weight-based accounting. Pass the same `Arc` to several builders to pace those clients'
+    /// combined request rate, e.g. for multiple instances running behind one IP.
+    pub fn throttle(mut self, throttle: std::sync::Arc<crate::throttle::RequestThrottle>) -> Self {
+        self.throttle = Some(throttle);
+        self
+    }
+
+    /// Registers a [`RequestInterceptor`], run around every request attempt in registration
+    /// order. Useful for custom headers, request/response logging, latency measurement, and
+    /// test instrumentation, without modifying `send_request` itself.
+    pub fn interceptor(mut self, interceptor: impl RequestInterceptor + 'static) -> Self {
+        self.interceptors.push(Box::new(interceptor));
+        self
+    }
+
+    /// Installs a custom [`HttpTransport`], overriding `http_client`/`timeout`/`user_agent`/
+    /// `connect_timeout` for requests sent through [`BinanceOptionsClient::send_request`]. Lets
+    /// unit tests inject canned responses and error cases without network access. Byte-streaming
+    /// endpoints like `get_ticker_stream` bypass `HttpTransport` and always use a real
+    /// `reqwest::Client`, since a custom transport can't stream an unbuffered response body.
+    pub fn transport(mut self, transport: impl HttpTransport + 'static) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Wraps whichever transport ends up installed (custom or the default `reqwest`-backed one)
+    /// in a [`crate::response_cache::CachingTransport`], caching up to `capacity` distinct GET
+    /// responses (keyed by URL and query params) for `ttl` each. Useful for endpoints like
+    /// `exchangeInfo` or ticker snapshots that don't need to be re-fetched on every call.
+    pub fn response_cache(mut self, capacity: usize, ttl: std::time::Duration) -> Self {
+        self.response_cache = Some((capacity, ttl));
+        self
+    }
+
+    /// Builds the `BinanceOptionsClient`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::Unknown` if a `reqwest::Client` could not be built
+    /// from the given `timeout`/`user_agent` settings.
+    pub fn build(self) -> Result<BinanceOptionsClient, BinanceOptionsClientError> {
+        info!(
+            "Creating new BinanceOptionsClient with base URL: {}{}",
+            self.base_url,
+            if self.api_key.is_some() {
+                " (with credentials)"
+            } else {
+                ""
+            }
+        );
+
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder()
+                    .gzip(self.compression)
+                    .deflate(self.compression);
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(connect_timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(connect_timeout);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if let Some(pool_max_idle_per_host) = self.pool_max_idle_per_host {
+                    builder = builder.pool_max_idle_per_host(pool_max_idle_per_host);
+                }
+                if let Some(pool_idle_timeout) = self.pool_idle_timeout {
+                    builder = builder.pool_idle_timeout(pool_idle_timeout);
+                }
+                if let Some(tcp_keepalive) = self.tcp_keepalive {
+                    builder = builder.tcp_keepalive(tcp_keepalive);
+                }
+                if self.http2_prior_knowledge {
+                    builder = builder.http2_prior_knowledge();
+                }
+                builder.build().map_err(|e| {
+                    BinanceOptionsClientError::Unknown(format!(
+                        "failed to build HTTP client: {e}"
+                    ))
+                })?
+            }
+        };
+
+        let transport: Box<dyn HttpTransport> = match self.transport {
+            Some(transport) => transport,
+            None => Box::new(ReqwestTransport {
+                client: client.clone(),
+            }),
+        };
+        let transport: Box<dyn HttpTransport> = match self.response_cache {
+            Some((capacity, ttl)) => Box::new(crate::response_cache::CachingTransport::new(
+                transport, capacity, ttl,
+            )),
+            None => transport,
+        };
+
+        Ok(BinanceOptionsClient {
+            client,
+            transport,
+            base_url: self.base_url,
+            base_url_pool: self.base_url_pool,
+            api_key: self.api_key,
+            secret_key: self.secret_key,
+            recv_window: self.recv_window,
+            clock_offset_ms: AtomicI64::new(0),
+            rate_limiter: self.rate_limit.map(RateLimiter::with_limit),
+            throttle: self.throttle,
+            retry_policy: self.retry_policy,
+            circuit_breaker: self.circuit_breaker,
+            last_used_weight: std::sync::atomic::AtomicU32::new(0),
+            interceptors: self.interceptors,
+            symbol_validator: self.symbol_validator,
+        })
+    }
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Default for BinanceOptionsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BinanceOptionsClient {
+    /// Creates a new instance of `BinanceOptionsClient` without API credentials.
+    ///
+    /// Suitable for public market-data endpoints only; calling a signed or
+    /// API-key-authenticated endpoint (e.g. [`BinanceOptionsClient::get_account`]) with a
+    /// client built this way returns `BinanceOptionsClientError::MissingCredentials`.
+    ///
+    /// For credentials, a custom base URL, timeouts, or a custom `reqwest::Client`, use
+    /// [`BinanceOptionsClient::builder`] instead.
+    pub fn new() -> Self {
+        ClientBuilder::new()
+            .build()
+            .expect("default client configuration is always valid")
+    }
+
+    /// Creates a new instance of `BinanceOptionsClient` with API credentials, required for
+    /// signed and API-key-authenticated endpoints.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - The API key, sent as the `X-MBX-APIKEY` header.
+    /// * `secret_key` - The API secret, used to HMAC-SHA256 sign request query strings.
+    pub fn with_credentials(api_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        ClientBuilder::new()
+            .api_key(api_key)
+            .secret_key(secret_key)
+            .build()
+            .expect("default client configuration is always valid")
+    }
+
+    /// Creates a new instance of `BinanceOptionsClient` targeting a custom base URL, without
+    /// API credentials.
+    ///
+    /// Useful for pointing at the options testnet (see [`Network::Testnet`]) or a local mock
+    /// server in integration tests.
+    pub fn with_base_url(base_url: impl Into<String>) -> Self {
+        ClientBuilder::new()
+            .base_url(base_url)
+            .build()
+            .expect("default client configuration is always valid")
+    }
+
+    /// Returns a [`ClientBuilder`] for configuring credentials, base URL, timeouts, user agent,
+    /// or a custom `reqwest::Client` before constructing a `BinanceOptionsClient`.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// Returns the current health of the client's circuit breaker, or `None` if no breaker was
+    /// configured via [`ClientBuilder::circuit_breaker`].
+    pub fn health(&self) -> Option<CircuitHealth> {
+        self.circuit_breaker.as_ref().map(|breaker| breaker.health())
+    }
+
+    /// Returns the most recently observed `X-MBX-USED-WEIGHT*` utilization, letting callers
+    /// throttle proactively instead of waiting for a 429/418. Usage is zero until a response
+    /// carrying one of those headers has been seen.
+    pub fn rate_limit_status(&self) -> RateLimitStatus {
+        RateLimitStatus {
+            used_weight: self.last_used_weight.load(Ordering::Relaxed),
+            limit: self.rate_limiter.as_ref().map(RateLimiter::limit),
+        }
+    }
+
+    /// Returns the base URL the next request should target: the current URL of
+    /// `base_url_pool` if one was configured via [`ClientBuilder::base_url_pool`], otherwise
+    /// the plain `base_url`.
+    fn effective_base_url(&self) -> &str {
+        self.base_url_pool
+            .as_deref()
+            .map_or(self.base_url.as_str(), crate::failover::BaseUrlPool::current)
+    }
+
+    /// Records the highest value across all `X-MBX-USED-WEIGHT*` response headers, and warns
+    /// once utilization crosses 80% of the configured rate limit.
+    fn record_used_weight(&self, headers: &reqwest::header::HeaderMap) {
+        let used_weight = headers
+            .iter()
+            .filter(|(name, _)| {
+                name.as_str()
+                    .to_ascii_lowercase()
+                    .starts_with("x-mbx-used-weight")
+            })
+            .filter_map(|(_, value)| value.to_str().ok()?.parse::<u32>().ok())
+            .max();
+
+        let Some(used_weight) = used_weight else {
+            return;
+        };
+        self.last_used_weight.store(used_weight, Ordering::Relaxed);
+
+        let status = self.rate_limit_status();
+        if status.is_near_limit(0.8) {
+            warn!(
+                "Rate limit utilization at {}/{:?}, nearing the configured limit",
+                status.used_weight, status.limit
+            );
+        }
+    }
+
+    /// Runs every registered [`RequestInterceptor::after`] hook for one completed attempt.
+    fn run_after_interceptors(
+        &self,
+        request: &Request,
+        status: Option<reqwest::StatusCode>,
+        elapsed: Duration,
+    ) {
+        if self.interceptors.is_empty() {
+            return;
+        }
+        let response = ResponseSummary { status, elapsed };
+        for interceptor in &self.interceptors {
+            interceptor.after(request, &response);
+        }
+    }
+
+    /// Sends an HTTP request to the Binance Options API and returns the deserialized response.
+    ///
+    /// Honors `request.requires_api_key` (adds the `X-MBX-APIKEY` header) and
+    /// `request.requires_signature` (appends a `timestamp` and HMAC-SHA256 `signature`
+    /// query parameter), using the credentials the client was constructed with.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `T` - The type into which the response will be deserialized.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the request requires credentials the client
+    /// wasn't constructed with, the network request fails, the response status is
+    /// unsuccessful, or JSON parsing fails.
+    pub async fn send_request<T: DeserializeOwned>(
+        &self,
+        request: Request,
+    ) -> Result<T, BinanceOptionsClientError> {
+        if let Some(circuit_breaker) = &self.circuit_breaker
+            && !circuit_breaker.allow_request()
+        {
+            return Err(BinanceOptionsClientError::CircuitOpen);
+        }
+
+        if let Some(validator) = &self.symbol_validator
+            && let Some((_, symbol)) = request.params.iter().find(|(name, _)| name == "symbol")
+        {
+            validator.validate(symbol)?;
+        }
+
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter
+                .acquire(rate_limit::weight_for_path(&request.path))
+                .await?;
+        }
+
+        if let Some(throttle) = &self.throttle {
+            throttle.acquire().await;
+        }
+
+        let max_attempts = self.retry_policy.map_or(1, |policy| policy.max_attempts);
+        let mut attempt = 0;
+        let request_id = next_request_id();
+
+        loop {
+            attempt += 1;
+
+            match self.execute_request::<T>(&request, &request_id).await {
+                Ok(data) => {
+                    if let Some(circuit_breaker) = &self.circuit_breaker {
+                        circuit_breaker.record_success();
+                    }
+                    if let Some(base_url_pool) = &self.base_url_pool {
+                        base_url_pool.record_success();
+                    }
+                    return Ok(data);
+                }
+                Err((error, retry_after)) => {
+                    let policy = match self.retry_policy {
+                        Some(policy) if attempt < max_attempts && retry::is_retryable(&error) => {
+                            policy
+                        }
+                        _ => {
+                            if let Some(circuit_breaker) = &self.circuit_breaker {
+                                if matches!(
+                                    &error,
+                                    BinanceOptionsClientError::HttpResponse { code, .. }
+                                        if code.as_u16() == 418
+                                ) {
+                                    circuit_breaker.report_ban();
+                                } else {
+                                    circuit_breaker.record_failure();
+                                }
+                            }
+                            if let Some(base_url_pool) = &self.base_url_pool {
+                                base_url_pool.record_failure();
+                            }
+                            return Err(error);
+                        }
+                    };
+
+                    let delay = policy.delay_for_attempt(attempt, retry_after);
+                    warn!(
+                        "[{}] Retrying request to {} after {:?} (attempt {} of {}): {}",
+                        request_id,
+                        request.path,
+                        delay,
+                        attempt + 1,
+                        max_attempts,
+                        error
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+            }
+        }
+    }
+
+    /// Executes a single attempt of `request`: signs it (if required), sends it, and
+    /// deserializes the response. Does not retry; [`BinanceOptionsClient::send_request`] owns
+    /// the retry loop so it can inspect the error and any `Retry-After` hint between attempts.
+    async fn execute_request<T: DeserializeOwned>(
+        &self,
+        request: &Request,
+        request_id: &str,
+    ) -> Result<T, (BinanceOptionsClientError, Option<Duration>)> {
+        let mut request = request.clone();
+        for interceptor in &self.interceptors {
+            interceptor.before(&mut request);
+        }
+
+        let started_at = Instant::now();
+        let mut params = request.params.clone();
+
+        if request.requires_signature {
+            let secret_key = self.secret_key.as_deref().ok_or_else(|| {
+                (
+                    BinanceOptionsClientError::MissingCredentials(
+                        "signed endpoint requires a secret key".to_string(),
+                    ),
+                    None,
+                )
+            })?;
+
+            let local_timestamp_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as i64;
+            let timestamp = local_timestamp_ms + self.clock_offset_ms.load(Ordering::Relaxed);
+            params.push(("timestamp".to_owned(), timestamp.to_string()));
+
+            if let Some(recv_window) = self.recv_window {
+                params.push(("recvWindow".to_owned(), recv_window.to_string()));
+            }
+
+            // Sign the exact bytes that go over the wire: `HttpTransport` sends `params` via
+            // reqwest's `.query()`, which percent-encodes through `serde_urlencoded` — signing
+            // an unencoded `key=value` join here would mismatch whenever a value needs escaping
+            // (spaces, `+`, `=`, etc.) and Binance would reject the signature.
+            let query_string = serde_urlencoded::to_string(&params).map_err(|error| {
+                (
+                    BinanceOptionsClientError::Unknown(format!(
+                        "failed to encode request parameters for signing: {error}"
+                    )),
+                    None,
+                )
+            })?;
+            let signature =
+                crate::signing::hmac_sha256_hex(secret_key.as_bytes(), query_string.as_bytes());
+            params.push(("signature".to_owned(), signature));
+        }
+
+        let url = format!("{}{}", self.effective_base_url(), request.path);
+        debug!(
+            "[{}] Sending request to: {} with method: {:?}",
+            request_id, url, request.method
+        );
+
+        if !params.is_empty() {
+            debug!("[{}] Request parameters: {:?}", request_id, params);
+        }
+
+        if !matches!(
+            request.method,
+            Method::GET | Method::POST | Method::PUT | Method::DELETE
+        ) {
+            error!("[{}] Unsupported HTTP method: {:?}", request_id, request.method);
+            return Err((
+                BinanceOptionsClientError::Unknown("Unsupported HTTP method".to_string()),
+                None,
+            ));
+        }
+
+        let mut headers = request.headers.clone();
+        if request.requires_api_key {
+            let api_key = self.api_key.as_deref().ok_or_else(|| {
+                (
+                    BinanceOptionsClientError::MissingCredentials(
+                        "endpoint requires an API key".to_string(),
+                    ),
+                    None,
+                )
+            })?;
+            headers.push(("X-MBX-APIKEY".to_owned(), api_key.to_owned()));
+        }
+
+        let call = HttpCall {
+            url,
+            method: request.method.clone(),
+            params,
+            headers,
+            timeout: request.timeout,
+        };
+
+        let response = match self.transport.send(&call).await {
+            Ok(response) => response,
+            Err(error) => {
+                error!("[{}] Network error: {}", request_id, error);
+                self.run_after_interceptors(&request, None, started_at.elapsed());
+                return Err((error, None));
+            }
+        };
+
+        self.record_used_weight(&response.headers);
+
+        if !response.status.is_success() {
+            warn!("[{}] Request failed with status: {}", request_id, response.status);
+            let retry_after = response
+                .headers
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_secs);
+            self.run_after_interceptors(&request, Some(response.status), started_at.elapsed());
+            return Err((
+                BinanceOptionsClientError::from_parts(
+                    response.status,
+                    response.body,
+                    retry_after,
+                    Some(request_id.to_string()),
+                ),
+                retry_after,
+            ));
+        }
+
+        debug!(
+            "[{}] Received response (first 200 chars): {}",
+            request_id,
+            response.body.chars().take(200).collect::<String>()
+        );
+
+        let data = match serde_json::from_str(&response.body) {
+            Ok(d) => d,
+            Err(e) => {
+                self.run_after_interceptors(&request, Some(response.status), started_at.elapsed());
+                error!("[{}] JSON parse error: {}", request_id, e);
+                error!("[{}] JSON data: {}", request_id, response.body);
+                return Err((BinanceOptionsClientError::JsonParse(e), None));
+            }
+        };
+
+        self.run_after_interceptors(&request, Some(response.status), started_at.elapsed());
+        info!("[{}] Request completed successfully", request_id);
+        Ok(data)
+    }
+
+    /// Retrieves the Binance Options API server's current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails,
+    /// the response status is unsuccessful, or JSON parsing fails.
+    pub async fn get_server_time(&self) -> Result<ServerTime, BinanceOptionsClientError> {
+        info!("Getting server time");
+
+        let req: Request = ServerTimeRequest::new().into();
+        self.send_request(req).await
+    }
+
+    /// Synchronizes the client's clock against the Binance Options API server time, storing the
+    /// resulting offset so that subsequent signed requests use a corrected `timestamp` even if
+    /// the local clock is skewed. This is what prevents the classic `-1021 Timestamp outside of
+    /// recvWindow` error on machines with drifted clocks.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails,
+    /// the response status is unsuccessful, or JSON parsing fails.
+    pub async fn sync_clock(&self) -> Result<(), BinanceOptionsClientError> {
+        let local_before_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as i64;
+        let server_time = self.get_server_time().await?;
+        let offset = server_time.server_time.timestamp_millis() - local_before_ms;
+
+        info!("Synced clock with server; offset is {} ms", offset);
+        self.clock_offset_ms.store(offset, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Retrieves raw ticker data as a JSON string from the Binance Options API.
+    ///
+    /// # Returns
+    ///
+    /// A string containing the raw JSON response.
+    pub async fn get_ticker_raw(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<String, BinanceOptionsClientError> {
+        let request_id = next_request_id();
+        info!(
+            "[{}] Getting raw ticker data{}",
+            request_id,
+            symbol.map_or(String::new(), |s| format!(" for symbol: {}", s))
+        );
+
+        if let Some(validator) = &self.symbol_validator
+            && let Some(s) = symbol
+        {
+            validator.validate(s)?;
+        }
+
+        // Start with a TickerRequest.
+        let mut ticker_req = TickerRequest::new();
+        if let Some(s) = symbol {
+            ticker_req = ticker_req.symbol(s);
+        }
+
+        // Convert to Request (which has `path` and `params` fields).
+        let req: Request = ticker_req.into();
+
+        let url = format!("{}{}", self.effective_base_url(), req.path);
+        let mut request_builder = self.client.get(&url);
+        if !req.params.is_empty() {
+            request_builder = request_builder.query(&req.params);
+        }
+
+        let response = request_builder
+            .send()
+            .await
+            .map_err(BinanceOptionsClientError::Network)?;
+        if !response.status().is_success() {
+            return Err(BinanceOptionsClientError::from_response(response, Some(request_id)).await);
+        }
+        let text = response
+            .text()
+            .await
+            .map_err(BinanceOptionsClientError::Network)?;
+        Ok(text)
+    }
+
+    /// Retrieves ticker data and deserializes it directly into `OptionTicker`s, for callers who
+    /// don't need the raw JSON [`Self::get_ticker_raw`] returns and would otherwise just parse
+    /// it themselves.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::Network` if the request fails,
+    /// `BinanceOptionsClientError::HttpResponse`/`ApiError` if the response is unsuccessful, and
+    /// `BinanceOptionsClientError::JsonParse` if the body isn't a valid ticker array.
+    pub async fn get_ticker(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<OptionTicker>, BinanceOptionsClientError> {
+        info!(
+            "Getting ticker data{}",
+            symbol.map_or(String::new(), |s| format!(" for symbol: {}", s))
+        );
+
+        let mut ticker_req = TickerRequest::new();
+        if let Some(s) = symbol {
+            ticker_req = ticker_req.symbol(s);
+        }
+        let req: Request = ticker_req.into();
+        self.send_request(req).await
+    }
+
+    /// Retrieves ticker data as a `Stream` of parsed `OptionTicker`s, yielding each one as soon
+    /// as its bytes have arrived rather than buffering the whole response body into a `String`
+    /// first (as [`Self::get_ticker_raw`] plus [`Self::parse_ticker`] do). Network download and
+    /// JSON parsing overlap, which matters for the ~1400-entry ticker list.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::Network` if the initial request fails, and
+    /// `BinanceOptionsClientError::HttpResponse` or `ApiError` if the response status is
+    /// unsuccessful. The stream then yields `Network` if a later chunk read fails, and
+    /// `BinanceOptionsClientError::JsonParse` or `Unknown` if an element isn't valid ticker JSON.
+    pub async fn get_ticker_stream(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<
+        impl futures_util::Stream<Item = Result<OptionTicker, BinanceOptionsClientError>>,
+        BinanceOptionsClientError,
+    > {
+        let request_id = next_request_id();
+        info!(
+            "[{}] Streaming ticker data{}",
+            request_id,
+            symbol.map_or(String::new(), |s| format!(" for symbol: {}", s))
+        );
+
+        if let Some(validator) = &self.symbol_validator
+            && let Some(s) = symbol
+        {
+            validator.validate(s)?;
+        }
+
+        let mut ticker_req = TickerRequest::new();
+        if let Some(s) = symbol {
+            ticker_req = ticker_req.symbol(s);
+        }
+        let req: Request = ticker_req.into();
+
+        let url = format!("{}{}", self.effective_base_url(), req.path);
+        let mut request_builder = self.client.get(&url);
+        if !req.params.is_empty() {
+            request_builder = request_builder.query(&req.params);
+        }
+
+        let response = request_builder
+            .send()
+            .await
+            .map_err(BinanceOptionsClientError::Network)?;
+        if !response.status().is_success() {
+            return Err(BinanceOptionsClientError::from_response(response, Some(request_id)).await);
+        }
+
+        let byte_stream = Box::pin(response.bytes_stream());
+        let splitter = crate::ticker_stream::ArraySplitter::default();
+        let queued: std::collections::VecDeque<String> = std::collections::VecDeque::new();
+
+        Ok(futures_util::stream::unfold(
+            Some((byte_stream, splitter, queued)),
+            |state| async move {
+                use futures_util::StreamExt;
+
+                let (mut byte_stream, mut splitter, mut queued) = state?;
+                loop {
+                    if let Some(element) = queued.pop_front() {
+                        let parsed = serde_json::from_str(&element)
+                            .map_err(BinanceOptionsClientError::JsonParse);
+                        return Some((parsed, Some((byte_stream, splitter, queued))));
+                    }
+
+                    match byte_stream.next().await {
+                        Some(Ok(chunk)) => match splitter.feed(&chunk) {
+                            Ok(elements) => {
+                                queued.extend(elements);
+                            }
+                            Err(error) => return Some((Err(error), None)),
+                        },
+                        Some(Err(error)) => {
+                            return Some((
+                                Err(BinanceOptionsClientError::Network(error)),
+                                None,
+                            ));
+                        }
+                        None => return None,
+                    }
+                }
+            },
+        ))
+    }
+
+    /// Fetches ticker data for several symbols concurrently, bounding how many requests are
+    /// in flight at once to `max_concurrency`, and aggregates the results. Each symbol is
+    /// fetched independently, so one symbol's failure (e.g. an invalid symbol) doesn't prevent
+    /// the others from succeeding.
+    ///
+    /// # Returns
+    ///
+    /// A tuple of the successfully-parsed tickers (order not guaranteed to match `symbols`,
+    /// since requests complete in whatever order the network returns them) and a
+    /// [`TickerFetchError`] per symbol whose request failed.
+    pub async fn get_tickers_for(
+        &self,
+        symbols: &[&str],
+        max_concurrency: usize,
+    ) -> (Vec<OptionTicker>, Vec<TickerFetchError>) {
+        use futures_util::StreamExt;
+
+        let fetches = symbols.iter().map(|&symbol| async move {
+            let request: Request = TickerRequest::new().symbol(symbol).into();
+            self.send_request::<Vec<OptionTicker>>(request)
+                .await
+                .map_err(|error| TickerFetchError {
+                    symbol: symbol.to_string(),
+                    error,
+                })
+        });
+
+        let results: Vec<_> = futures_util::stream::iter(fetches)
+            .buffer_unordered(max_concurrency.max(1))
+            .collect()
+            .await;
+
+        let mut tickers = Vec::new();
+        let mut errors = Vec::new();
+        for result in results {
+            match result {
+                Ok(batch) => tickers.extend(batch),
+                Err(error) => errors.push(error),
+            }
+        }
+        (tickers, errors)
+    }
+
+    /// Polls ticker data on a fixed cadence, as a REST-based alternative for consumers that
+    /// can't use [`crate::ws`]. Each tick fetches [`Self::get_ticker`] (`symbols: None`) or
+    /// [`Self::get_tickers_for`] (`symbols: Some`, with one request in flight per symbol), then
+    /// sleeps until the next tick, jittered by up to 10% so many clients started together don't
+    /// all poll in lockstep. A poll that errors backs off using the client's configured
+    /// [`RetryPolicy`] (or the default one) before the next attempt, rather than hammering a
+    /// struggling endpoint every `interval` regardless.
+    ///
+    /// If `change_only` is `true`, a poll whose snapshot has no [`crate::ticker_tracker::TickerChange`]
+    /// relative to the previous one (see [`TickerTracker::diff`]) is skipped rather than
+    /// re-emitted, so a downstream consumer only sees polls that actually moved something.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the same errors [`Self::get_ticker`] would; a symbol-specific error
+    /// from [`Self::get_tickers_for`] surfaces as that symbol's error, with the rest of that
+    /// poll's results discarded for the tick.
+    pub fn poll_tickers(
+        &self,
+        interval: Duration,
+        symbols: Option<Vec<String>>,
+        change_only: bool,
+    ) -> impl futures_util::Stream<Item = Result<Vec<OptionTicker>, BinanceOptionsClientError>> + '_
+    {
+        struct State {
+            attempt: u32,
+            tracker: Option<TickerTracker>,
+            is_first_tick: bool,
+        }
+
+        futures_util::stream::unfold(
+            State {
+                attempt: 0,
+                tracker: change_only.then(TickerTracker::new),
+                is_first_tick: true,
+            },
+            move |mut state| {
+                let symbols = symbols.clone();
+                async move {
+                    loop {
+                        if !state.is_first_tick {
+                            let delay = if state.attempt > 0 {
+                                self.retry_policy
+                                    .unwrap_or_default()
+                                    .delay_for_attempt(state.attempt, None)
+                            } else {
+                                jittered_interval(interval)
+                            };
+                            tokio::time::sleep(delay).await;
+                        }
+                        state.is_first_tick = false;
+
+                        let result = match &symbols {
+                            Some(symbols) => {
+                                let refs: Vec<&str> = symbols.iter().map(String::as_str).collect();
+                                let (tickers, mut errors) =
+                                    self.get_tickers_for(&refs, refs.len().max(1)).await;
+                                match errors.pop() {
+                                    Some(first_error) => Err(first_error.error),
+                                    None => Ok(tickers),
+                                }
+                            }
+                            None => self.get_ticker(None).await,
+                        };
+
+                        match result {
+                            Ok(tickers) => {
+                                state.attempt = 0;
+                                if let Some(tracker) = &mut state.tracker
+                                    && tracker.diff(&tickers).is_empty()
+                                {
+                                    continue;
+                                }
+                                return Some((Ok(tickers), state));
+                            }
+                            Err(error) => {
+                                state.attempt += 1;
+                                return Some((Err(error), state));
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Retrieves the order book depth for a symbol from the Binance Options API.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The ticker symbol to fetch the order book for.
+    /// * `limit` - An optional cap on the number of bid/ask levels returned.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `OrderBook` for the symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails,
+    /// the response status is unsuccessful, or JSON parsing fails.
+    pub async fn get_depth(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<OrderBook, BinanceOptionsClientError> {
+        info!("Getting order book depth for symbol: {}", symbol);
+
+        let mut depth_req = DepthRequest::new(symbol);
+        if let Some(limit) = limit {
+            depth_req = depth_req.limit(limit);
+        }
+
+        let req: Request = depth_req.into();
+        self.send_request(req).await
+    }
+
+    /// Retrieves the mark price and Greeks for a symbol from the Binance Options API.
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The ticker symbol to fetch mark price and Greeks for.
+    ///
+    /// # Returns
+    ///
+    /// The parsed `MarkPrice` for the symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails, the response status
+    /// is unsuccessful, JSON parsing fails, or no mark price data is returned for the symbol.
+    pub async fn get_mark(&self, symbol: &str) -> Result<MarkPrice, BinanceOptionsClientError> {
+        info!("Getting mark price and Greeks for symbol: {}", symbol);
+
+        let req: Request = MarkRequest::new(symbol).into();
+        let marks: Vec<MarkPrice> = self.send_request(req).await?;
+
+        marks.into_iter().next().ok_or_else(|| {
+            BinanceOptionsClientError::Unknown(format!(
+                "no mark price data returned for symbol: {symbol}"
+            ))
+        })
+    }
+
+    /// Retrieves the underlying index price from the Binance Options API.
+    ///
+    /// # Arguments
+    ///
+    /// * `underlying` - The underlying asset to fetch the index price for (e.g. "BTCUSDT").
+    ///
+    /// # Returns
+    ///
+    /// The parsed `IndexPrice` for the underlying asset.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails,
+    /// the response status is unsuccessful, or JSON parsing fails.
+    pub async fn get_index_price(
+        &self,
+        underlying: &str,
+    ) -> Result<IndexPrice, BinanceOptionsClientError> {
+        info!("Getting underlying index price for: {}", underlying);
+
+        let req: Request = IndexRequest::new(underlying).into();
+        self.send_request(req).await
+    }
+
+    /// Retrieves historical exercise (settlement) records from the Binance Options API.
+    ///
+    /// # Arguments
+    ///
+    /// * `underlying` - The underlying asset to fetch exercise records for (e.g. "BTCUSDT").
+    /// * `start` - An optional start of the time range, as a millisecond timestamp.
+    /// * `end` - An optional end of the time range, as a millisecond timestamp.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `ExerciseRecord` entries.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails,
+    /// the response status is unsuccessful, or JSON parsing fails.
+    pub async fn get_exercise_history(
+        &self,
+        underlying: &str,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Result<Vec<ExerciseRecord>, BinanceOptionsClientError> {
+        info!("Getting exercise history for underlying: {}", underlying);
+
+        let mut history_req = ExerciseHistoryRequest::new(underlying);
+        if let Some(start) = start {
+            history_req = history_req.start(start);
+        }
+        if let Some(end) = end {
+            history_req = history_req.end(end);
+        }
+
+        let req: Request = history_req.into();
+        self.send_request(req).await
+    }
+
+    /// Streams every historical exercise record for `underlying` from `start` onward, walking
+    /// the time-range cursor forward a page at a time so callers don't have to re-issue
+    /// [`Self::get_exercise_history`] themselves to page through a large history. See
+    /// [`paginate_by_time`] for how exhaustion is detected.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the same errors [`Self::get_exercise_history`] would.
+    pub fn get_exercise_history_stream(
+        &self,
+        underlying: &str,
+        start: Option<i64>,
+    ) -> impl futures_util::Stream<Item = Result<ExerciseRecord, BinanceOptionsClientError>> + '_ {
+        let underlying = underlying.to_owned();
+        paginate_by_time(
+            start,
+            move |cursor| {
+                let underlying = underlying.clone();
+                async move { self.get_exercise_history(&underlying, cursor, None).await }
+            },
+            |record: &ExerciseRecord| record.expiry_date.timestamp_millis(),
+        )
+    }
+
+    /// Places a new order on the Binance Options API.
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key or
+    /// secret key configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn post_order(
+        &self,
+        request: NewOrderRequest,
+    ) -> Result<OrderResponse, BinanceOptionsClientError> {
+        info!("Placing new order for symbol: {}", request.symbol);
+
+        let req: Request = request.into();
+        self.send_request(req).await
+    }
+
+    /// Cancels a single open order on the Binance Options API.
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key or
+    /// secret key configured. Returns an `ApiError` for which
+    /// [`BinanceOptionsClientError::is_unknown_order`] is true if `order_id` doesn't exist, or
+    /// was already filled or cancelled.
+    pub async fn cancel_order(
+        &self,
+        symbol: &str,
+        order_id: i64,
+    ) -> Result<OrderResponse, BinanceOptionsClientError> {
+        info!("Cancelling order {} for symbol: {}", order_id, symbol);
+
+        let req: Request = CancelOrderRequest::new(symbol, order_id).into();
+        self.send_request(req).await
+    }
+
+    /// Cancels every open order for a symbol on the Binance Options API.
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key or
+    /// secret key configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn cancel_all_orders(
+        &self,
+        symbol: &str,
+    ) -> Result<CancelAllOrdersResponse, BinanceOptionsClientError> {
+        info!("Cancelling all open orders for symbol: {}", symbol);
+
+        let req: Request = CancelAllOrdersRequest::new(symbol).into();
+        self.send_request(req).await
+    }
+
+    /// Retrieves signed account information (asset balances and Greeks exposure) from the
+    /// Binance Options API.
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key or
+    /// secret key configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn get_account(&self) -> Result<AccountInfo, BinanceOptionsClientError> {
+        info!("Getting signed account information");
+
+        let req: Request = AccountRequest::new().into();
+        self.send_request(req).await
+    }
+
+    /// Retrieves currently open orders from the Binance Options API.
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - An optional symbol filter. If `None`, returns open orders for all symbols.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key or
+    /// secret key configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn get_open_orders(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<OrderResponse>, BinanceOptionsClientError> {
+        info!(
+            "Getting open orders{}",
+            symbol.map_or(String::new(), |s| format!(" for symbol: {}", s))
+        );
+
+        let mut open_orders_req = OpenOrdersRequest::new();
+        if let Some(s) = symbol {
+            open_orders_req = open_orders_req.symbol(s);
+        }
+
+        let req: Request = open_orders_req.into();
+        self.send_request(req).await
+    }
+
+    /// Retrieves historical (filled, cancelled, or expired) orders for a symbol from the
+    /// Binance Options API.
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - The option symbol to fetch order history for.
+    /// * `pagination` - The time range and result-size bounds to apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key or
+    /// secret key configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn get_order_history(
+        &self,
+        symbol: &str,
+        pagination: Pagination,
+    ) -> Result<Vec<OrderResponse>, BinanceOptionsClientError> {
+        info!("Getting order history for symbol: {}", symbol);
+
+        let req: Request = OrderHistoryRequest::new(symbol, pagination).into();
+        self.send_request(req).await
+    }
+
+    /// Streams every historical order for `symbol` from `start` onward, walking the time-range
+    /// cursor forward a page at a time so callers don't have to re-issue
+    /// [`Self::get_order_history`] themselves to page through a large history. See
+    /// [`paginate_by_time`] for how exhaustion is detected.
+    ///
+    /// # Errors
+    ///
+    /// The stream yields the same errors [`Self::get_order_history`] would.
+    pub fn get_order_history_stream(
+        &self,
+        symbol: &str,
+        start: Option<i64>,
+    ) -> impl futures_util::Stream<Item = Result<OrderResponse, BinanceOptionsClientError>> + '_ {
+        let symbol = symbol.to_owned();
+        paginate_by_time(
+            start,
+            move |cursor| {
+                let symbol = symbol.clone();
+                async move {
+                    let mut pagination = Pagination::new();
+                    if let Some(cursor) = cursor {
+                        pagination = pagination.start(cursor);
+                    }
+                    self.get_order_history(&symbol, pagination).await
+                }
+            },
+            |order: &OrderResponse| order.create_time.timestamp_millis(),
+        )
+    }
+
+    /// Retrieves signed position information from the Binance Options API.
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Arguments
+    ///
+    /// * `symbol` - An optional symbol filter. If `None`, returns positions for all symbols.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key or
+    /// secret key configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn get_positions(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<OptionPosition>, BinanceOptionsClientError> {
+        info!(
+            "Getting position information{}",
+            symbol.map_or(String::new(), |s| format!(" for symbol: {}", s))
+        );
+
+        let mut position_req = PositionRequest::new();
+        if let Some(s) = symbol {
+            position_req = position_req.symbol(s);
+        }
+
+        let req: Request = position_req.into();
+        self.send_request(req).await
+    }
+
+    /// Creates a new user data stream listen key, used to subscribe to account and order
+    /// updates over WebSocket. The key expires after 60 minutes unless kept alive with
+    /// [`BinanceOptionsClient::keepalive_listen_key`].
+    ///
+    /// Requires the client to have been constructed with
+    /// [`BinanceOptionsClient::with_credentials`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key
+    /// configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn create_listen_key(&self) -> Result<String, BinanceOptionsClientError> {
+        info!("Creating a new user data stream listen key");
+
+        let req: Request = CreateListenKeyRequest::new().into();
+        let response: ListenKeyResponse = self.send_request(req).await?;
+        Ok(response.listen_key)
+    }
+
+    /// Extends a listen key's validity by another 60 minutes. Should be called roughly every
+    /// 30 minutes for as long as the stream should stay open.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key
+    /// configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn keepalive_listen_key(
+        &self,
+        listen_key: &str,
+    ) -> Result<(), BinanceOptionsClientError> {
+        info!("Sending keepalive for user data stream listen key");
+
+        let req: Request = KeepAliveListenKeyRequest::new(listen_key).into();
+        let _: serde_json::Value = self.send_request(req).await?;
+        Ok(())
+    }
+
+    /// Closes a listen key, ending its user data stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::MissingCredentials` if the client has no API key
+    /// configured, or the usual network/HTTP/JSON errors otherwise.
+    pub async fn close_listen_key(
+        &self,
+        listen_key: &str,
+    ) -> Result<(), BinanceOptionsClientError> {
+        info!("Closing user data stream listen key");
+
+        let req: Request = CloseListenKeyRequest::new(listen_key).into();
+        let _: serde_json::Value = self.send_request(req).await?;
+        Ok(())
+    }
+
+    /// Parses ticker JSON data using the specified parsing strategy (default is streaming)
+    /// and measures performance metrics.
+    ///
+    /// # Arguments
+    ///
+    /// * `json_data` - A string slice containing JSON ticker data.
+    /// * `strategy` - An optional parsing strategy. If `None` is provided, streaming is used.
+    ///
+    /// # Returns
+    ///
+    /// A tuple containing a vector of `OptionTicker` entries and the associated parsing metrics.
+    pub fn parse_ticker_with_metrics(
+        &self,
+        json_data: &str,
+        strategy: Option<crate::parser::ParsingStrategy>,
+    ) -> Result<(Vec<OptionTicker>, ParsingMetrics), BinanceOptionsClientError> {
+        info!(
+            "Parsing ticker data using selected strategy (default is streaming) and measuring performance"
+        );
+        let start = Instant::now();
+
+        // Delegate to the parser module.
+        let tickers = crate::parser::parse_ticker(json_data, strategy)?;
+
+        let duration = start.elapsed();
+        let entry_count = tickers.len().max(1);
+        let total_time_ms = duration.as_secs_f64() * 1000.0;
+        let time_per_entry_ms = total_time_ms / entry_count as f64;
+
+        let metrics = ParsingMetrics {
+            time_per_entry_ms,
+            entries_parsed: entry_count,
+            total_time_ms,
+        };
+
+        info!(
+            "Parsed {} ticker entries in {:.3} ms ({:.6} ms per entry)",
+            entry_count, total_time_ms, time_per_entry_ms
+        );
+
+        Ok((tickers, metrics))
+    }
+
+    /// Parses ticker JSON data using the specified parsing strategy (default is streaming).
+    ///
+    /// # Arguments
+    ///
+    /// * `json_data` - A string slice containing JSON ticker data.
+    /// * `strategy` - An optional parsing strategy. If `None` is provided, streaming is used.
+    ///
+    /// # Returns
+    ///
+    /// A vector of `OptionTicker` entries.
+    pub fn parse_ticker(
+        &self,
+        json_data: &str,
+        strategy: Option<crate::parser::ParsingStrategy>,
+    ) -> Result<Vec<OptionTicker>, BinanceOptionsClientError> {
+        info!("Parsing ticker data using selected strategy (default is streaming)");
+
+        // Delegate to the parser module.
+        let tickers = crate::parser::parse_ticker(json_data, strategy)?;
+
+        info!("Parsed {} ticker entries", tickers.len());
+
+        Ok(tickers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        AccountRequest, BoxFuture, CancelAllOrdersRequest, CancelOrderRequest,
+        CloseListenKeyRequest, CreateListenKeyRequest, DepthRequest, ExerciseHistoryRequest,
+        HttpCall, HttpResponse, HttpTransport, IndexRequest, KeepAliveListenKeyRequest,
+        MarkRequest, Method, NewOrderRequest, OpenOrdersRequest, OrderHistoryRequest,
+        OrderValidationError, OrderSide, OrderType, Pagination, PositionRequest, Request,
+        RequestInterceptor, ServerTimeRequest, TickerRequest, TimeInForce,
+    };
+    use crate::api::{
+        ACCOUNT_ENDPOINT, CANCEL_ALL_ORDERS_ENDPOINT, DEPTH_ENDPOINT, EXERCISE_HISTORY_ENDPOINT,
+        INDEX_ENDPOINT, MARK_ENDPOINT, OPEN_ORDERS_ENDPOINT, ORDER_ENDPOINT,
+        ORDER_HISTORY_ENDPOINT, POSITION_ENDPOINT, SERVER_TIME_ENDPOINT, TICKER_ENDPOINT,
+        USER_DATA_STREAM_ENDPOINT,
+    };
+    use crate::model::{OptionContract, OptionTicker};
+
+    #[test]
+    fn ticker_request_convert_to_request_test() {
+        let request: Request = TickerRequest::new().symbol("BTC-200730-9000-C").into();
 
         assert_eq!(request.path, TICKER_ENDPOINT.to_string());
         assert_eq!(request.method, Method::GET);
@@ -300,4 +2764,1076 @@ mod tests {
             vec![("symbol".to_owned(), "BTC-200730-9000-C".to_string())]
         );
     }
+
+    #[test]
+    fn depth_request_convert_to_request_test() {
+        let request: Request = DepthRequest::new("BTC-200730-9000-C").limit(50).into();
+
+        assert_eq!(request.path, DEPTH_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.params,
+            vec![
+                ("symbol".to_owned(), "BTC-200730-9000-C".to_string()),
+                ("limit".to_owned(), "50".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn mark_request_convert_to_request_test() {
+        let request: Request = MarkRequest::new("BTC-200730-9000-C").into();
+
+        assert_eq!(request.path, MARK_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.params,
+            vec![("symbol".to_owned(), "BTC-200730-9000-C".to_string())]
+        );
+    }
+
+    #[test]
+    fn index_request_convert_to_request_test() {
+        let request: Request = IndexRequest::new("BTCUSDT").into();
+
+        assert_eq!(request.path, INDEX_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.params,
+            vec![("underlying".to_owned(), "BTCUSDT".to_string())]
+        );
+    }
+
+    #[test]
+    fn exercise_history_request_convert_to_request_test() {
+        let request: Request = ExerciseHistoryRequest::new("BTCUSDT").start(1000).end(2000).into();
+
+        assert_eq!(request.path, EXERCISE_HISTORY_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.params,
+            vec![
+                ("underlying".to_owned(), "BTCUSDT".to_string()),
+                ("startTime".to_owned(), "1000".to_string()),
+                ("endTime".to_owned(), "2000".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn account_request_convert_to_request_test() {
+        let request: Request = AccountRequest::new().into();
+
+        assert_eq!(request.path, ACCOUNT_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert!(request.params.is_empty());
+        assert!(request.requires_api_key);
+        assert!(request.requires_signature);
+    }
+
+    #[test]
+    fn new_order_request_convert_to_request_test() {
+        let request: Request = NewOrderRequest::new(
+            "BTC-200730-9000-C",
+            OrderSide::Buy,
+            OrderType::Limit,
+            "1",
+        )
+        .price("100")
+        .time_in_force(TimeInForce::GoodTillCancelled)
+        .client_order_id("my-order-1")
+        .into();
+
+        assert_eq!(request.path, ORDER_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::POST);
+        assert_eq!(
+            request.params,
+            vec![
+                ("symbol".to_owned(), "BTC-200730-9000-C".to_string()),
+                ("side".to_owned(), "BUY".to_string()),
+                ("type".to_owned(), "LIMIT".to_string()),
+                ("quantity".to_owned(), "1".to_string()),
+                ("price".to_owned(), "100".to_string()),
+                ("timeInForce".to_owned(), "GTC".to_string()),
+                ("clientOrderId".to_owned(), "my-order-1".to_string()),
+            ]
+        );
+        assert!(request.requires_api_key);
+        assert!(request.requires_signature);
+    }
+
+    fn contract_with_filters(tick_size: &str, step_size: &str, min_notional: &str) -> OptionContract {
+        OptionContract {
+            id: 1,
+            base_asset: "BTC".to_owned(),
+            quote_asset: "USDT".to_owned(),
+            underlying: "BTCUSDT".to_owned(),
+            settle_asset: "USDT".to_owned(),
+            filters: Some(crate::model::ContractFilters {
+                tick_size: tick_size.to_owned(),
+                step_size: step_size.to_owned(),
+                min_notional: min_notional.to_owned(),
+            }),
+        }
+    }
+
+    #[test]
+    fn validate_accepts_an_order_that_satisfies_every_filter() {
+        let contract = contract_with_filters("0.5", "1", "10");
+        let order = NewOrderRequest::new("BTC-200730-9000-C", OrderSide::Buy, OrderType::Limit, "1")
+            .price("100");
+
+        assert_eq!(order.validate(&contract), Ok(()));
+    }
+
+    #[test]
+    fn validate_rejects_a_price_off_the_tick_size() {
+        let contract = contract_with_filters("0.5", "1", "10");
+        let order = NewOrderRequest::new("BTC-200730-9000-C", OrderSide::Buy, OrderType::Limit, "1")
+            .price("100.3");
+
+        assert_eq!(
+            order.validate(&contract),
+            Err(OrderValidationError::PriceNotOnTick {
+                price: "100.3".parse().unwrap(),
+                tick_size: "0.5".parse().unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_rejects_a_quantity_off_the_step_size() {
+        let contract = contract_with_filters("0.5", "0.1", "10");
+        let order = NewOrderRequest::new("BTC-200730-9000-C", OrderSide::Buy, OrderType::Limit, "0.25")
+            .price("100");
+
+        assert_eq!(
+            order.validate(&contract),
+            Err(OrderValidationError::QuantityNotOnStep {
+                quantity: "0.25".parse().unwrap(),
+                step_size: "0.1".parse().unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_rejects_a_notional_below_the_minimum() {
+        let contract = contract_with_filters("0.5", "1", "1000");
+        let order = NewOrderRequest::new("BTC-200730-9000-C", OrderSide::Buy, OrderType::Limit, "1")
+            .price("100");
+
+        assert_eq!(
+            order.validate(&contract),
+            Err(OrderValidationError::BelowMinNotional {
+                notional: "100".parse().unwrap(),
+                min_notional: "1000".parse().unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn validate_skips_price_and_notional_checks_for_a_market_order() {
+        let contract = contract_with_filters("0.5", "1", "1000000");
+        let order = NewOrderRequest::new("BTC-200730-9000-C", OrderSide::Buy, OrderType::Market, "1");
+
+        assert_eq!(order.validate(&contract), Ok(()));
+    }
+
+    #[test]
+    fn validate_requires_filters_to_be_present_on_the_contract() {
+        let mut contract = contract_with_filters("0.5", "1", "10");
+        contract.filters = None;
+        let order = NewOrderRequest::new("BTC-200730-9000-C", OrderSide::Buy, OrderType::Limit, "1")
+            .price("100");
+
+        assert_eq!(order.validate(&contract), Err(OrderValidationError::MissingFilters));
+    }
+
+    #[test]
+    fn cancel_order_request_convert_to_request_test() {
+        let request: Request = CancelOrderRequest::new("BTC-200730-9000-C", 12345).into();
+
+        assert_eq!(request.path, ORDER_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::DELETE);
+        assert_eq!(
+            request.params,
+            vec![
+                ("symbol".to_owned(), "BTC-200730-9000-C".to_string()),
+                ("orderId".to_owned(), "12345".to_string()),
+            ]
+        );
+        assert!(request.requires_api_key);
+        assert!(request.requires_signature);
+    }
+
+    #[test]
+    fn cancel_all_orders_request_convert_to_request_test() {
+        let request: Request = CancelAllOrdersRequest::new("BTC-200730-9000-C").into();
+
+        assert_eq!(request.path, CANCEL_ALL_ORDERS_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::DELETE);
+        assert_eq!(
+            request.params,
+            vec![("symbol".to_owned(), "BTC-200730-9000-C".to_string())]
+        );
+        assert!(request.requires_api_key);
+        assert!(request.requires_signature);
+    }
+
+    #[test]
+    fn open_orders_request_convert_to_request_test() {
+        let request: Request = OpenOrdersRequest::new().symbol("BTC-200730-9000-C").into();
+
+        assert_eq!(request.path, OPEN_ORDERS_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.params,
+            vec![("symbol".to_owned(), "BTC-200730-9000-C".to_string())]
+        );
+        assert!(request.requires_api_key);
+        assert!(request.requires_signature);
+    }
+
+    #[test]
+    fn order_history_request_convert_to_request_test() {
+        let pagination = Pagination::new().start(1000).end(2000).limit(50);
+        let request: Request = OrderHistoryRequest::new("BTC-200730-9000-C", pagination).into();
+
+        assert_eq!(request.path, ORDER_HISTORY_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.params,
+            vec![
+                ("symbol".to_owned(), "BTC-200730-9000-C".to_string()),
+                ("startTime".to_owned(), "1000".to_string()),
+                ("endTime".to_owned(), "2000".to_string()),
+                ("limit".to_owned(), "50".to_string()),
+            ]
+        );
+        assert!(request.requires_api_key);
+        assert!(request.requires_signature);
+    }
+
+    #[test]
+    fn position_request_convert_to_request_test() {
+        let request: Request = PositionRequest::new().symbol("BTC-200730-9000-C").into();
+
+        assert_eq!(request.path, POSITION_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert_eq!(
+            request.params,
+            vec![("symbol".to_owned(), "BTC-200730-9000-C".to_string())]
+        );
+        assert!(request.requires_api_key);
+        assert!(request.requires_signature);
+    }
+
+    #[test]
+    fn server_time_request_convert_to_request_test() {
+        let request: Request = ServerTimeRequest::new().into();
+
+        assert_eq!(request.path, SERVER_TIME_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::GET);
+        assert!(request.params.is_empty());
+        assert!(!request.requires_api_key);
+        assert!(!request.requires_signature);
+    }
+
+    #[test]
+    fn create_listen_key_request_convert_to_request_test() {
+        let request: Request = CreateListenKeyRequest::new().into();
+
+        assert_eq!(request.path, USER_DATA_STREAM_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::POST);
+        assert!(request.params.is_empty());
+        assert!(request.requires_api_key);
+        assert!(!request.requires_signature);
+    }
+
+    #[test]
+    fn keep_alive_listen_key_request_convert_to_request_test() {
+        let request: Request = KeepAliveListenKeyRequest::new("abc123").into();
+
+        assert_eq!(request.path, USER_DATA_STREAM_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::PUT);
+        assert_eq!(request.params, vec![("listenKey".to_string(), "abc123".to_string())]);
+        assert!(request.requires_api_key);
+        assert!(!request.requires_signature);
+    }
+
+    #[test]
+    fn close_listen_key_request_convert_to_request_test() {
+        let request: Request = CloseListenKeyRequest::new("abc123").into();
+
+        assert_eq!(request.path, USER_DATA_STREAM_ENDPOINT.to_string());
+        assert_eq!(request.method, Method::DELETE);
+        assert_eq!(request.params, vec![("listenKey".to_string(), "abc123".to_string())]);
+        assert!(request.requires_api_key);
+        assert!(!request.requires_signature);
+    }
+
+    #[test]
+    fn recv_window_is_included_in_builder_config() {
+        let client = super::BinanceOptionsClient::builder()
+            .recv_window(5000)
+            .build()
+            .unwrap();
+        assert_eq!(client.recv_window, Some(5000));
+    }
+
+    #[test]
+    fn rate_limited_builder_configures_a_rate_limiter() {
+        let client = super::BinanceOptionsClient::builder()
+            .rate_limit(100)
+            .build()
+            .unwrap();
+        assert_eq!(client.rate_limiter.as_ref().map(|l| l.limit()), Some(100));
+
+        let default_client = super::BinanceOptionsClient::new();
+        assert!(default_client.rate_limiter.is_none());
+    }
+
+    #[test]
+    fn throttle_builder_configures_a_shared_throttle() {
+        let throttle = std::sync::Arc::new(crate::throttle::RequestThrottle::new(
+            100,
+            std::time::Duration::from_secs(60),
+        ));
+        let client = super::BinanceOptionsClient::builder()
+            .throttle(throttle.clone())
+            .build()
+            .unwrap();
+        assert!(std::sync::Arc::ptr_eq(
+            client.throttle.as_ref().unwrap(),
+            &throttle
+        ));
+
+        let default_client = super::BinanceOptionsClient::new();
+        assert!(default_client.throttle.is_none());
+    }
+
+    #[tokio::test]
+    async fn send_request_is_paced_by_a_shared_throttle() {
+        let throttle = std::sync::Arc::new(crate::throttle::RequestThrottle::new(
+            1,
+            std::time::Duration::from_millis(50),
+        ));
+        let client = super::BinanceOptionsClient::builder()
+            .throttle(throttle.clone())
+            .transport(FakeTransport {
+                response: HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: r#"{"serverTime":1690000000000}"#.to_string(),
+                },
+            })
+            .build()
+            .unwrap();
+
+        client
+            .send_request::<crate::model::ServerTime>(ServerTimeRequest::new().into())
+            .await
+            .unwrap();
+
+        let started = std::time::Instant::now();
+        client
+            .send_request::<crate::model::ServerTime>(ServerTimeRequest::new().into())
+            .await
+            .unwrap();
+        assert!(started.elapsed() >= std::time::Duration::from_millis(40));
+    }
+
+    #[test]
+    fn retry_policy_builder_configures_retries() {
+        use crate::retry::RetryPolicy;
+
+        let client = super::BinanceOptionsClient::builder()
+            .retry_policy(RetryPolicy::new(5))
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.retry_policy.map(|policy| policy.max_attempts),
+            Some(5)
+        );
+
+        let default_client = super::BinanceOptionsClient::new();
+        assert!(default_client.retry_policy.is_none());
+    }
+
+    #[test]
+    fn base_url_pool_builder_configures_the_pool() {
+        let client = super::BinanceOptionsClient::builder()
+            .base_url_pool(
+                vec!["https://a.example".to_string(), "https://b.example".to_string()],
+                2,
+            )
+            .build()
+            .unwrap();
+        let pool = client.base_url_pool.as_ref().unwrap();
+        assert_eq!(pool.current(), "https://a.example");
+
+        let default_client = super::BinanceOptionsClient::new();
+        assert!(default_client.base_url_pool.is_none());
+    }
+
+    struct AlwaysFailingTransport;
+
+    impl HttpTransport for AlwaysFailingTransport {
+        fn send<'a>(
+            &'a self,
+            _call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, crate::error::BinanceOptionsClientError>> {
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: String::new(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn repeated_failures_advance_the_base_url_pool() {
+        let client = super::BinanceOptionsClient::builder()
+            .base_url_pool(
+                vec!["https://a.example".to_string(), "https://b.example".to_string()],
+                2,
+            )
+            .transport(AlwaysFailingTransport)
+            .build()
+            .unwrap();
+        let pool = client.base_url_pool.clone().unwrap();
+        assert_eq!(pool.current(), "https://a.example");
+
+        let _ = client.get_server_time().await;
+        assert_eq!(pool.current(), "https://a.example");
+        let _ = client.get_server_time().await;
+        assert_eq!(pool.current(), "https://b.example");
+    }
+
+    #[tokio::test]
+    async fn symbol_validator_rejects_an_unknown_symbol_locally_without_a_network_call() {
+        let validator = std::sync::Arc::new(crate::symbol_validation::SymbolValidator::new());
+        validator.refresh(["BTC".to_string()]);
+
+        let client = super::BinanceOptionsClient::builder()
+            .symbol_validator(validator)
+            .transport(AlwaysFailingTransport)
+            .build()
+            .unwrap();
+
+        let error = client.get_ticker(Some("ETH-200730-9000-C")).await.unwrap_err();
+        assert!(matches!(error, crate::error::BinanceOptionsClientError::UnknownSymbol(_)));
+    }
+
+    #[tokio::test]
+    async fn symbol_validator_allows_a_known_symbol_through() {
+        let validator = std::sync::Arc::new(crate::symbol_validation::SymbolValidator::new());
+        validator.refresh(["BTC".to_string()]);
+
+        let client = super::BinanceOptionsClient::builder()
+            .symbol_validator(validator)
+            .transport(FakeTransport {
+                response: HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: r#"[{"symbol":"BTC-200730-9000-C","priceChange":"0","priceChangePercent":"0","lastPrice":"100","lastQty":"1","open":"100","high":"100","low":"100","volume":"1","amount":"100","bidPrice":"99","askPrice":"101","openTime":1690000000000,"closeTime":1690000000000,"firstTradeId":1,"tradeCount":1,"strikePrice":"9000","exercisePrice":"9000"}]"#.to_string(),
+                },
+            })
+            .build()
+            .unwrap();
+
+        let tickers = client.get_ticker(Some("BTC-200730-9000-C")).await.unwrap();
+        assert_eq!(tickers.len(), 1);
+    }
+
+    #[test]
+    fn connect_timeout_builder_is_accepted() {
+        super::BinanceOptionsClient::builder()
+            .timeout(std::time::Duration::from_secs(5))
+            .connect_timeout(std::time::Duration::from_secs(1))
+            .build()
+            .expect("builder with timeout/connect_timeout should succeed");
+    }
+
+    #[test]
+    fn compression_builder_is_accepted_enabled_or_disabled() {
+        super::BinanceOptionsClient::builder()
+            .compression(false)
+            .build()
+            .expect("builder with compression disabled should succeed");
+        super::BinanceOptionsClient::builder()
+            .compression(true)
+            .build()
+            .expect("builder with compression enabled should succeed");
+    }
+
+    #[test]
+    fn pool_and_keepalive_settings_are_accepted() {
+        super::BinanceOptionsClient::builder()
+            .pool_max_idle_per_host(4)
+            .pool_idle_timeout(std::time::Duration::from_secs(30))
+            .tcp_keepalive(std::time::Duration::from_secs(15))
+            .http2_prior_knowledge(true)
+            .build()
+            .expect("builder with pool/keepalive settings should succeed");
+    }
+
+    #[test]
+    fn request_timeout_override_is_stored_on_the_request() {
+        let request: Request = TickerRequest::new().symbol("BTC-200730-9000-C").into();
+        assert_eq!(request.timeout, None);
+
+        let request = request.timeout(std::time::Duration::from_millis(250));
+        assert_eq!(request.timeout, Some(std::time::Duration::from_millis(250)));
+    }
+
+    #[test]
+    fn request_header_builder_appends_headers() {
+        let request: Request = TickerRequest::new().into();
+        assert!(request.headers.is_empty());
+
+        let request = request.header("X-Test", "abc");
+        assert_eq!(request.headers, vec![("X-Test".to_owned(), "abc".to_owned())]);
+    }
+
+    #[derive(Default)]
+    struct RecordingInterceptor {
+        before_calls: std::sync::atomic::AtomicUsize,
+    }
+
+    impl RequestInterceptor for RecordingInterceptor {
+        fn before(&self, request: &mut Request) {
+            self.before_calls
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+            request
+                .headers
+                .push(("X-Test-Intercepted".to_owned(), "1".to_owned()));
+        }
+    }
+
+    #[test]
+    fn interceptor_before_hook_can_mutate_the_request() {
+        let interceptor = RecordingInterceptor::default();
+        let mut request: Request = ServerTimeRequest::new().into();
+
+        interceptor.before(&mut request);
+
+        assert_eq!(
+            request.headers,
+            vec![("X-Test-Intercepted".to_owned(), "1".to_owned())]
+        );
+        assert_eq!(
+            interceptor
+                .before_calls
+                .load(std::sync::atomic::Ordering::Relaxed),
+            1
+        );
+    }
+
+    #[test]
+    fn interceptor_builder_registers_interceptors_in_order() {
+        let client = super::BinanceOptionsClient::builder()
+            .interceptor(RecordingInterceptor::default())
+            .interceptor(RecordingInterceptor::default())
+            .build()
+            .unwrap();
+        assert_eq!(client.interceptors.len(), 2);
+
+        let default_client = super::BinanceOptionsClient::new();
+        assert!(default_client.interceptors.is_empty());
+    }
+
+    struct FakeTransport {
+        response: HttpResponse,
+    }
+
+    impl HttpTransport for FakeTransport {
+        fn send<'a>(
+            &'a self,
+            _call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, crate::error::BinanceOptionsClientError>> {
+            let status = self.response.status;
+            let headers = self.response.headers.clone();
+            let body = self.response.body.clone();
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status,
+                    headers,
+                    body,
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn send_request_can_be_exercised_with_a_canned_transport() {
+        let client = super::BinanceOptionsClient::builder()
+            .transport(FakeTransport {
+                response: HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: r#"{"serverTime":1700000000000}"#.to_string(),
+                },
+            })
+            .build()
+            .unwrap();
+
+        let server_time = client.get_server_time().await.unwrap();
+        assert_eq!(server_time.server_time.timestamp_millis(), 1700000000000);
+    }
+
+    #[tokio::test]
+    async fn send_request_surfaces_an_api_error_from_a_canned_transport() {
+        let client = super::BinanceOptionsClient::builder()
+            .transport(FakeTransport {
+                response: HttpResponse {
+                    status: reqwest::StatusCode::BAD_REQUEST,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: r#"{"code":-1121,"msg":"Invalid symbol."}"#.to_string(),
+                },
+            })
+            .build()
+            .unwrap();
+
+        let result = client.get_server_time().await;
+        assert!(matches!(
+            result,
+            Err(crate::error::BinanceOptionsClientError::ApiError { code: -1121, .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_ticker_deserializes_directly_into_option_tickers() {
+        let client = super::BinanceOptionsClient::builder()
+            .transport(FakeTransport {
+                response: HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: r#"[{
+                        "symbol": "BTC-200730-9000-C",
+                        "priceChange": "0",
+                        "priceChangePercent": "0",
+                        "lastPrice": "100",
+                        "lastQty": "1",
+                        "open": "100",
+                        "high": "100",
+                        "low": "100",
+                        "volume": "1",
+                        "amount": "100",
+                        "bidPrice": "99",
+                        "askPrice": "101",
+                        "openTime": 1690000000000,
+                        "closeTime": 1690000000000,
+                        "firstTradeId": 1,
+                        "tradeCount": 1,
+                        "strikePrice": "9000",
+                        "exercisePrice": "9000"
+                    }]"#
+                    .to_string(),
+                },
+            })
+            .build()
+            .unwrap();
+
+        let tickers = client.get_ticker(Some("BTC-200730-9000-C")).await.unwrap();
+        assert_eq!(tickers.len(), 1);
+        assert_eq!(tickers[0].symbol, "BTC-200730-9000-C");
+    }
+
+    struct PerSymbolTransport {
+        failing_symbol: &'static str,
+    }
+
+    impl HttpTransport for PerSymbolTransport {
+        fn send<'a>(
+            &'a self,
+            call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, crate::error::BinanceOptionsClientError>> {
+            let symbol = call
+                .params
+                .iter()
+                .find(|(name, _)| name == "symbol")
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default();
+            let failing_symbol = self.failing_symbol;
+            Box::pin(async move {
+                if symbol == failing_symbol {
+                    return Ok(HttpResponse {
+                        status: reqwest::StatusCode::BAD_REQUEST,
+                        headers: reqwest::header::HeaderMap::new(),
+                        body: r#"{"code":-1121,"msg":"Invalid symbol."}"#.to_string(),
+                    });
+                }
+                Ok(HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: format!(
+                        r#"[{{
+                            "symbol": "{symbol}",
+                            "priceChange": "0",
+                            "priceChangePercent": "0",
+                            "lastPrice": "100",
+                            "lastQty": "1",
+                            "open": "100",
+                            "high": "100",
+                            "low": "100",
+                            "volume": "1",
+                            "amount": "100",
+                            "bidPrice": "99",
+                            "askPrice": "101",
+                            "openTime": 1690000000000,
+                            "closeTime": 1690000000000,
+                            "firstTradeId": 1,
+                            "tradeCount": 1,
+                            "strikePrice": "9000",
+                            "exercisePrice": "9000"
+                        }}]"#
+                    ),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn get_tickers_for_aggregates_successes_and_reports_per_symbol_errors() {
+        let client = super::BinanceOptionsClient::builder()
+            .transport(PerSymbolTransport {
+                failing_symbol: "BTC-200730-9000-C",
+            })
+            .build()
+            .unwrap();
+
+        let (tickers, errors) = client
+            .get_tickers_for(
+                &["BTC-200730-9000-C", "ETH-200730-9000-C", "ETH-200730-9500-P"],
+                2,
+            )
+            .await;
+
+        assert_eq!(tickers.len(), 2);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].symbol, "BTC-200730-9000-C");
+        assert!(matches!(
+            errors[0].error,
+            crate::error::BinanceOptionsClientError::ApiError { code: -1121, .. }
+        ));
+    }
+
+    #[test]
+    fn circuit_breaker_builder_configures_a_breaker() {
+        use crate::circuit_breaker::{CircuitBreaker, CircuitHealth};
+
+        let client = super::BinanceOptionsClient::builder()
+            .circuit_breaker(CircuitBreaker::new(3, std::time::Duration::from_secs(30)))
+            .build()
+            .unwrap();
+        assert_eq!(client.health(), Some(CircuitHealth::Closed));
+
+        let default_client = super::BinanceOptionsClient::new();
+        assert_eq!(default_client.health(), None);
+    }
+
+    #[test]
+    fn rate_limit_status_defaults_to_zero_used_weight() {
+        let client = super::BinanceOptionsClient::new();
+        let status = client.rate_limit_status();
+        assert_eq!(status.used_weight, 0);
+        assert_eq!(status.limit, None);
+    }
+
+    #[test]
+    fn record_used_weight_tracks_the_highest_header_value() {
+        let client = super::BinanceOptionsClient::builder()
+            .rate_limit(100)
+            .build()
+            .unwrap();
+
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", "10".parse().unwrap());
+        headers.insert("x-mbx-used-weight", "25".parse().unwrap());
+        client.record_used_weight(&headers);
+
+        let status = client.rate_limit_status();
+        assert_eq!(status.used_weight, 25);
+        assert_eq!(status.limit, Some(100));
+    }
+
+    #[tokio::test]
+    async fn send_request_fast_fails_while_the_breaker_is_open() {
+        use crate::circuit_breaker::CircuitBreaker;
+
+        let client = super::BinanceOptionsClient::builder()
+            .circuit_breaker(CircuitBreaker::new(1, std::time::Duration::from_secs(30)))
+            .build()
+            .unwrap();
+        client.circuit_breaker.as_ref().unwrap().record_failure();
+
+        let request: Request = TickerRequest::new().symbol("BTC-200730-9000-C").into();
+        let result: Result<crate::model::OptionTicker, _> = client.send_request(request).await;
+
+        assert!(matches!(
+            result,
+            Err(crate::error::BinanceOptionsClientError::CircuitOpen)
+        ));
+    }
+
+    #[tokio::test]
+    async fn get_account_without_credentials_fails_without_a_network_call() {
+        let client = super::BinanceOptionsClient::new();
+
+        let result = client.get_account().await;
+
+        assert!(matches!(
+            result,
+            Err(crate::error::BinanceOptionsClientError::MissingCredentials(_))
+        ));
+    }
+
+    #[test]
+    fn with_base_url_overrides_the_default_base_url() {
+        let client = super::BinanceOptionsClient::with_base_url("http://127.0.0.1:1234");
+        assert_eq!(client.base_url, "http://127.0.0.1:1234");
+    }
+
+    #[test]
+    fn network_selector_picks_the_right_base_url() {
+        use super::{ClientBuilder, Network};
+
+        let mainnet = ClientBuilder::new()
+            .network(Network::Mainnet)
+            .build()
+            .unwrap();
+        assert_eq!(mainnet.base_url, "https://eapi.binance.com");
+
+        let testnet = ClientBuilder::new()
+            .network(Network::Testnet)
+            .build()
+            .unwrap();
+        assert_eq!(testnet.base_url, "https://testnet.binanceops.com");
+    }
+
+    type Captured = std::sync::Arc<std::sync::Mutex<Option<Vec<(String, String)>>>>;
+
+    struct CapturingTransport {
+        captured: Captured,
+    }
+
+    impl HttpTransport for CapturingTransport {
+        fn send<'a>(
+            &'a self,
+            call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, crate::error::BinanceOptionsClientError>> {
+            *self.captured.lock().unwrap() = Some(call.params.clone());
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: r#"{"symbol":"BTC-200730-9000-C","orderId":1,"price":"100","quantity":"1","executedQty":"0","side":"BUY","type":"LIMIT","timeInForce":"GTC","status":"NEW","clientOrderId":"my order+id=1","createTime":1000}"#.to_string(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn the_signature_covers_the_same_bytes_reqwest_sends_on_the_wire() {
+        let captured = std::sync::Arc::new(std::sync::Mutex::new(None));
+        let client = super::BinanceOptionsClient::builder()
+            .api_key("key")
+            .secret_key("secret")
+            .transport(CapturingTransport {
+                captured: captured.clone(),
+            })
+            .build()
+            .unwrap();
+
+        // A value that needs percent-escaping (space, `+`, `=`) is exactly the case where an
+        // unencoded `key=value` join and reqwest's actual `.query()` encoding diverge.
+        let request = NewOrderRequest::new("BTC-200730-9000-C", OrderSide::Buy, OrderType::Limit, "1")
+            .price("100")
+            .time_in_force(TimeInForce::GoodTillCancelled)
+            .client_order_id("my order+id=1");
+        client.send_request::<crate::model::OrderResponse>(request.into()).await.unwrap();
+
+        let params = captured.lock().unwrap().clone().unwrap();
+        let (signature_param, signature) = params.last().cloned().unwrap();
+        assert_eq!(signature_param, "signature");
+
+        let signed_params = &params[..params.len() - 1];
+        let expected_query_string = serde_urlencoded::to_string(signed_params).unwrap();
+        let expected_signature =
+            crate::signing::hmac_sha256_hex(b"secret", expected_query_string.as_bytes());
+        assert_eq!(signature, expected_signature);
+    }
+
+    #[tokio::test]
+    async fn builder_with_credentials_allows_signed_requests_to_be_attempted() {
+        let client = super::BinanceOptionsClient::builder()
+            .api_key("key")
+            .secret_key("secret")
+            .base_url("http://127.0.0.1:0")
+            .build()
+            .expect("builder with no custom http_client should always succeed");
+
+        // Credentials are present, so the error should come from the (unreachable) network
+        // call, not a `MissingCredentials` short-circuit.
+        let result = client.get_account().await;
+        assert!(!matches!(
+            result,
+            Err(crate::error::BinanceOptionsClientError::MissingCredentials(_))
+        ));
+    }
+
+    struct PagedOrderHistoryTransport;
+
+    impl HttpTransport for PagedOrderHistoryTransport {
+        fn send<'a>(
+            &'a self,
+            call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, crate::error::BinanceOptionsClientError>> {
+            let start_time = call
+                .params
+                .iter()
+                .find(|(name, _)| name == "startTime")
+                .map(|(_, value)| value.clone());
+            Box::pin(async move {
+                let body = match start_time.as_deref() {
+                    None => {
+                        r#"[{"orderId":1,"symbol":"BTC-200730-9000-C","price":"100","quantity":"1","executedQty":"1","side":"BUY","type":"LIMIT","timeInForce":"GTC","status":"FILLED","clientOrderId":"a","createTime":1000},
+                            {"orderId":2,"symbol":"BTC-200730-9000-C","price":"100","quantity":"1","executedQty":"1","side":"BUY","type":"LIMIT","timeInForce":"GTC","status":"FILLED","clientOrderId":"b","createTime":2000}]"#
+                    }
+                    Some("2001") => {
+                        r#"[{"orderId":3,"symbol":"BTC-200730-9000-C","price":"100","quantity":"1","executedQty":"1","side":"BUY","type":"LIMIT","timeInForce":"GTC","status":"FILLED","clientOrderId":"c","createTime":3000}]"#
+                    }
+                    _ => "[]",
+                };
+                Ok(HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: body.to_string(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn get_order_history_stream_walks_every_page_until_exhaustion() {
+        use futures_util::StreamExt;
+
+        let client = super::BinanceOptionsClient::builder()
+            .api_key("key")
+            .secret_key("secret")
+            .transport(PagedOrderHistoryTransport)
+            .build()
+            .unwrap();
+
+        let order_ids: Vec<i64> = client
+            .get_order_history_stream("BTC-200730-9000-C", None)
+            .map(|result| result.unwrap().order_id)
+            .collect()
+            .await;
+
+        assert_eq!(order_ids, vec![1, 2, 3]);
+    }
+
+    struct SequencedTickerTransport {
+        bodies: std::sync::Mutex<std::collections::VecDeque<String>>,
+    }
+
+    impl HttpTransport for SequencedTickerTransport {
+        fn send<'a>(
+            &'a self,
+            _call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, crate::error::BinanceOptionsClientError>> {
+            let body = self
+                .bodies
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("test exhausted its canned responses");
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: reqwest::StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body,
+                })
+            })
+        }
+    }
+
+    fn ticker_body(last_price: &str) -> String {
+        format!(
+            r#"[{{
+                "symbol": "BTC-200730-9000-C",
+                "priceChange": "0",
+                "priceChangePercent": "0",
+                "lastPrice": "{last_price}",
+                "lastQty": "1",
+                "open": "100",
+                "high": "100",
+                "low": "100",
+                "volume": "1",
+                "amount": "100",
+                "bidPrice": "99",
+                "askPrice": "101",
+                "openTime": 1690000000000,
+                "closeTime": 1690000000000,
+                "firstTradeId": 1,
+                "tradeCount": 1,
+                "strikePrice": "9000",
+                "exercisePrice": "9000"
+            }}]"#
+        )
+    }
+
+    #[tokio::test]
+    async fn poll_tickers_emits_one_snapshot_per_tick() {
+        use futures_util::StreamExt;
+
+        let client = super::BinanceOptionsClient::builder()
+            .transport(SequencedTickerTransport {
+                bodies: std::sync::Mutex::new(
+                    [ticker_body("100"), ticker_body("110")].into(),
+                ),
+            })
+            .build()
+            .unwrap();
+
+        let snapshots: Vec<Vec<OptionTicker>> = client
+            .poll_tickers(std::time::Duration::from_millis(1), None, false)
+            .take(2)
+            .map(|result| result.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0][0].last_price, "100");
+        assert_eq!(snapshots[1][0].last_price, "110");
+    }
+
+    #[tokio::test]
+    async fn poll_tickers_with_change_only_skips_unchanged_ticks() {
+        use futures_util::StreamExt;
+
+        let client = super::BinanceOptionsClient::builder()
+            .transport(SequencedTickerTransport {
+                bodies: std::sync::Mutex::new(
+                    [ticker_body("100"), ticker_body("100"), ticker_body("110")].into(),
+                ),
+            })
+            .build()
+            .unwrap();
+
+        let snapshots: Vec<Vec<OptionTicker>> = client
+            .poll_tickers(std::time::Duration::from_millis(1), None, true)
+            .take(2)
+            .map(|result| result.unwrap())
+            .collect()
+            .await;
+
+        assert_eq!(snapshots.len(), 2);
+        assert_eq!(snapshots[0][0].last_price, "100");
+        assert_eq!(snapshots[1][0].last_price, "110");
+    }
 }
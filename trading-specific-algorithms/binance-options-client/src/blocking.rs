@@ -0,0 +1,460 @@
+//! A synchronous mirror of [`crate::BinanceOptionsClient`] for scripts and tools that don't
+//! want to pull in a Tokio runtime. Gated behind the `blocking` feature, which in turn enables
+//! `reqwest`'s own `blocking` feature.
+//!
+//! [`BlockingBinanceOptionsClient::send_request`] reimplements the same signing/retry/circuit
+//! breaker machinery as [`crate::api::BinanceOptionsClient::send_request`], just with
+//! `std::thread::sleep` in place of `tokio::time::sleep`; add further endpoints following the
+//! same `From<XRequest> for Request` pattern used throughout [`crate::api`]. Client-side rate
+//! limiting isn't available here, since [`crate::rate_limit::RateLimiter::acquire`] sleeps via
+//! Tokio — use [`crate::BinanceOptionsClient`] if you need it.
+
+use crate::api::{DepthRequest, IndexRequest, Request, ServerTimeRequest, TickerRequest};
+use crate::circuit_breaker::CircuitBreaker;
+use crate::error::BinanceOptionsClientError;
+use crate::model::{IndexPrice, OptionTicker, OrderBook, ServerTime};
+use crate::retry::{self, RetryPolicy};
+use log::{debug, info, warn};
+use reqwest::blocking::Client;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+
+/// Base URL for the production Binance Options API.
+const BASE_URL: &str = "https://eapi.binance.com";
+
+/// The shape of a Binance API error payload: `{"code": -1121, "msg": "Invalid symbol."}`.
+#[derive(Debug, serde::Deserialize)]
+struct BlockingApiErrorPayload {
+    code: i64,
+    msg: String,
+}
+
+/// Builds a [`BlockingBinanceOptionsClient`]. Mirrors the core options of [`crate::ClientBuilder`].
+pub struct BlockingClientBuilder {
+    base_url: String,
+    api_key: Option<String>,
+    secret_key: Option<String>,
+    timeout: Option<Duration>,
+    user_agent: Option<String>,
+    client: Option<Client>,
+    recv_window: Option<u64>,
+    retry_policy: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreaker>,
+}
+
+impl BlockingClientBuilder {
+    /// Creates a new builder targeting the production Binance Options API, with no credentials.
+    pub fn new() -> Self {
+        Self {
+            base_url: BASE_URL.to_string(),
+            api_key: None,
+            secret_key: None,
+            timeout: None,
+            user_agent: None,
+            client: None,
+            recv_window: None,
+            retry_policy: None,
+            circuit_breaker: None,
+        }
+    }
+
+    /// Sets the API key, sent as the `X-MBX-APIKEY` header on API-key-authenticated endpoints.
+    pub fn api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the API secret, used to HMAC-SHA256 sign the query string of signed endpoints.
+    pub fn secret_key(mut self, secret_key: impl Into<String>) -> Self {
+        self.secret_key = Some(secret_key.into());
+        self
+    }
+
+    /// Overrides the base URL (defaults to the production Binance Options API).
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into();
+        self
+    }
+
+    /// Sets the timeout applied to every request (time to receive the full response).
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Supplies a pre-configured `reqwest::blocking::Client`, overriding `timeout`/`user_agent`.
+    pub fn http_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Sets the `recvWindow` (in milliseconds) sent with signed requests. If unset, Binance
+    /// applies its own default.
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = Some(recv_window);
+        self
+    }
+
+    /// Enables retrying transient failures (network errors, 5xx responses, and 429s) according
+    /// to `retry_policy`. Non-retryable API errors always surface immediately.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = Some(retry_policy);
+        self
+    }
+
+    /// Installs a circuit breaker that fast-fails `send_request` once too many consecutive
+    /// failures (or a ban response) have been observed.
+    pub fn circuit_breaker(mut self, circuit_breaker: CircuitBreaker) -> Self {
+        self.circuit_breaker = Some(circuit_breaker);
+        self
+    }
+
+    /// Builds the `BlockingBinanceOptionsClient`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::Unknown` if a `reqwest::blocking::Client` could not
+    /// be built from the given `timeout`/`user_agent` settings.
+    pub fn build(self) -> Result<BlockingBinanceOptionsClient, BinanceOptionsClientError> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder();
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                builder.build().map_err(|e| {
+                    BinanceOptionsClientError::Unknown(format!(
+                        "failed to build HTTP client: {e}"
+                    ))
+                })?
+            }
+        };
+
+        Ok(BlockingBinanceOptionsClient {
+            client,
+            base_url: self.base_url,
+            api_key: self.api_key,
+            secret_key: self.secret_key,
+            recv_window: self.recv_window,
+            retry_policy: self.retry_policy,
+            circuit_breaker: self.circuit_breaker,
+            clock_offset_ms: AtomicI64::new(0),
+        })
+    }
+}
+
+impl Default for BlockingClientBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A synchronous Binance Options API client. See the module docs for its scope relative to
+/// [`crate::BinanceOptionsClient`].
+pub struct BlockingBinanceOptionsClient {
+    client: Client,
+    base_url: String,
+    api_key: Option<String>,
+    secret_key: Option<String>,
+    recv_window: Option<u64>,
+    retry_policy: Option<RetryPolicy>,
+    circuit_breaker: Option<CircuitBreaker>,
+    clock_offset_ms: AtomicI64,
+}
+
+impl BlockingBinanceOptionsClient {
+    /// Creates a client with default settings and no credentials.
+    pub fn new() -> Self {
+        BlockingClientBuilder::new()
+            .build()
+            .expect("default client configuration is always valid")
+    }
+
+    /// Sends a request to the Binance Options API and returns the deserialized response. See
+    /// [`crate::api::BinanceOptionsClient::send_request`] for the retry/signing/circuit-breaker
+    /// behavior this mirrors.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the request requires credentials the client
+    /// wasn't constructed with, the network request fails, the response status is
+    /// unsuccessful, or JSON parsing fails.
+    pub fn send_request<T: DeserializeOwned>(
+        &self,
+        request: Request,
+    ) -> Result<T, BinanceOptionsClientError> {
+        if let Some(circuit_breaker) = &self.circuit_breaker
+            && !circuit_breaker.allow_request()
+        {
+            return Err(BinanceOptionsClientError::CircuitOpen);
+        }
+
+        let max_attempts = self.retry_policy.map_or(1, |policy| policy.max_attempts);
+        let mut attempt = 0;
+        let request_id = crate::api::next_request_id();
+
+        loop {
+            attempt += 1;
+
+            match self.execute_request::<T>(&request, &request_id) {
+                Ok(data) => {
+                    if let Some(circuit_breaker) = &self.circuit_breaker {
+                        circuit_breaker.record_success();
+                    }
+                    return Ok(data);
+                }
+                Err((error, retry_after)) => {
+                    let policy = match self.retry_policy {
+                        Some(policy) if attempt < max_attempts && retry::is_retryable(&error) => {
+                            policy
+                        }
+                        _ => {
+                            if let Some(circuit_breaker) = &self.circuit_breaker {
+                                if matches!(
+                                    &error,
+                                    BinanceOptionsClientError::HttpResponse { code, .. }
+                                        if code.as_u16() == 418
+                                ) {
+                                    circuit_breaker.report_ban();
+                                } else {
+                                    circuit_breaker.record_failure();
+                                }
+                            }
+                            return Err(error);
+                        }
+                    };
+
+                    let delay = policy.delay_for_attempt(attempt, retry_after);
+                    warn!(
+                        "[{}] Retrying blocking request to {} after {:?} (attempt {} of {}): {}",
+                        request_id,
+                        request.path,
+                        delay,
+                        attempt + 1,
+                        max_attempts,
+                        error
+                    );
+                    std::thread::sleep(delay);
+                }
+            }
+        }
+    }
+
+    /// Executes a single attempt of `request`, without retrying. Mirrors
+    /// [`crate::api::BinanceOptionsClient::execute_request`].
+    fn execute_request<T: DeserializeOwned>(
+        &self,
+        request: &Request,
+        request_id: &str,
+    ) -> Result<T, (BinanceOptionsClientError, Option<Duration>)> {
+        let mut params = request.params.clone();
+
+        if request.requires_signature {
+            let secret_key = self.secret_key.as_deref().ok_or_else(|| {
+                (
+                    BinanceOptionsClientError::MissingCredentials(
+                        "signed endpoint requires a secret key".to_string(),
+                    ),
+                    None,
+                )
+            })?;
+
+            let local_timestamp_ms = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_millis() as i64;
+            let timestamp = local_timestamp_ms + self.clock_offset_ms.load(Ordering::Relaxed);
+            params.push(("timestamp".to_owned(), timestamp.to_string()));
+
+            if let Some(recv_window) = self.recv_window {
+                params.push(("recvWindow".to_owned(), recv_window.to_string()));
+            }
+
+            let query_string = serde_urlencoded::to_string(&params).map_err(|error| {
+                (
+                    BinanceOptionsClientError::Unknown(format!(
+                        "failed to encode request parameters for signing: {error}"
+                    )),
+                    None,
+                )
+            })?;
+            let signature =
+                crate::signing::hmac_sha256_hex(secret_key.as_bytes(), query_string.as_bytes());
+            params.push(("signature".to_owned(), signature));
+        }
+
+        let url = format!("{}{}", self.base_url, request.path);
+        debug!(
+            "[{}] Sending blocking request to: {} with method: {:?}",
+            request_id, url, request.method
+        );
+
+        let mut request_builder = match request.method {
+            Method::GET => self.client.get(&url),
+            Method::POST => self.client.post(&url),
+            Method::PUT => self.client.put(&url),
+            Method::DELETE => self.client.delete(&url),
+            _ => {
+                return Err((
+                    BinanceOptionsClientError::Unknown("Unsupported HTTP method".to_string()),
+                    None,
+                ));
+            }
+        };
+
+        if request.requires_api_key {
+            let api_key = self.api_key.as_deref().ok_or_else(|| {
+                (
+                    BinanceOptionsClientError::MissingCredentials(
+                        "endpoint requires an API key".to_string(),
+                    ),
+                    None,
+                )
+            })?;
+            request_builder = request_builder.header("X-MBX-APIKEY", api_key);
+        }
+
+        if !params.is_empty() {
+            request_builder = request_builder.query(&params);
+        }
+
+        if let Some(timeout) = request.timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
+
+        let response = match request_builder.send() {
+            Ok(resp) => resp,
+            Err(e) => return Err((BinanceOptionsClientError::Network(e), None)),
+        };
+
+        if !response.status().is_success() {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_secs);
+            let code = response.status();
+            let body = match response.text() {
+                Ok(body) => body,
+                Err(e) => return Err((BinanceOptionsClientError::Network(e), None)),
+            };
+            let error = match serde_json::from_str::<BlockingApiErrorPayload>(&body) {
+                Ok(payload) => BinanceOptionsClientError::ApiError {
+                    code: payload.code,
+                    msg: payload.msg,
+                    request_id: Some(request_id.to_string()),
+                },
+                Err(_) => BinanceOptionsClientError::HttpResponse {
+                    code,
+                    body,
+                    retry_after,
+                    request_id: Some(request_id.to_string()),
+                },
+            };
+            return Err((error, retry_after));
+        }
+
+        let text = match response.text() {
+            Ok(t) => t,
+            Err(e) => return Err((BinanceOptionsClientError::Network(e), None)),
+        };
+
+        serde_json::from_str(&text).map_err(|e| (BinanceOptionsClientError::JsonParse(e), None))
+    }
+
+    /// Retrieves the Binance Options API server's current time.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails, the response status
+    /// is unsuccessful, or JSON parsing fails.
+    pub fn get_server_time(&self) -> Result<ServerTime, BinanceOptionsClientError> {
+        info!("Getting server time (blocking)");
+        self.send_request(ServerTimeRequest::new().into())
+    }
+
+    /// Synchronizes the client's clock against the Binance Options API server time. See
+    /// [`crate::api::BinanceOptionsClient::sync_clock`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails, the response status
+    /// is unsuccessful, or JSON parsing fails.
+    pub fn sync_clock(&self) -> Result<(), BinanceOptionsClientError> {
+        let local_before_ms = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as i64;
+        let server_time = self.get_server_time()?;
+        let offset = server_time.server_time.timestamp_millis() - local_before_ms;
+
+        info!("Synced blocking client clock with server; offset is {} ms", offset);
+        self.clock_offset_ms.store(offset, Ordering::Relaxed);
+
+        Ok(())
+    }
+
+    /// Retrieves ticker data, optionally filtered to a single symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails, the response status
+    /// is unsuccessful, or JSON parsing fails.
+    pub fn get_ticker(
+        &self,
+        symbol: Option<&str>,
+    ) -> Result<Vec<OptionTicker>, BinanceOptionsClientError> {
+        let mut request = TickerRequest::new();
+        if let Some(symbol) = symbol {
+            request = request.symbol(symbol);
+        }
+        self.send_request(request.into())
+    }
+
+    /// Retrieves the order book depth for a symbol.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails, the response status
+    /// is unsuccessful, or JSON parsing fails.
+    pub fn get_depth(
+        &self,
+        symbol: &str,
+        limit: Option<u32>,
+    ) -> Result<OrderBook, BinanceOptionsClientError> {
+        let mut request = DepthRequest::new(symbol);
+        if let Some(limit) = limit {
+            request = request.limit(limit);
+        }
+        self.send_request(request.into())
+    }
+
+    /// Retrieves the underlying index price.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `BinanceOptionsClientError` if the network request fails, the response status
+    /// is unsuccessful, or JSON parsing fails.
+    pub fn get_index_price(&self, underlying: &str) -> Result<IndexPrice, BinanceOptionsClientError> {
+        self.send_request(IndexRequest::new(underlying).into())
+    }
+}
+
+impl Default for BlockingBinanceOptionsClient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
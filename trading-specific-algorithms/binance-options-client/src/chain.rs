@@ -0,0 +1,206 @@
+//! Organizes raw ticker data into an options chain: underlying → expiry → strike, with calls
+//! and puts paired up at each strike. Lets strategies query "what's near the money" without
+//! re-deriving the chain's shape from a flat `Vec<OptionTicker>` every time.
+
+use crate::model::{OptionKind, OptionSymbolParseError, OptionTicker};
+use rust_decimal::Decimal;
+use std::collections::BTreeMap;
+
+/// An expiry date, as `(year, month, day)`. Ordered chronologically.
+pub type Expiry = (u32, u32, u32);
+
+/// The call and/or put ticker quoted at a single strike.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StrikePair {
+    /// The call ticker at this strike, if one is quoted.
+    pub call: Option<OptionTicker>,
+    /// The put ticker at this strike, if one is quoted.
+    pub put: Option<OptionTicker>,
+}
+
+/// An options chain built from a flat list of tickers, grouped by underlying, then expiry,
+/// then strike.
+#[derive(Debug, Clone, Default)]
+pub struct OptionsChain {
+    underlyings: BTreeMap<String, BTreeMap<Expiry, BTreeMap<Decimal, StrikePair>>>,
+}
+
+impl OptionsChain {
+    /// Builds a chain from ticker data, parsing each ticker's `symbol` to determine its
+    /// underlying, expiry, strike, and call/put side.
+    ///
+    /// # Errors
+    ///
+    /// Returns `OptionSymbolParseError` if any ticker's `symbol` doesn't follow Binance's
+    /// option symbol convention.
+    pub fn build(tickers: Vec<OptionTicker>) -> Result<Self, OptionSymbolParseError> {
+        let mut chain = Self::default();
+        for ticker in tickers {
+            let symbol = ticker.parsed_symbol()?;
+            let expiry = (symbol.expiry_year, symbol.expiry_month, symbol.expiry_day);
+            let pair = chain
+                .underlyings
+                .entry(symbol.underlying)
+                .or_default()
+                .entry(expiry)
+                .or_default()
+                .entry(symbol.strike)
+                .or_default();
+            match symbol.kind {
+                OptionKind::Call => pair.call = Some(ticker),
+                OptionKind::Put => pair.put = Some(ticker),
+            }
+        }
+        Ok(chain)
+    }
+
+    /// Returns the earliest expiry quoted for `underlying`, if any.
+    pub fn nearest_expiry(&self, underlying: &str) -> Option<Expiry> {
+        self.underlyings
+            .get(underlying)?
+            .keys()
+            .next()
+            .copied()
+    }
+
+    /// Returns every expiry quoted for `underlying`, in chronological order.
+    pub fn expiries(&self, underlying: &str) -> Vec<Expiry> {
+        self.underlyings
+            .get(underlying)
+            .map(|expiries| expiries.keys().copied().collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the strike/pair entries for `underlying` at `expiry`, in ascending strike order.
+    pub fn strikes(&self, underlying: &str, expiry: Expiry) -> Vec<(Decimal, &StrikePair)> {
+        self.underlyings
+            .get(underlying)
+            .and_then(|expiries| expiries.get(&expiry))
+            .map(|strikes| strikes.iter().map(|(strike, pair)| (*strike, pair)).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns the at-the-money strike (as [`Self::atm`] picks it) and up to `n` strikes on
+    /// either side of it (so up to `2 * n + 1` total), ordered by strike, for `underlying` at
+    /// `expiry`.
+    pub fn strikes_around(
+        &self,
+        underlying: &str,
+        expiry: Expiry,
+        spot: Decimal,
+        n: usize,
+    ) -> Vec<(Decimal, &StrikePair)> {
+        let strikes = self.strikes(underlying, expiry);
+        let Some(atm_index) = (0..strikes.len()).min_by_key(|&i| (strikes[i].0 - spot).abs()) else {
+            return Vec::new();
+        };
+        let start = atm_index.saturating_sub(n);
+        let end = (atm_index + n + 1).min(strikes.len());
+        strikes[start..end].to_vec()
+    }
+
+    /// Returns the strike closest to `spot` (the at-the-money strike) for `underlying` at
+    /// `expiry`.
+    pub fn atm(&self, underlying: &str, expiry: Expiry, spot: Decimal) -> Option<(Decimal, &StrikePair)> {
+        self.strikes(underlying, expiry)
+            .into_iter()
+            .min_by_key(|(strike, _)| (*strike - spot).abs())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn ticker(symbol: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: "0".to_string(),
+            last_qty: "0".to_string(),
+            open: "0".to_string(),
+            high: "0".to_string(),
+            low: "0".to_string(),
+            volume: "0".to_string(),
+            amount: "0".to_string(),
+            bid_price: "0".to_string(),
+            ask_price: "0".to_string(),
+            open_time: chrono::DateTime::UNIX_EPOCH,
+            close_time: chrono::DateTime::UNIX_EPOCH,
+            first_trade_id: 0,
+            trade_count: 0,
+            strike_price: "9000".to_string(),
+            exercise_price: "9000".to_string(),
+        }
+    }
+
+    #[test]
+    fn build_groups_by_underlying_expiry_and_strike() {
+        let chain = OptionsChain::build(vec![
+            ticker("BTC-200730-9000-C"),
+            ticker("BTC-200730-9000-P"),
+            ticker("BTC-200730-9500-C"),
+        ])
+        .unwrap();
+
+        let expiry = (2020, 7, 30);
+        assert_eq!(chain.nearest_expiry("BTC"), Some(expiry));
+
+        let pair = chain
+            .strikes("BTC", expiry)
+            .into_iter()
+            .find(|(strike, _)| *strike == Decimal::from_str("9000").unwrap())
+            .unwrap()
+            .1;
+        assert!(pair.call.is_some());
+        assert!(pair.put.is_some());
+    }
+
+    #[test]
+    fn build_rejects_a_malformed_symbol() {
+        assert!(OptionsChain::build(vec![ticker("not-a-symbol")]).is_err());
+    }
+
+    #[test]
+    fn atm_returns_the_closest_strike() {
+        let chain = OptionsChain::build(vec![
+            ticker("BTC-200730-9000-C"),
+            ticker("BTC-200730-9500-C"),
+            ticker("BTC-200730-10000-C"),
+        ])
+        .unwrap();
+
+        let (strike, _) = chain
+            .atm("BTC", (2020, 7, 30), Decimal::from_str("9600").unwrap())
+            .unwrap();
+        assert_eq!(strike, Decimal::from_str("9500").unwrap());
+    }
+
+    #[test]
+    fn strikes_around_returns_neighbors_on_both_sides() {
+        let chain = OptionsChain::build(vec![
+            ticker("BTC-200730-8000-C"),
+            ticker("BTC-200730-9000-C"),
+            ticker("BTC-200730-9500-C"),
+            ticker("BTC-200730-10000-C"),
+            ticker("BTC-200730-11000-C"),
+        ])
+        .unwrap();
+
+        let strikes: Vec<Decimal> = chain
+            .strikes_around("BTC", (2020, 7, 30), Decimal::from_str("9500").unwrap(), 1)
+            .into_iter()
+            .map(|(strike, _)| strike)
+            .collect();
+        assert_eq!(
+            strikes,
+            vec![
+                Decimal::from_str("9000").unwrap(),
+                Decimal::from_str("9500").unwrap(),
+                Decimal::from_str("10000").unwrap(),
+            ]
+        );
+    }
+}
@@ -0,0 +1,197 @@
+//! Circuit breaker guarding against hammering a failing Binance endpoint. Opens after too many
+//! consecutive failures (or an explicit ban signal, e.g. an HTTP 418) and fast-fails subsequent
+//! calls for a cool-down period before letting a single trial request through.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Observable state of a [`CircuitBreaker`], returned by [`CircuitBreaker::health`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CircuitHealth {
+    /// Requests are passing through normally.
+    Closed,
+    /// The breaker tripped and is fast-failing calls until the cool-down elapses.
+    Open,
+    /// The cool-down elapsed; the next call is let through as a trial.
+    HalfOpen,
+}
+
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+    /// Set once a half-open trial request has been admitted for the current `opened_at`, so
+    /// concurrent callers racing `allow_request` don't all get let through at once.
+    half_open_trial_issued: bool,
+}
+
+impl BreakerState {
+    fn health(&self, cooldown: Duration) -> CircuitHealth {
+        match self.opened_at {
+            Some(opened_at) if opened_at.elapsed() < cooldown => CircuitHealth::Open,
+            Some(_) => CircuitHealth::HalfOpen,
+            None => CircuitHealth::Closed,
+        }
+    }
+}
+
+/// Trips open after `failure_threshold` consecutive failures (or a call to
+/// [`CircuitBreaker::report_ban`]), fast-failing calls for `cooldown` before letting a
+/// half-open trial request through.
+pub struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    state: Mutex<BreakerState>,
+}
+
+impl CircuitBreaker {
+    /// Creates a breaker that opens after `failure_threshold` consecutive failures, staying
+    /// open for `cooldown` before allowing a half-open trial request through.
+    pub fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            state: Mutex::new(BreakerState {
+                consecutive_failures: 0,
+                opened_at: None,
+                half_open_trial_issued: false,
+            }),
+        }
+    }
+
+    /// Returns the breaker's current health. An `Open` breaker whose cool-down has elapsed is
+    /// reported as `HalfOpen`, but its internal state isn't mutated until a call is made.
+    pub fn health(&self) -> CircuitHealth {
+        let state = self.state.lock().unwrap();
+        state.health(self.cooldown)
+    }
+
+    /// True unless the breaker is currently `Open`; callers should fast-fail instead of
+    /// attempting a request when this returns `false`.
+    ///
+    /// While `HalfOpen`, only the first caller to observe it is admitted — everyone else keeps
+    /// fast-failing until the trial reports success or failure — so a burst of concurrent
+    /// callers can't all pile onto the still-recovering endpoint at once.
+    pub fn allow_request(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        match state.health(self.cooldown) {
+            CircuitHealth::Closed => true,
+            CircuitHealth::Open => false,
+            CircuitHealth::HalfOpen => {
+                if state.half_open_trial_issued {
+                    false
+                } else {
+                    state.half_open_trial_issued = true;
+                    true
+                }
+            }
+        }
+    }
+
+    /// Records a successful call, closing the breaker and resetting the failure count.
+    pub fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures = 0;
+        state.opened_at = None;
+        state.half_open_trial_issued = false;
+    }
+
+    /// Records a failed call, opening the breaker once `failure_threshold` consecutive
+    /// failures have been observed.
+    pub fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= self.failure_threshold {
+            state.opened_at = Some(Instant::now());
+            state.half_open_trial_issued = false;
+        }
+    }
+
+    /// Trips the breaker open immediately, e.g. in response to a ban response (HTTP 418) from
+    /// the exchange, regardless of the current consecutive-failure count.
+    pub fn report_ban(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.opened_at = Some(Instant::now());
+        state.half_open_trial_issued = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_closed() {
+        let breaker = CircuitBreaker::new(3, Duration::from_secs(30));
+        assert_eq!(breaker.health(), CircuitHealth::Closed);
+        assert!(breaker.allow_request());
+    }
+
+    #[test]
+    fn opens_after_threshold_consecutive_failures() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+        breaker.record_failure();
+        assert_eq!(breaker.health(), CircuitHealth::Closed);
+        breaker.record_failure();
+        assert_eq!(breaker.health(), CircuitHealth::Open);
+        assert!(!breaker.allow_request());
+    }
+
+    #[test]
+    fn success_resets_the_failure_count() {
+        let breaker = CircuitBreaker::new(2, Duration::from_secs(30));
+        breaker.record_failure();
+        breaker.record_success();
+        breaker.record_failure();
+        assert_eq!(breaker.health(), CircuitHealth::Closed);
+    }
+
+    #[test]
+    fn cooldown_elapsing_transitions_to_half_open() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(0));
+        breaker.record_failure();
+        assert_eq!(breaker.health(), CircuitHealth::HalfOpen);
+        assert!(breaker.allow_request());
+    }
+
+    #[test]
+    fn only_one_half_open_trial_is_admitted_at_a_time() {
+        let breaker = CircuitBreaker::new(1, Duration::from_millis(0));
+        breaker.record_failure();
+        assert_eq!(breaker.health(), CircuitHealth::HalfOpen);
+
+        assert!(breaker.allow_request());
+        // Health still reports `HalfOpen` (the cooldown hasn't been touched), but a second
+        // caller must not also be admitted as a trial.
+        assert_eq!(breaker.health(), CircuitHealth::HalfOpen);
+        assert!(!breaker.allow_request());
+    }
+
+    #[test]
+    fn concurrent_half_open_callers_admit_exactly_one_trial() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let breaker = Arc::new(CircuitBreaker::new(1, Duration::from_millis(0)));
+        breaker.record_failure();
+        assert_eq!(breaker.health(), CircuitHealth::HalfOpen);
+
+        let admitted: u32 = (0..8)
+            .map(|_| {
+                let breaker = Arc::clone(&breaker);
+                thread::spawn(move || breaker.allow_request())
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|handle| handle.join().unwrap() as u32)
+            .sum();
+
+        assert_eq!(admitted, 1);
+    }
+
+    #[test]
+    fn report_ban_opens_immediately() {
+        let breaker = CircuitBreaker::new(10, Duration::from_secs(30));
+        breaker.report_ban();
+        assert_eq!(breaker.health(), CircuitHealth::Open);
+    }
+}
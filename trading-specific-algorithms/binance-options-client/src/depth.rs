@@ -0,0 +1,271 @@
+//! Local order-book maintenance from the partial/diff depth WebSocket stream: seed from a REST
+//! snapshot, apply diffs in sequence, and expose a consistent best bid/ask view. Mirrors the
+//! standard Binance depth-maintenance algorithm: discard diffs that precede the snapshot, then
+//! require each subsequent diff's `U` to immediately follow the previous diff's `u`.
+
+use crate::model::OrderBook;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// A price/quantity diff delivered over the `@depth` stream.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct DepthUpdateEvent {
+    /// The option symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// The first update ID covered by this event.
+    #[serde(rename = "U")]
+    pub first_update_id: i64,
+    /// The last update ID covered by this event.
+    #[serde(rename = "u")]
+    pub final_update_id: i64,
+    /// Bid levels as `(price, quantity)`; a quantity of `"0"` removes the level.
+    #[serde(rename = "b")]
+    pub bids: Vec<(String, String)>,
+    /// Ask levels as `(price, quantity)`; a quantity of `"0"` removes the level.
+    #[serde(rename = "a")]
+    pub asks: Vec<(String, String)>,
+}
+
+/// Error returned while seeding or updating a [`ManagedOrderBook`].
+#[derive(Debug, thiserror::Error)]
+pub enum DepthError {
+    /// A price or quantity field wasn't a valid decimal.
+    #[error("failed to parse a depth level: {0}")]
+    Decimal(#[from] rust_decimal::Error),
+    /// A diff's `first_update_id` doesn't immediately follow the book's current
+    /// `last_update_id`, meaning an update was missed and the book must be re-seeded from a
+    /// fresh snapshot.
+    #[error("depth update gap: expected next update to start at {expected}, got {got}")]
+    Gap {
+        /// The update ID the book expected next.
+        expected: i64,
+        /// The update ID the diff actually started at.
+        got: i64,
+    },
+}
+
+/// A price level at the top of an order book side: `(price, quantity)`.
+pub type PriceLevel = (Decimal, Decimal);
+
+/// A callback invoked with the new best bid or ask whenever it changes.
+type BestPriceCallback = Box<dyn FnMut(Option<PriceLevel>) + Send>;
+
+/// An order book maintained locally from a REST snapshot plus a sequence of WebSocket diffs.
+pub struct ManagedOrderBook {
+    bids: BTreeMap<Decimal, Decimal>,
+    asks: BTreeMap<Decimal, Decimal>,
+    last_update_id: i64,
+    on_best_bid: Option<BestPriceCallback>,
+    on_best_ask: Option<BestPriceCallback>,
+}
+
+impl ManagedOrderBook {
+    /// Seeds a managed order book from a REST depth snapshot.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DepthError::Decimal` if a price or quantity in `snapshot` isn't a valid
+    /// decimal.
+    pub fn from_snapshot(snapshot: &OrderBook) -> Result<Self, DepthError> {
+        Ok(Self {
+            bids: levels_to_map(&snapshot.bids)?,
+            asks: levels_to_map(&snapshot.asks)?,
+            last_update_id: snapshot.update_id,
+            on_best_bid: None,
+            on_best_ask: None,
+        })
+    }
+
+    /// Registers a callback invoked whenever `apply` changes the best bid.
+    pub fn on_best_bid(mut self, callback: impl FnMut(Option<PriceLevel>) + Send + 'static) -> Self {
+        self.on_best_bid = Some(Box::new(callback));
+        self
+    }
+
+    /// Registers a callback invoked whenever `apply` changes the best ask.
+    pub fn on_best_ask(mut self, callback: impl FnMut(Option<PriceLevel>) + Send + 'static) -> Self {
+        self.on_best_ask = Some(Box::new(callback));
+        self
+    }
+
+    /// Applies a diff from the depth stream. Diffs entirely covered by the snapshot (or an
+    /// already-applied diff) are silently ignored; a diff that leaves a gap after the current
+    /// position returns `DepthError::Gap`, signalling that the caller must re-snapshot and
+    /// rebuild the book.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DepthError::Decimal` if a level in `update` isn't a valid decimal, or
+    /// `DepthError::Gap` if `update` doesn't pick up where the book left off.
+    pub fn apply(&mut self, update: &DepthUpdateEvent) -> Result<(), DepthError> {
+        if update.final_update_id <= self.last_update_id {
+            return Ok(());
+        }
+        if update.first_update_id > self.last_update_id + 1 {
+            return Err(DepthError::Gap {
+                expected: self.last_update_id + 1,
+                got: update.first_update_id,
+            });
+        }
+
+        let bid_before = self.best_bid();
+        let ask_before = self.best_ask();
+
+        apply_levels(&mut self.bids, &update.bids)?;
+        apply_levels(&mut self.asks, &update.asks)?;
+        self.last_update_id = update.final_update_id;
+
+        let bid_after = self.best_bid();
+        if bid_after != bid_before
+            && let Some(callback) = &mut self.on_best_bid
+        {
+            callback(bid_after);
+        }
+        let ask_after = self.best_ask();
+        if ask_after != ask_before
+            && let Some(callback) = &mut self.on_best_ask
+        {
+            callback(ask_after);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the highest bid and its quantity, if the book has any bids.
+    pub fn best_bid(&self) -> Option<PriceLevel> {
+        self.bids.iter().next_back().map(|(&price, &qty)| (price, qty))
+    }
+
+    /// Returns the lowest ask and its quantity, if the book has any asks.
+    pub fn best_ask(&self) -> Option<PriceLevel> {
+        self.asks.iter().next().map(|(&price, &qty)| (price, qty))
+    }
+
+    /// The update ID the book has applied through. Matches the snapshot's `update_id` until
+    /// the first diff is applied.
+    pub fn last_update_id(&self) -> i64 {
+        self.last_update_id
+    }
+}
+
+fn levels_to_map(levels: &[(String, String)]) -> Result<BTreeMap<Decimal, Decimal>, DepthError> {
+    levels
+        .iter()
+        .map(|(price, qty)| Ok((Decimal::from_str(price)?, Decimal::from_str(qty)?)))
+        .collect()
+}
+
+fn apply_levels(
+    book_side: &mut BTreeMap<Decimal, Decimal>,
+    levels: &[(String, String)],
+) -> Result<(), DepthError> {
+    for (price, qty) in levels {
+        let price = Decimal::from_str(price)?;
+        let qty = Decimal::from_str(qty)?;
+        if qty.is_zero() {
+            book_side.remove(&price);
+        } else {
+            book_side.insert(price, qty);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot() -> OrderBook {
+        OrderBook {
+            bids: vec![
+                ("100".to_string(), "1".to_string()),
+                ("99".to_string(), "2".to_string()),
+            ],
+            asks: vec![
+                ("101".to_string(), "1".to_string()),
+                ("102".to_string(), "2".to_string()),
+            ],
+            update_id: 10,
+        }
+    }
+
+    fn diff(first: i64, last: i64, bids: Vec<(&str, &str)>, asks: Vec<(&str, &str)>) -> DepthUpdateEvent {
+        DepthUpdateEvent {
+            symbol: "BTC-200730-9000-C".to_string(),
+            first_update_id: first,
+            final_update_id: last,
+            bids: bids
+                .into_iter()
+                .map(|(p, q)| (p.to_string(), q.to_string()))
+                .collect(),
+            asks: asks
+                .into_iter()
+                .map(|(p, q)| (p.to_string(), q.to_string()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn from_snapshot_exposes_the_best_bid_and_ask() {
+        let book = ManagedOrderBook::from_snapshot(&snapshot()).unwrap();
+        assert_eq!(
+            book.best_bid(),
+            Some((Decimal::from_str("100").unwrap(), Decimal::from_str("1").unwrap()))
+        );
+        assert_eq!(
+            book.best_ask(),
+            Some((Decimal::from_str("101").unwrap(), Decimal::from_str("1").unwrap()))
+        );
+    }
+
+    #[test]
+    fn apply_ignores_a_diff_entirely_covered_by_the_snapshot() {
+        let mut book = ManagedOrderBook::from_snapshot(&snapshot()).unwrap();
+        book.apply(&diff(1, 10, vec![("100", "999")], vec![])).unwrap();
+        assert_eq!(book.last_update_id(), 10);
+    }
+
+    #[test]
+    fn apply_rejects_a_diff_that_leaves_a_gap() {
+        let mut book = ManagedOrderBook::from_snapshot(&snapshot()).unwrap();
+        let result = book.apply(&diff(13, 15, vec![], vec![]));
+        assert!(matches!(
+            result,
+            Err(DepthError::Gap { expected: 11, got: 13 })
+        ));
+    }
+
+    #[test]
+    fn apply_updates_and_removes_levels_in_sequence() {
+        let mut book = ManagedOrderBook::from_snapshot(&snapshot()).unwrap();
+        book.apply(&diff(11, 11, vec![("100", "0"), ("98", "5")], vec![]))
+            .unwrap();
+        assert_eq!(
+            book.best_bid(),
+            Some((Decimal::from_str("99").unwrap(), Decimal::from_str("2").unwrap()))
+        );
+        assert_eq!(book.last_update_id(), 11);
+    }
+
+    #[test]
+    fn apply_fires_the_best_bid_callback_only_when_the_top_changes() {
+        use std::sync::{Arc, Mutex};
+
+        let calls = Arc::new(Mutex::new(Vec::new()));
+        let calls_clone = Arc::clone(&calls);
+        let mut book = ManagedOrderBook::from_snapshot(&snapshot())
+            .unwrap()
+            .on_best_bid(move |best| calls_clone.lock().unwrap().push(best));
+
+        // Doesn't touch the best bid (100).
+        book.apply(&diff(11, 11, vec![("99", "3")], vec![])).unwrap();
+        assert!(calls.lock().unwrap().is_empty());
+
+        // Removes the best bid, promoting 99 to the top.
+        book.apply(&diff(12, 12, vec![("100", "0")], vec![])).unwrap();
+        assert_eq!(calls.lock().unwrap().len(), 1);
+    }
+}
@@ -1,4 +1,5 @@
 use reqwest::StatusCode;
+use std::time::Duration;
 use thiserror::Error;
 
 /// A unified error type for the Binance Options client.
@@ -12,12 +13,26 @@ pub enum BinanceOptionsClientError {
     /// A non-success HTTP status was returned.
     /// We capture the status code and response body (if any).
     #[error("Received HTTP {code}. Body: {body}")]
-    HttpResponse { code: StatusCode, body: String },
+    HttpResponse {
+        code: StatusCode,
+        body: String,
+        /// The server-supplied `Retry-After` delay, if the response carried one.
+        retry_after: Option<Duration>,
+        /// The ID [`crate::api::BinanceOptionsClient::send_request`] (or its blocking
+        /// equivalent) generated for the request that produced this error, if any, for
+        /// correlating this failure with the matching `debug!`/`warn!`/`error!` log lines.
+        request_id: Option<String>,
+    },
 
     /// Binance API indicates an error in the JSON response body,
     /// e.g. {"code":-1121, "msg":"Invalid symbol."}
     #[error("Binance API error code {code}: {msg}")]
-    ApiError { code: i64, msg: String },
+    ApiError {
+        code: i64,
+        msg: String,
+        /// See the `request_id` field on [`Self::HttpResponse`].
+        request_id: Option<String>,
+    },
 
     /// JSON (de)serialization error occurred (e.g., malformed JSON).
     #[error("JSON parse error: {0}")]
@@ -26,14 +41,45 @@ pub enum BinanceOptionsClientError {
     /// Catch-all for unexpected or unclassified errors.
     #[error("Unknown error: {0}")]
     Unknown(String),
+
+    /// A request targets a signed or API-key-authenticated endpoint, but the client
+    /// wasn't constructed with the required credentials.
+    #[error("Missing API credentials: {0}")]
+    MissingCredentials(String),
+
+    /// The client's circuit breaker is open, fast-failing this request instead of hammering an
+    /// endpoint that has been failing or banning the client.
+    #[error("Circuit breaker is open; fast-failing request")]
+    CircuitOpen,
+
+    /// A request's `symbol` parameter names an underlying that isn't in the locally cached
+    /// `exchangeInfo` contract list, raised by a configured
+    /// [`crate::symbol_validation::SymbolValidator`] before the request is sent, so it doesn't
+    /// cost a network round trip to learn the same thing from Binance's `-1121`.
+    #[error("Unknown symbol: {0}")]
+    UnknownSymbol(String),
 }
 
 impl BinanceOptionsClientError {
+    /// Binance's error code for "Unknown order sent" (e.g. cancelling an order that doesn't
+    /// exist, or that was already filled or cancelled).
+    const UNKNOWN_ORDER_CODE: i64 = -2011;
+
+    /// True if this is a Binance `ApiError` for an unknown order (code -2011), letting
+    /// callers distinguish "already gone" from other cancel failures.
+    pub fn is_unknown_order(&self) -> bool {
+        matches!(self, Self::ApiError { code, .. } if *code == Self::UNKNOWN_ORDER_CODE)
+    }
+
     /// Construct an error from an HTTP response that is not successful (e.g., 4XX/5XX).
     /// This helper can parse JSON to check if there's a known Binance "code" / "msg".
+    /// `request_id` is carried through to [`Self::request_id`] for correlating this error with
+    /// the log lines emitted for the request that produced it.
     ///
     /// Example usage:
-    /// ```rust
+    /// ```rust,no_run
+    /// // `no_run`: this hits the live network, which isn't available when running the doctest
+    /// // suite offline.
     /// use reqwest::Client;
     /// use binance_options_client::error::BinanceOptionsClientError;
     ///
@@ -42,29 +88,101 @@ impl BinanceOptionsClientError {
     ///     let client = Client::new();
     ///     let response = client.get("https://www.binance.com/api/v3/ticker/price?symbol=BTCUSDT").send().await?;
     ///     if !response.status().is_success() {
-    ///         let err = BinanceOptionsClientError::from_response(response).await;
+    ///         let err = BinanceOptionsClientError::from_response(response, None).await;
     ///         println!("Error: {}", err);
     ///         // Handle the error appropriately in a real application
     ///     }
     ///     Ok(())
     /// }
     /// ```
-    pub async fn from_response(response: reqwest::Response) -> Self {
+    pub async fn from_response(response: reqwest::Response, request_id: Option<String>) -> Self {
         let code = response.status();
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<u64>().ok())
+            .map(Duration::from_secs);
         let body = match response.text().await {
             Ok(b) => b,
             Err(e) => return Self::Network(e),
         };
 
+        Self::from_parts(code, body, retry_after, request_id)
+    }
+
+    /// Construct an error from an already-read status code and response body, for transports
+    /// that buffer the body up front (e.g. [`crate::api::HttpTransport`]) rather than handing
+    /// over a live `reqwest::Response`. `retry_after` is the already-parsed `Retry-After`
+    /// header, if the caller has one. `request_id` is the ID the caller generated for this
+    /// request, if any; see [`Self::request_id`].
+    pub fn from_parts(
+        code: StatusCode,
+        body: String,
+        retry_after: Option<Duration>,
+        request_id: Option<String>,
+    ) -> Self {
         // Try to parse the standard Binance error: { "code": i64, "msg": String }
         if let Ok(binance_err) = serde_json::from_str::<BinanceApiError>(&body) {
             Self::ApiError {
                 code: binance_err.code,
                 msg: binance_err.msg,
+                request_id,
             }
         } else {
             // If it doesn't match the Binance error structure, fall back to a generic HTTP error
-            Self::HttpResponse { code, body }
+            Self::HttpResponse {
+                code,
+                body,
+                retry_after,
+                request_id,
+            }
+        }
+    }
+
+    /// True if this is a transient failure worth retrying: network errors, 5xx HTTP responses,
+    /// and HTTP 429 (rate limited). [`crate::retry::is_retryable`] is a thin wrapper around this.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Network(_) => true,
+            Self::HttpResponse { code, .. } => code.is_server_error() || code.as_u16() == 429,
+            Self::ApiError { .. }
+            | Self::JsonParse(_)
+            | Self::Unknown(_)
+            | Self::MissingCredentials(_)
+            | Self::CircuitOpen
+            | Self::UnknownSymbol(_) => false,
+        }
+    }
+
+    /// True if the server rejected the request for exceeding a rate limit (HTTP 429). Note this
+    /// does not cover an IP ban (HTTP 418), which Binance uses as a distinct, longer-lived
+    /// penalty for continuing to send requests after a 429.
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, Self::HttpResponse { code, .. } if code.as_u16() == 429)
+    }
+
+    /// The server-supplied `Retry-After` delay, if this error carries one. Only ever `Some` for
+    /// [`Self::HttpResponse`]; a Binance-coded [`Self::ApiError`] body has no field for it.
+    pub fn retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::HttpResponse { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// The ID generated for the request that produced this error, if any, for correlating a
+    /// failure in a concurrent batch of requests with the exact request that caused it. Only
+    /// ever `Some` for [`Self::HttpResponse`] and [`Self::ApiError`], the variants constructed
+    /// from an actual HTTP exchange; a [`Self::Network`] or [`Self::JsonParse`] failure has no
+    /// field to carry one without losing their `#[from]` conversion, and every other variant is
+    /// raised locally, before a request ID would exist.
+    pub fn request_id(&self) -> Option<&str> {
+        match self {
+            Self::HttpResponse { request_id, .. } | Self::ApiError { request_id, .. } => {
+                request_id.as_deref()
+            }
+            _ => None,
         }
     }
 }
@@ -75,3 +193,152 @@ struct BinanceApiError {
     code: i64,
     msg: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BinanceOptionsClientError;
+
+    #[test]
+    fn is_unknown_order_matches_code_minus_2011() {
+        let err = BinanceOptionsClientError::ApiError {
+            code: -2011,
+            msg: "Unknown order sent.".to_string(),
+            request_id: None,
+        };
+        assert!(err.is_unknown_order());
+    }
+
+    #[test]
+    fn is_unknown_order_false_for_other_codes() {
+        let err = BinanceOptionsClientError::ApiError {
+            code: -1121,
+            msg: "Invalid symbol.".to_string(),
+            request_id: None,
+        };
+        assert!(!err.is_unknown_order());
+
+        let err = BinanceOptionsClientError::Unknown("boom".to_string());
+        assert!(!err.is_unknown_order());
+    }
+
+    #[test]
+    fn is_retryable_matches_network_5xx_and_429_only() {
+        assert!(BinanceOptionsClientError::Network(
+            reqwest::Client::new().get("not a url").build().unwrap_err()
+        )
+        .is_retryable());
+        assert!(BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            body: String::new(),
+            retry_after: None,
+            request_id: None,
+        }
+        .is_retryable());
+        assert!(!BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::BAD_REQUEST,
+            body: String::new(),
+            retry_after: None,
+            request_id: None,
+        }
+        .is_retryable());
+        assert!(!BinanceOptionsClientError::ApiError {
+            code: -1121,
+            msg: "Invalid symbol.".to_string(),
+            request_id: None,
+        }
+        .is_retryable());
+    }
+
+    #[test]
+    fn is_rate_limited_is_true_only_for_http_429() {
+        assert!(BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::TOO_MANY_REQUESTS,
+            body: String::new(),
+            retry_after: None,
+            request_id: None,
+        }
+        .is_rate_limited());
+        assert!(!BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::IM_A_TEAPOT,
+            body: String::new(),
+            retry_after: None,
+            request_id: None,
+        }
+        .is_rate_limited());
+        assert!(!BinanceOptionsClientError::ApiError {
+            code: -1003,
+            msg: "Too many requests".to_string(),
+            request_id: None,
+        }
+        .is_rate_limited());
+    }
+
+    #[test]
+    fn retry_after_is_carried_on_http_response_and_absent_elsewhere() {
+        let with_delay = BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::TOO_MANY_REQUESTS,
+            body: String::new(),
+            retry_after: Some(std::time::Duration::from_secs(30)),
+            request_id: None,
+        };
+        assert_eq!(with_delay.retry_after(), Some(std::time::Duration::from_secs(30)));
+
+        let without_delay = BinanceOptionsClientError::ApiError {
+            code: -1003,
+            msg: "Too many requests".to_string(),
+            request_id: None,
+        };
+        assert_eq!(without_delay.retry_after(), None);
+    }
+
+    #[test]
+    fn from_parts_threads_retry_after_into_the_http_response_variant() {
+        let err = BinanceOptionsClientError::from_parts(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            "not json".to_string(),
+            Some(std::time::Duration::from_secs(5)),
+            None,
+        );
+        assert_eq!(err.retry_after(), Some(std::time::Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn request_id_is_carried_on_http_response_and_api_error_only() {
+        let http_response = BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            body: String::new(),
+            retry_after: None,
+            request_id: Some("req-1".to_string()),
+        };
+        assert_eq!(http_response.request_id(), Some("req-1"));
+
+        let api_error = BinanceOptionsClientError::ApiError {
+            code: -1121,
+            msg: "Invalid symbol.".to_string(),
+            request_id: Some("req-2".to_string()),
+        };
+        assert_eq!(api_error.request_id(), Some("req-2"));
+
+        let unknown = BinanceOptionsClientError::Unknown("boom".to_string());
+        assert_eq!(unknown.request_id(), None);
+    }
+
+    #[test]
+    fn from_parts_threads_request_id_into_whichever_variant_it_resolves_to() {
+        let api_error = BinanceOptionsClientError::from_parts(
+            reqwest::StatusCode::BAD_REQUEST,
+            r#"{"code":-1121,"msg":"Invalid symbol."}"#.to_string(),
+            None,
+            Some("req-3".to_string()),
+        );
+        assert_eq!(api_error.request_id(), Some("req-3"));
+
+        let http_response = BinanceOptionsClientError::from_parts(
+            reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            "not json".to_string(),
+            None,
+            Some("req-4".to_string()),
+        );
+        assert_eq!(http_response.request_id(), Some("req-4"));
+    }
+}
@@ -0,0 +1,308 @@
+//! CSV export for the client's ticker, chain, and kline data, for spreadsheet-based analysis
+//! of a snapshot. Writes plain, hand-rolled CSV (quoting a field only when it actually contains
+//! a comma, quote, or newline) rather than pulling in a `csv` crate, matching how the rest of
+//! the client avoids a dependency for small, self-contained formats (see `ticker_stream`'s
+//! hand-rolled JSON array splitter).
+
+use crate::chain::{Expiry, OptionsChain};
+use crate::model::OptionTicker;
+use crate::ws::Kline;
+use std::io::{self, Write};
+
+/// A selectable column of [`OptionTicker`] data for [`write_tickers`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickerColumn {
+    /// The ticker symbol.
+    Symbol,
+    /// Last traded price.
+    LastPrice,
+    /// Bid price.
+    BidPrice,
+    /// Ask price.
+    AskPrice,
+    /// Trading volume.
+    Volume,
+    /// Opening time, formatted as RFC 3339.
+    OpenTime,
+    /// Closing time, formatted as RFC 3339.
+    CloseTime,
+}
+
+impl TickerColumn {
+    /// Every column: symbol, then the prices, then volume, then the open/close times.
+    pub const ALL: [TickerColumn; 7] = [
+        TickerColumn::Symbol,
+        TickerColumn::LastPrice,
+        TickerColumn::BidPrice,
+        TickerColumn::AskPrice,
+        TickerColumn::Volume,
+        TickerColumn::OpenTime,
+        TickerColumn::CloseTime,
+    ];
+
+    fn header(self) -> &'static str {
+        match self {
+            TickerColumn::Symbol => "symbol",
+            TickerColumn::LastPrice => "last_price",
+            TickerColumn::BidPrice => "bid_price",
+            TickerColumn::AskPrice => "ask_price",
+            TickerColumn::Volume => "volume",
+            TickerColumn::OpenTime => "open_time",
+            TickerColumn::CloseTime => "close_time",
+        }
+    }
+
+    fn value(self, ticker: &OptionTicker) -> String {
+        match self {
+            TickerColumn::Symbol => ticker.symbol.clone(),
+            TickerColumn::LastPrice => ticker.last_price.clone(),
+            TickerColumn::BidPrice => ticker.bid_price.clone(),
+            TickerColumn::AskPrice => ticker.ask_price.clone(),
+            TickerColumn::Volume => ticker.volume.clone(),
+            TickerColumn::OpenTime => ticker.open_time.to_rfc3339(),
+            TickerColumn::CloseTime => ticker.close_time.to_rfc3339(),
+        }
+    }
+}
+
+/// Writes `tickers` to `writer` as CSV, with one row per ticker and `columns` selecting which
+/// fields to include and in what order.
+///
+/// # Errors
+///
+/// Returns an `io::Error` if writing to `writer` fails.
+pub fn write_tickers(
+    writer: &mut impl Write,
+    tickers: &[OptionTicker],
+    columns: &[TickerColumn],
+) -> io::Result<()> {
+    write_row(writer, columns.iter().map(|column| column.header().to_string()))?;
+    for ticker in tickers {
+        write_row(writer, columns.iter().map(|column| column.value(ticker)))?;
+    }
+    Ok(())
+}
+
+/// A selectable column of [`OptionsChain`] data for [`write_chain`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChainColumn {
+    /// The strike price.
+    Strike,
+    /// The call side's symbol, if quoted at this strike.
+    CallSymbol,
+    /// The call side's last traded price, if quoted at this strike.
+    CallLastPrice,
+    /// The put side's symbol, if quoted at this strike.
+    PutSymbol,
+    /// The put side's last traded price, if quoted at this strike.
+    PutLastPrice,
+}
+
+impl ChainColumn {
+    /// Every column, strike first, then the call side, then the put side.
+    pub const ALL: [ChainColumn; 5] = [
+        ChainColumn::Strike,
+        ChainColumn::CallSymbol,
+        ChainColumn::CallLastPrice,
+        ChainColumn::PutSymbol,
+        ChainColumn::PutLastPrice,
+    ];
+
+    fn header(self) -> &'static str {
+        match self {
+            ChainColumn::Strike => "strike",
+            ChainColumn::CallSymbol => "call_symbol",
+            ChainColumn::CallLastPrice => "call_last_price",
+            ChainColumn::PutSymbol => "put_symbol",
+            ChainColumn::PutLastPrice => "put_last_price",
+        }
+    }
+
+    fn value(self, strike: rust_decimal::Decimal, pair: &crate::chain::StrikePair) -> String {
+        match self {
+            ChainColumn::Strike => strike.to_string(),
+            ChainColumn::CallSymbol => pair.call.as_ref().map_or_else(String::new, |t| t.symbol.clone()),
+            ChainColumn::CallLastPrice => {
+                pair.call.as_ref().map_or_else(String::new, |t| t.last_price.clone())
+            }
+            ChainColumn::PutSymbol => pair.put.as_ref().map_or_else(String::new, |t| t.symbol.clone()),
+            ChainColumn::PutLastPrice => {
+                pair.put.as_ref().map_or_else(String::new, |t| t.last_price.clone())
+            }
+        }
+    }
+}
+
+/// Writes every strike quoted for `underlying` at `expiry` in `chain` to `writer` as CSV, one
+/// row per strike, with `columns` selecting which fields to include and in what order.
+///
+/// # Errors
+///
+/// Returns an `io::Error` if writing to `writer` fails.
+pub fn write_chain(
+    writer: &mut impl Write,
+    chain: &OptionsChain,
+    underlying: &str,
+    expiry: Expiry,
+    columns: &[ChainColumn],
+) -> io::Result<()> {
+    write_row(writer, columns.iter().map(|column| column.header().to_string()))?;
+    for (strike, pair) in chain.strikes(underlying, expiry) {
+        write_row(writer, columns.iter().map(|column| column.value(strike, pair)))?;
+    }
+    Ok(())
+}
+
+/// A selectable column of [`Kline`] data for [`write_klines`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KlineColumn {
+    /// Candle open time, epoch milliseconds.
+    OpenTime,
+    /// Candle close time, epoch milliseconds.
+    CloseTime,
+    /// Opening price.
+    Open,
+    /// Closing price.
+    Close,
+    /// Highest price.
+    High,
+    /// Lowest price.
+    Low,
+    /// Trading volume.
+    Volume,
+}
+
+impl KlineColumn {
+    /// Every column, in the order [`Kline`] declares its fields.
+    pub const ALL: [KlineColumn; 7] = [
+        KlineColumn::OpenTime,
+        KlineColumn::CloseTime,
+        KlineColumn::Open,
+        KlineColumn::Close,
+        KlineColumn::High,
+        KlineColumn::Low,
+        KlineColumn::Volume,
+    ];
+
+    fn header(self) -> &'static str {
+        match self {
+            KlineColumn::OpenTime => "open_time",
+            KlineColumn::CloseTime => "close_time",
+            KlineColumn::Open => "open",
+            KlineColumn::Close => "close",
+            KlineColumn::High => "high",
+            KlineColumn::Low => "low",
+            KlineColumn::Volume => "volume",
+        }
+    }
+
+    fn value(self, kline: &Kline) -> String {
+        match self {
+            KlineColumn::OpenTime => kline.open_time.to_string(),
+            KlineColumn::CloseTime => kline.close_time.to_string(),
+            KlineColumn::Open => kline.open.clone(),
+            KlineColumn::Close => kline.close.clone(),
+            KlineColumn::High => kline.high.clone(),
+            KlineColumn::Low => kline.low.clone(),
+            KlineColumn::Volume => kline.volume.clone(),
+        }
+    }
+}
+
+/// Writes `klines` to `writer` as CSV, one row per candle, with `columns` selecting which
+/// fields to include and in what order.
+///
+/// # Errors
+///
+/// Returns an `io::Error` if writing to `writer` fails.
+pub fn write_klines(writer: &mut impl Write, klines: &[Kline], columns: &[KlineColumn]) -> io::Result<()> {
+    write_row(writer, columns.iter().map(|column| column.header().to_string()))?;
+    for kline in klines {
+        write_row(writer, columns.iter().map(|column| column.value(kline)))?;
+    }
+    Ok(())
+}
+
+/// Writes `fields` as one comma-joined, newline-terminated CSV row, quoting any field that
+/// contains a comma, quote, or newline per the usual CSV convention.
+fn write_row(writer: &mut impl Write, fields: impl Iterator<Item = String>) -> io::Result<()> {
+    for (index, field) in fields.enumerate() {
+        if index > 0 {
+            write!(writer, ",")?;
+        }
+        if field.contains(',') || field.contains('"') || field.contains('\n') {
+            write!(writer, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            write!(writer, "{field}")?;
+        }
+    }
+    writeln!(writer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn ticker(symbol: &str, last_price: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: last_price.to_string(),
+            last_qty: "1".to_string(),
+            open: "100".to_string(),
+            high: "100".to_string(),
+            low: "100".to_string(),
+            volume: "1".to_string(),
+            amount: "100".to_string(),
+            bid_price: "99".to_string(),
+            ask_price: "101".to_string(),
+            open_time: DateTime::<Utc>::UNIX_EPOCH,
+            close_time: DateTime::<Utc>::UNIX_EPOCH,
+            first_trade_id: 1,
+            trade_count: 1,
+            strike_price: "9000".to_string(),
+            exercise_price: "9000".to_string(),
+        }
+    }
+
+    #[test]
+    fn write_tickers_emits_a_header_and_one_row_per_ticker() {
+        let tickers = vec![ticker("BTC-200730-9000-C", "100"), ticker("ETH-200730-9000-C", "50")];
+        let mut out = Vec::new();
+
+        write_tickers(&mut out, &tickers, &[TickerColumn::Symbol, TickerColumn::LastPrice]).unwrap();
+
+        let csv = String::from_utf8(out).unwrap();
+        assert_eq!(
+            csv,
+            "symbol,last_price\nBTC-200730-9000-C,100\nETH-200730-9000-C,50\n"
+        );
+    }
+
+    #[test]
+    fn write_chain_emits_a_row_per_strike_with_missing_sides_left_blank() {
+        let chain = OptionsChain::build(vec![ticker("BTC-200730-9000-C", "100")]).unwrap();
+        let mut out = Vec::new();
+
+        write_chain(
+            &mut out,
+            &chain,
+            "BTC",
+            (2020, 7, 30),
+            &[ChainColumn::Strike, ChainColumn::CallSymbol, ChainColumn::PutSymbol],
+        )
+        .unwrap();
+
+        let csv = String::from_utf8(out).unwrap();
+        assert_eq!(csv, "strike,call_symbol,put_symbol\n9000,BTC-200730-9000-C,\n");
+    }
+
+    #[test]
+    fn a_field_containing_a_comma_is_quoted() {
+        let mut out = Vec::new();
+        write_row(&mut out, ["a,b".to_string(), "c".to_string()].into_iter()).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "\"a,b\",c\n");
+    }
+}
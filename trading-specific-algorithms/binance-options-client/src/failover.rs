@@ -0,0 +1,106 @@
+//! Ordered base-URL failover: a [`BaseUrlPool`] tries its configured base URLs in order,
+//! sticking with [`Self::current`] until it accumulates too many consecutive failures, then
+//! moving on to the next URL (wrapping back to the first after the last). Meant for always-on
+//! collectors that would otherwise go fully offline during an outage of a single endpoint (e.g.
+//! `eapi.binance.com`), by also configuring known-good alternates.
+
+use std::sync::atomic::{AtomicU32, AtomicUsize, Ordering};
+
+/// An ordered list of base URLs with consecutive-failure health tracking.
+pub struct BaseUrlPool {
+    urls: Vec<String>,
+    failure_threshold: u32,
+    current: AtomicUsize,
+    consecutive_failures: AtomicU32,
+}
+
+impl BaseUrlPool {
+    /// Creates a pool that tries `urls` in order, failing over to the next one once
+    /// `failure_threshold` consecutive failures against the current one have been recorded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `urls` is empty; a pool with nothing to fail over to isn't useful.
+    pub fn new(urls: Vec<String>, failure_threshold: u32) -> Self {
+        assert!(
+            !urls.is_empty(),
+            "BaseUrlPool requires at least one base URL"
+        );
+        Self {
+            urls,
+            failure_threshold: failure_threshold.max(1),
+            current: AtomicUsize::new(0),
+            consecutive_failures: AtomicU32::new(0),
+        }
+    }
+
+    /// Returns the base URL requests should currently target.
+    pub fn current(&self) -> &str {
+        &self.urls[self.current_index()]
+    }
+
+    /// Returns the index into the configured URL list of the currently active base URL.
+    pub fn current_index(&self) -> usize {
+        self.current.load(Ordering::Relaxed) % self.urls.len()
+    }
+
+    /// Records a successful call against the current base URL, resetting its failure streak.
+    pub fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+    }
+
+    /// Records a failed call against the current base URL, failing over to the next one once
+    /// `failure_threshold` consecutive failures have been observed.
+    pub fn record_failure(&self) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if failures >= self.failure_threshold {
+            self.consecutive_failures.store(0, Ordering::Relaxed);
+            self.current.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_on_the_first_url() {
+        let pool = BaseUrlPool::new(vec!["a".to_string(), "b".to_string()], 2);
+        assert_eq!(pool.current(), "a");
+    }
+
+    #[test]
+    fn fails_over_after_the_threshold_and_wraps_around() {
+        let pool = BaseUrlPool::new(vec!["a".to_string(), "b".to_string()], 2);
+        pool.record_failure();
+        assert_eq!(pool.current(), "a");
+        pool.record_failure();
+        assert_eq!(pool.current(), "b");
+        pool.record_failure();
+        pool.record_failure();
+        assert_eq!(pool.current(), "a");
+    }
+
+    #[test]
+    fn success_resets_the_failure_streak() {
+        let pool = BaseUrlPool::new(vec!["a".to_string(), "b".to_string()], 2);
+        pool.record_failure();
+        pool.record_success();
+        pool.record_failure();
+        assert_eq!(pool.current(), "a");
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one base URL")]
+    fn rejects_an_empty_url_list() {
+        BaseUrlPool::new(vec![], 2);
+    }
+
+    #[test]
+    fn zero_failure_threshold_is_clamped_to_one() {
+        let pool = BaseUrlPool::new(vec!["a".to_string(), "b".to_string()], 0);
+        pool.record_failure();
+        assert_eq!(pool.current(), "b");
+    }
+}
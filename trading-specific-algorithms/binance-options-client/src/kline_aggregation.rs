@@ -0,0 +1,260 @@
+//! Resampling, rolling statistics, and pagination-merging helpers over `Vec<`[`Kline`]`>`, so
+//! basic time-series prep lives next to the candle data rather than in every strategy that
+//! consumes it. These operate purely on already-fetched klines (e.g. from
+//! [`crate::ws::connect`] or stitched together from several paginated history calls); this
+//! crate has no REST kline endpoint of its own, so [`merge_klines`] is about combining batches a
+//! caller already has, not driving pagination itself.
+
+use crate::ws::Kline;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// Merges one or more kline batches (e.g. from several WS sessions or paginated history calls)
+/// into a single series sorted by `open_time`. A tie (the same `open_time` present in more than
+/// one batch) is resolved in favor of whichever batch is passed later, since a later-observed
+/// candle for an in-progress interval is usually the more complete one.
+pub fn merge_klines(batches: impl IntoIterator<Item = Vec<Kline>>) -> Vec<Kline> {
+    let mut by_open_time: std::collections::BTreeMap<i64, Kline> = std::collections::BTreeMap::new();
+    for batch in batches {
+        for kline in batch {
+            by_open_time.insert(kline.open_time, kline);
+        }
+    }
+    by_open_time.into_values().collect()
+}
+
+/// Resamples `klines` into candles covering `bucket_ms`-wide windows aligned to the epoch,
+/// labeling each resulting [`Kline::interval`] with `interval_label` (this module has no
+/// notion of interval strings beyond what Binance uses, e.g. `"5m"`, so the caller supplies one
+/// matching `bucket_ms`). `klines` doesn't need to be pre-sorted. A bucket is dropped if any of
+/// its candles' numeric fields don't parse, the same "can't use it" treatment unparseable
+/// ticker fields get elsewhere in this crate.
+pub fn resample(klines: &[Kline], bucket_ms: i64, interval_label: &str) -> Vec<Kline> {
+    let mut buckets: std::collections::BTreeMap<i64, Vec<&Kline>> = std::collections::BTreeMap::new();
+    for kline in klines {
+        let bucket_start = (kline.open_time / bucket_ms) * bucket_ms;
+        buckets.entry(bucket_start).or_default().push(kline);
+    }
+
+    buckets
+        .into_iter()
+        .filter_map(|(bucket_start, mut group)| {
+            group.sort_by_key(|k| k.open_time);
+
+            let open = Decimal::from_str(&group.first()?.open).ok()?;
+            let close = Decimal::from_str(&group.last()?.close).ok()?;
+            let high = group
+                .iter()
+                .map(|k| Decimal::from_str(&k.high))
+                .collect::<Result<Vec<_>, _>>()
+                .ok()?
+                .into_iter()
+                .reduce(Decimal::max)?;
+            let low = group
+                .iter()
+                .map(|k| Decimal::from_str(&k.low))
+                .collect::<Result<Vec<_>, _>>()
+                .ok()?
+                .into_iter()
+                .reduce(Decimal::min)?;
+            let volume: Decimal = group
+                .iter()
+                .map(|k| Decimal::from_str(&k.volume))
+                .collect::<Result<Vec<_>, _>>()
+                .ok()?
+                .into_iter()
+                .sum();
+
+            Some(Kline {
+                open_time: bucket_start,
+                close_time: bucket_start + bucket_ms - 1,
+                interval: interval_label.to_string(),
+                open: open.to_string(),
+                close: close.to_string(),
+                high: high.to_string(),
+                low: low.to_string(),
+                volume: volume.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Rolling VWAP (volume-weighted average of the typical price `(high + low + close) / 3`) over
+/// a trailing window of `window` candles. Yields one value per input candle, in order; `None`
+/// for every candle before the window has filled, and for a window containing a candle whose
+/// fields don't parse or that together have zero volume.
+pub fn rolling_vwap(klines: &[Kline], window: usize) -> Vec<Option<f64>> {
+    klines
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            if window == 0 || i + 1 < window {
+                return None;
+            }
+
+            let mut weighted_sum = 0.0;
+            let mut volume_sum = 0.0;
+            for kline in &klines[i + 1 - window..=i] {
+                let high: f64 = kline.high.parse().ok()?;
+                let low: f64 = kline.low.parse().ok()?;
+                let close: f64 = kline.close.parse().ok()?;
+                let volume: f64 = kline.volume.parse().ok()?;
+                weighted_sum += (high + low + close) / 3.0 * volume;
+                volume_sum += volume;
+            }
+
+            (volume_sum > 0.0).then(|| weighted_sum / volume_sum)
+        })
+        .collect()
+}
+
+/// Rolling realized volatility (annualized sample standard deviation of log returns of
+/// `close`) over a trailing window of `window` candles, aligned like [`rolling_vwap`].
+/// `candles_per_year` annualizes the per-candle variance, e.g. `525_600.0` for 1-minute candles
+/// or `105_120.0` for 5-minute ones. Requires `window >= 3` (at least two log returns to take a
+/// sample standard deviation of); smaller windows yield `None` for every candle.
+pub fn rolling_realized_volatility(
+    klines: &[Kline],
+    window: usize,
+    candles_per_year: f64,
+) -> Vec<Option<f64>> {
+    if window < 3 {
+        return vec![None; klines.len()];
+    }
+
+    klines
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            if i + 1 < window {
+                return None;
+            }
+
+            let closes: Vec<f64> = klines[i + 1 - window..=i]
+                .iter()
+                .map(|k| k.close.parse().ok())
+                .collect::<Option<_>>()?;
+            let log_returns: Vec<f64> = closes
+                .windows(2)
+                .map(|pair| (pair[1] / pair[0]).ln())
+                .collect();
+
+            let mean = log_returns.iter().sum::<f64>() / log_returns.len() as f64;
+            let variance = log_returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>()
+                / (log_returns.len() - 1) as f64;
+
+            Some(variance.sqrt() * candles_per_year.sqrt())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kline(open_time: i64, open: &str, high: &str, low: &str, close: &str, volume: &str) -> Kline {
+        Kline {
+            open_time,
+            close_time: open_time + 999,
+            interval: "1m".to_string(),
+            open: open.to_string(),
+            high: high.to_string(),
+            low: low.to_string(),
+            close: close.to_string(),
+            volume: volume.to_string(),
+        }
+    }
+
+    #[test]
+    fn merge_klines_sorts_by_open_time_and_dedupes() {
+        let batch_a = vec![kline(0, "1", "1", "1", "1", "1"), kline(60_000, "2", "2", "2", "2", "2")];
+        let batch_b = vec![kline(60_000, "3", "3", "3", "3", "3"), kline(120_000, "4", "4", "4", "4", "4")];
+
+        let merged = merge_klines([batch_a, batch_b]);
+
+        let open_times: Vec<i64> = merged.iter().map(|k| k.open_time).collect();
+        assert_eq!(open_times, vec![0, 60_000, 120_000]);
+        // The later batch's candle for the duplicated open_time wins.
+        assert_eq!(merged[1].close, "3");
+    }
+
+    #[test]
+    fn resample_aggregates_ohlcv_within_each_bucket() {
+        let klines = vec![
+            kline(0, "10", "12", "9", "11", "100"),
+            kline(60_000, "11", "13", "10", "12", "150"),
+            kline(120_000, "20", "21", "19", "20", "50"),
+        ];
+
+        let resampled = resample(&klines, 120_000, "2m");
+
+        assert_eq!(resampled.len(), 2);
+        assert_eq!(resampled[0].open_time, 0);
+        assert_eq!(resampled[0].open, "10");
+        assert_eq!(resampled[0].close, "12");
+        assert_eq!(resampled[0].high, "13");
+        assert_eq!(resampled[0].low, "9");
+        assert_eq!(resampled[0].volume, "250");
+        assert_eq!(resampled[0].interval, "2m");
+
+        assert_eq!(resampled[1].open_time, 120_000);
+        assert_eq!(resampled[1].volume, "50");
+    }
+
+    #[test]
+    fn resample_drops_buckets_with_unparseable_fields() {
+        let klines = vec![kline(0, "not-a-number", "12", "9", "11", "100")];
+        assert!(resample(&klines, 60_000, "1m").is_empty());
+    }
+
+    #[test]
+    fn rolling_vwap_is_none_before_the_window_fills() {
+        let klines = vec![
+            kline(0, "10", "10", "10", "10", "1"),
+            kline(60_000, "10", "10", "10", "10", "1"),
+        ];
+        let vwap = rolling_vwap(&klines, 3);
+        assert_eq!(vwap, vec![None, None]);
+    }
+
+    #[test]
+    fn rolling_vwap_weights_by_volume() {
+        let klines = vec![
+            kline(0, "10", "10", "10", "10", "1"),
+            kline(60_000, "20", "20", "20", "20", "3"),
+        ];
+        let vwap = rolling_vwap(&klines, 2);
+        assert_eq!(vwap[0], None);
+        // (10*1 + 20*3) / 4 = 17.5
+        assert_eq!(vwap[1], Some(17.5));
+    }
+
+    #[test]
+    fn rolling_realized_volatility_is_zero_for_a_constant_price() {
+        let klines: Vec<Kline> = (0..5)
+            .map(|i| kline(i * 60_000, "10", "10", "10", "10", "1"))
+            .collect();
+        let vol = rolling_realized_volatility(&klines, 3, 525_600.0);
+        assert_eq!(vol[2], Some(0.0));
+    }
+
+    #[test]
+    fn rolling_realized_volatility_requires_a_window_of_at_least_three() {
+        let klines: Vec<Kline> = (0..5)
+            .map(|i| kline(i * 60_000, "10", "10", "10", "10", "1"))
+            .collect();
+        assert_eq!(rolling_realized_volatility(&klines, 2, 525_600.0), vec![None; 5]);
+    }
+
+    #[test]
+    fn rolling_realized_volatility_is_positive_for_a_moving_price() {
+        let closes = ["10", "11", "9", "12", "8"];
+        let klines: Vec<Kline> = closes
+            .iter()
+            .enumerate()
+            .map(|(i, c)| kline(i as i64 * 60_000, c, c, c, c, "1"))
+            .collect();
+        let vol = rolling_realized_volatility(&klines, 4, 525_600.0);
+        assert!(vol[3].unwrap() > 0.0);
+    }
+}
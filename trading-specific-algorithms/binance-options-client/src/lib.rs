@@ -1,14 +1,119 @@
 pub mod api;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+pub mod chain;
+pub mod circuit_breaker;
+pub mod depth;
 pub mod error;
+pub mod export;
+pub mod failover;
+pub mod kline_aggregation;
+#[cfg(feature = "prometheus")]
+pub mod metrics;
 pub mod model;
+pub mod parity;
 pub mod parser;
+pub mod pricing;
+pub mod rate_limit;
+pub mod record_replay;
+pub mod response_cache;
+pub mod retry;
+pub mod signing;
+#[cfg(feature = "storage")]
+pub mod storage;
+pub mod symbol_validation;
+pub mod throttle;
+pub mod timestamp;
+pub(crate) mod ticker_stream;
+pub mod ticker_store;
+pub mod ticker_tracker;
+pub mod ticker_set;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod user_stream;
+pub mod ws;
+pub mod ws_supervisor;
 
 // Re-export key types for easy access
 pub use api::BinanceOptionsClient;
+pub use api::ClientBuilder;
+pub use api::HttpCall;
+pub use api::HttpResponse;
+pub use api::HttpTransport;
+pub use api::Network;
+pub use api::NewOrderRequest;
+pub use api::OrderValidationError;
+pub use api::Pagination;
+pub use api::Request;
+pub use api::RequestInterceptor;
+pub use api::ReqwestTransport;
+pub use api::ResponseSummary;
+pub use api::TickerFetchError;
 pub use api::TickerRequest;
+#[cfg(feature = "blocking")]
+pub use blocking::BlockingBinanceOptionsClient;
+#[cfg(feature = "blocking")]
+pub use blocking::BlockingClientBuilder;
+pub use chain::Expiry;
+pub use chain::OptionsChain;
+pub use chain::StrikePair;
+pub use circuit_breaker::CircuitBreaker;
+pub use circuit_breaker::CircuitHealth;
+pub use depth::DepthError;
+pub use depth::DepthUpdateEvent;
+pub use depth::ManagedOrderBook;
+pub use depth::PriceLevel;
 pub use error::BinanceOptionsClientError;
+pub use failover::BaseUrlPool;
+pub use kline_aggregation::merge_klines;
+pub use kline_aggregation::resample;
+pub use kline_aggregation::rolling_realized_volatility;
+pub use kline_aggregation::rolling_vwap;
+pub use model::OptionKind;
+pub use model::OptionSymbol;
+pub use model::OptionSymbolParseError;
 pub use model::OptionTicker;
+pub use model::OptionTickerDecimal;
+pub use model::OptionTickerRef;
+pub use model::ExerciseRecord;
+pub use model::AccountInfo;
+pub use model::CancelAllOrdersResponse;
+pub use model::GreeksError;
+pub use model::IndexPrice;
+pub use model::MarkPrice;
+pub use model::OptionPosition;
+pub use model::OrderBook;
+pub use model::OrderResponse;
 pub use model::ParsingMetrics;
+pub use model::ServerTime;
+pub use parity::ParityScanError;
+pub use parity::ParityViolation;
+pub use parity::scan_parity_violations;
+pub use pricing::Greeks;
+pub use pricing::PricingError;
+pub use rate_limit::RateLimiter;
+pub use rate_limit::RateLimitStatus;
+pub use model::ListenKeyResponse;
+pub use model::ExchangeInfo;
+pub use model::OptionContract;
+pub use retry::RetryPolicy;
+pub use symbol_validation::SymbolValidator;
+pub use throttle::RequestThrottle;
+pub use user_stream::AccountUpdateEvent;
+pub use user_stream::OrderUpdateEvent;
+pub use user_stream::UserStreamError;
+pub use user_stream::UserStreamEvent;
+pub use ws::LivenessConfig;
+pub use ws::MarketEvent;
+pub use ws::StreamKind;
+pub use ws::WsError;
+pub use ws::connect_with_liveness;
+pub use ws_supervisor::SupervisedEvent;
+pub use ws_supervisor::Supervisor;
+pub use ticker_store::TickerStore;
+pub use ticker_tracker::TickerChange;
+pub use ticker_tracker::TickerTracker;
+pub use ticker_set::TickerSet;
 
 // Initialize logging
 pub fn init_logging() {
@@ -0,0 +1,208 @@
+//! Opt-in Prometheus metrics for a [`crate::BinanceOptionsClient`]: request counts, latencies,
+//! and status/error codes (via [`ClientMetrics`] as a [`crate::RequestInterceptor`]), plus
+//! rate-limit usage and ticker-parsing durations (via its `record_*` methods). Gated behind the
+//! `prometheus` feature so clients that don't want the dependency don't pay for it.
+//!
+//! Build a [`ClientMetrics`], register it on a [`Registry`] of your own (so you can expose it
+//! alongside your application's other metrics), then install it as a request interceptor:
+//!
+//! ```no_run
+//! use binance_options_client::BinanceOptionsClient;
+//! use binance_options_client::metrics::ClientMetrics;
+//! use prometheus::Registry;
+//!
+//! let registry = Registry::new();
+//! let metrics = ClientMetrics::new(&registry)?;
+//! let client = BinanceOptionsClient::builder()
+//!     .interceptor(metrics.clone())
+//!     .build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use prometheus::{CounterVec, Gauge, Histogram, HistogramOpts, HistogramVec, Opts, Registry};
+
+use crate::api::{Request, RequestInterceptor, ResponseSummary};
+use crate::model::ParsingMetrics;
+use crate::rate_limit::RateLimitStatus;
+
+/// Prometheus metrics for a [`crate::BinanceOptionsClient`]. Cheap to clone: every field is a
+/// `prometheus` collector, which is itself reference-counted internally.
+///
+/// Install via [`crate::ClientBuilder::interceptor`] to automatically record request counts,
+/// latencies, and status codes; call [`Self::record_rate_limit`] and [`Self::record_parse`]
+/// yourself wherever you check [`crate::BinanceOptionsClient::rate_limit_status`] or call
+/// [`crate::BinanceOptionsClient::parse_ticker_with_metrics`], since those aren't requests and
+/// so don't go through the interceptor.
+#[derive(Clone)]
+pub struct ClientMetrics {
+    requests_total: CounterVec,
+    request_duration_seconds: HistogramVec,
+    rate_limit_used_weight: Gauge,
+    parse_duration_seconds: Histogram,
+}
+
+impl ClientMetrics {
+    /// Creates the metric collectors and registers them on `registry`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `prometheus::Error` if a metric of the same name is already registered on
+    /// `registry`.
+    pub fn new(registry: &Registry) -> prometheus::Result<Self> {
+        let requests_total = CounterVec::new(
+            Opts::new(
+                "binance_options_requests_total",
+                "Total API requests attempted, by endpoint path and outcome.",
+            ),
+            &["path", "status"],
+        )?;
+        let request_duration_seconds = HistogramVec::new(
+            HistogramOpts::new(
+                "binance_options_request_duration_seconds",
+                "API request latency in seconds, by endpoint path.",
+            ),
+            &["path"],
+        )?;
+        let rate_limit_used_weight = Gauge::new(
+            "binance_options_rate_limit_used_weight",
+            "Most recently observed request-weight usage for the current window.",
+        )?;
+        let parse_duration_seconds = Histogram::with_opts(HistogramOpts::new(
+            "binance_options_parse_duration_seconds",
+            "Ticker JSON parsing latency in seconds.",
+        ))?;
+
+        registry.register(Box::new(requests_total.clone()))?;
+        registry.register(Box::new(request_duration_seconds.clone()))?;
+        registry.register(Box::new(rate_limit_used_weight.clone()))?;
+        registry.register(Box::new(parse_duration_seconds.clone()))?;
+
+        Ok(Self {
+            requests_total,
+            request_duration_seconds,
+            rate_limit_used_weight,
+            parse_duration_seconds,
+        })
+    }
+
+    /// Records the most recently observed rate-limit usage, as returned by
+    /// [`crate::BinanceOptionsClient::rate_limit_status`].
+    pub fn record_rate_limit(&self, status: RateLimitStatus) {
+        self.rate_limit_used_weight.set(f64::from(status.used_weight));
+    }
+
+    /// Records one ticker-parsing pass's duration, as returned by
+    /// [`crate::BinanceOptionsClient::parse_ticker_with_metrics`].
+    pub fn record_parse(&self, metrics: &ParsingMetrics) {
+        self.parse_duration_seconds
+            .observe(metrics.total_time_ms / 1000.0);
+    }
+}
+
+impl RequestInterceptor for ClientMetrics {
+    fn after(&self, request: &Request, response: &ResponseSummary) {
+        let status = response
+            .status
+            .map_or_else(|| "network_error".to_string(), |status| status.as_u16().to_string());
+        self.requests_total
+            .with_label_values(&[&request.path, &status])
+            .inc();
+        self.request_duration_seconds
+            .with_label_values(&[&request.path])
+            .observe(response.elapsed.as_secs_f64());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use prometheus::proto::MetricFamily;
+    use std::time::Duration;
+
+    fn metric_family<'a>(families: &'a [MetricFamily], name: &str) -> &'a MetricFamily {
+        families
+            .iter()
+            .find(|family| family.name() == name)
+            .unwrap_or_else(|| panic!("no metric family named {name}"))
+    }
+
+    #[test]
+    fn after_increments_the_request_counter_and_observes_latency() {
+        let registry = Registry::new();
+        let metrics = ClientMetrics::new(&registry).unwrap();
+
+        let request: Request = crate::api::ServerTimeRequest::new().into();
+        metrics.after(
+            &request,
+            &ResponseSummary {
+                status: Some(reqwest::StatusCode::OK),
+                elapsed: Duration::from_millis(50),
+            },
+        );
+
+        let families = registry.gather();
+        let requests = metric_family(&families, "binance_options_requests_total");
+        let counter = &requests.get_metric()[0];
+        assert_eq!(counter.get_counter().value(), 1.0);
+        assert!(counter
+            .get_label()
+            .iter()
+            .any(|label| label.name() == "status" && label.value() == "200"));
+
+        let latency = metric_family(&families, "binance_options_request_duration_seconds");
+        assert_eq!(latency.get_metric()[0].get_histogram().get_sample_count(), 1);
+    }
+
+    #[test]
+    fn a_network_error_is_labeled_distinctly_from_an_http_status() {
+        let registry = Registry::new();
+        let metrics = ClientMetrics::new(&registry).unwrap();
+
+        let request: Request = crate::api::ServerTimeRequest::new().into();
+        metrics.after(
+            &request,
+            &ResponseSummary {
+                status: None,
+                elapsed: Duration::from_millis(10),
+            },
+        );
+
+        let families = registry.gather();
+        let requests = metric_family(&families, "binance_options_requests_total");
+        assert!(requests.get_metric()[0]
+            .get_label()
+            .iter()
+            .any(|label| label.name() == "status" && label.value() == "network_error"));
+    }
+
+    #[test]
+    fn record_rate_limit_sets_the_gauge_to_the_used_weight() {
+        let registry = Registry::new();
+        let metrics = ClientMetrics::new(&registry).unwrap();
+
+        metrics.record_rate_limit(RateLimitStatus {
+            used_weight: 42,
+            limit: Some(1200),
+        });
+
+        let families = registry.gather();
+        let gauge = metric_family(&families, "binance_options_rate_limit_used_weight");
+        assert_eq!(gauge.get_metric()[0].get_gauge().value(), 42.0);
+    }
+
+    #[test]
+    fn record_parse_observes_the_parse_duration_histogram() {
+        let registry = Registry::new();
+        let metrics = ClientMetrics::new(&registry).unwrap();
+
+        metrics.record_parse(&ParsingMetrics {
+            time_per_entry_ms: 0.5,
+            entries_parsed: 10,
+            total_time_ms: 5.0,
+        });
+
+        let families = registry.gather();
+        let histogram = metric_family(&families, "binance_options_parse_duration_seconds");
+        assert_eq!(histogram.get_metric()[0].get_histogram().get_sample_count(), 1);
+    }
+}
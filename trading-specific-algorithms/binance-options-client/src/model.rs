@@ -1,4 +1,12 @@
+use chrono::{DateTime, TimeZone, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+/// Seconds in a year, for converting a duration until expiry into the fractional-year units
+/// the pricing module expects.
+const SECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0;
 
 /// Represents a ticker record returned from the Binance Options API.
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
@@ -33,12 +41,12 @@ pub struct OptionTicker {
     /// Ask price.
     #[serde(rename = "askPrice")]
     pub ask_price: String,
-    /// Opening time (timestamp).
-    #[serde(rename = "openTime")]
-    pub open_time: i64,
-    /// Closing time (timestamp).
-    #[serde(rename = "closeTime")]
-    pub close_time: i64,
+    /// Opening time.
+    #[serde(rename = "openTime", with = "crate::timestamp")]
+    pub open_time: DateTime<Utc>,
+    /// Closing time.
+    #[serde(rename = "closeTime", with = "crate::timestamp")]
+    pub close_time: DateTime<Utc>,
     /// First trade ID.
     #[serde(rename = "firstTradeId")]
     pub first_trade_id: i64,
@@ -53,6 +61,661 @@ pub struct OptionTicker {
     pub exercise_price: String,
 }
 
+/// Decimal-typed variant of [`OptionTicker`], for consumers that would otherwise re-parse every
+/// price/volume field themselves. Built via [`OptionTicker::to_decimal`]; the original string
+/// fields remain available on `OptionTicker` for zero-loss use cases (e.g. forwarding the raw
+/// response unchanged).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OptionTickerDecimal {
+    /// Price change.
+    pub price_change: Decimal,
+    /// Price change percentage.
+    pub price_change_percent: Decimal,
+    /// Last traded price.
+    pub last_price: Decimal,
+    /// Last traded quantity.
+    pub last_qty: Decimal,
+    /// Opening price.
+    pub open: Decimal,
+    /// Highest price.
+    pub high: Decimal,
+    /// Lowest price.
+    pub low: Decimal,
+    /// Trading volume.
+    pub volume: Decimal,
+    /// Trading amount.
+    pub amount: Decimal,
+    /// Bid price.
+    pub bid_price: Decimal,
+    /// Ask price.
+    pub ask_price: Decimal,
+    /// Strike price.
+    pub strike_price: Decimal,
+    /// Exercise price.
+    pub exercise_price: Decimal,
+}
+
+impl OptionTicker {
+    /// Parses the string-typed price/volume/strike fields into [`OptionTickerDecimal`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `rust_decimal::Error` if any field isn't a valid decimal string.
+    pub fn to_decimal(&self) -> Result<OptionTickerDecimal, rust_decimal::Error> {
+        Ok(OptionTickerDecimal {
+            price_change: Decimal::from_str(&self.price_change)?,
+            price_change_percent: Decimal::from_str(&self.price_change_percent)?,
+            last_price: Decimal::from_str(&self.last_price)?,
+            last_qty: Decimal::from_str(&self.last_qty)?,
+            open: Decimal::from_str(&self.open)?,
+            high: Decimal::from_str(&self.high)?,
+            low: Decimal::from_str(&self.low)?,
+            volume: Decimal::from_str(&self.volume)?,
+            amount: Decimal::from_str(&self.amount)?,
+            bid_price: Decimal::from_str(&self.bid_price)?,
+            ask_price: Decimal::from_str(&self.ask_price)?,
+            strike_price: Decimal::from_str(&self.strike_price)?,
+            exercise_price: Decimal::from_str(&self.exercise_price)?,
+        })
+    }
+
+    /// Parses `symbol` into its structured form. See [`OptionSymbol`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `OptionSymbolParseError` if `symbol` doesn't follow Binance's
+    /// `{UNDERLYING}-{YYMMDD}-{STRIKE}-{C|P}` convention.
+    pub fn parsed_symbol(&self) -> Result<OptionSymbol, OptionSymbolParseError> {
+        self.symbol.parse()
+    }
+
+    /// Returns the number of whole days until this option expires, or `None` if `symbol`
+    /// doesn't parse or its expiry isn't a valid calendar date.
+    pub fn days_to_expiry(&self) -> Option<i64> {
+        self.parsed_symbol().ok()?.days_to_expiry()
+    }
+
+    /// Returns the time until this option expires, in fractional years, or `None` if
+    /// `symbol` doesn't parse or its expiry isn't a valid calendar date.
+    pub fn time_to_expiry_years(&self) -> Option<f64> {
+        self.parsed_symbol().ok()?.time_to_expiry_years()
+    }
+
+    /// Returns `spot / strike`, or `None` if `symbol` doesn't parse. See
+    /// [`OptionSymbol::moneyness`].
+    pub fn moneyness(&self, spot: Decimal) -> Option<Decimal> {
+        Some(self.parsed_symbol().ok()?.moneyness(spot))
+    }
+
+    /// Returns whether this option is in-the-money at `spot`, or `None` if `symbol` doesn't
+    /// parse. See [`OptionSymbol::is_itm`].
+    pub fn is_itm(&self, spot: Decimal) -> Option<bool> {
+        Some(self.parsed_symbol().ok()?.is_itm(spot))
+    }
+
+    /// Returns whether this option is out-of-the-money at `spot`, or `None` if `symbol`
+    /// doesn't parse. See [`OptionSymbol::is_otm`].
+    pub fn is_otm(&self, spot: Decimal) -> Option<bool> {
+        Some(self.parsed_symbol().ok()?.is_otm(spot))
+    }
+
+    /// Computes greeks locally from this ticker's quoted `last_price`, by first solving for
+    /// the implied volatility and then evaluating the Black-76 greeks at that volatility.
+    /// Useful when the `/eapi/v1/mark` endpoint (which already returns greeks) is unavailable.
+    ///
+    /// `spot` is the current underlying forward/index price; `risk_free_rate` is annualized.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GreeksError` if `symbol` or `last_price` can't be parsed, the option has
+    /// already expired, or the underlying pricing computation fails.
+    pub fn greeks(
+        &self,
+        spot: Decimal,
+        risk_free_rate: f64,
+    ) -> Result<crate::pricing::Greeks, GreeksError> {
+        let symbol = self.parsed_symbol()?;
+        let expiry = symbol.expiry_datetime().ok_or(GreeksError::InvalidExpiry)?;
+        let time_to_expiry_years = (expiry - Utc::now()).as_seconds_f64() / SECONDS_PER_YEAR;
+        if time_to_expiry_years <= 0.0 {
+            return Err(GreeksError::Expired);
+        }
+
+        let forward = spot.to_f64().ok_or(GreeksError::DecimalConversion)?;
+        let strike = symbol.strike.to_f64().ok_or(GreeksError::DecimalConversion)?;
+        let last_price = Decimal::from_str(&self.last_price)?
+            .to_f64()
+            .ok_or(GreeksError::DecimalConversion)?;
+
+        let implied_vol = crate::pricing::implied_volatility(
+            symbol.kind,
+            last_price,
+            forward,
+            strike,
+            time_to_expiry_years,
+            risk_free_rate,
+        )?;
+        Ok(crate::pricing::black76_greeks(
+            symbol.kind,
+            forward,
+            strike,
+            time_to_expiry_years,
+            risk_free_rate,
+            implied_vol,
+        )?)
+    }
+}
+
+/// Borrowed counterpart of [`OptionTicker`]: every string field borrows directly from the
+/// response buffer instead of allocating its own `String`, avoiding 14 allocations per ticker
+/// for read-only processing (filtering, scanning for the best bid/ask, etc.) that never
+/// needs to hold onto a ticker past the buffer's lifetime. Build one via
+/// [`crate::parser::parse_ticker_borrowed`]; call [`Self::to_owned_ticker`] when a caller does
+/// need an independent, owned copy.
+#[derive(Debug, Deserialize, Clone, PartialEq)]
+pub struct OptionTickerRef<'a> {
+    /// The ticker symbol.
+    pub symbol: &'a str,
+    /// Price change.
+    #[serde(rename = "priceChange")]
+    pub price_change: &'a str,
+    /// Price change percentage.
+    #[serde(rename = "priceChangePercent")]
+    pub price_change_percent: &'a str,
+    /// Last traded price.
+    #[serde(rename = "lastPrice")]
+    pub last_price: &'a str,
+    /// Last traded quantity.
+    #[serde(rename = "lastQty")]
+    pub last_qty: &'a str,
+    /// Opening price.
+    pub open: &'a str,
+    /// Highest price.
+    pub high: &'a str,
+    /// Lowest price.
+    pub low: &'a str,
+    /// Trading volume.
+    pub volume: &'a str,
+    /// Trading amount.
+    pub amount: &'a str,
+    /// Bid price.
+    #[serde(rename = "bidPrice")]
+    pub bid_price: &'a str,
+    /// Ask price.
+    #[serde(rename = "askPrice")]
+    pub ask_price: &'a str,
+    /// Opening time.
+    #[serde(rename = "openTime", with = "crate::timestamp")]
+    pub open_time: DateTime<Utc>,
+    /// Closing time.
+    #[serde(rename = "closeTime", with = "crate::timestamp")]
+    pub close_time: DateTime<Utc>,
+    /// First trade ID.
+    #[serde(rename = "firstTradeId")]
+    pub first_trade_id: i64,
+    /// Total number of trades.
+    #[serde(rename = "tradeCount")]
+    pub trade_count: i64,
+    /// Strike price.
+    #[serde(rename = "strikePrice")]
+    pub strike_price: &'a str,
+    /// Exercise price.
+    #[serde(rename = "exercisePrice")]
+    pub exercise_price: &'a str,
+}
+
+impl<'a> OptionTickerRef<'a> {
+    /// Copies every borrowed field into an owned [`OptionTicker`].
+    pub fn to_owned_ticker(&self) -> OptionTicker {
+        OptionTicker {
+            symbol: self.symbol.to_string(),
+            price_change: self.price_change.to_string(),
+            price_change_percent: self.price_change_percent.to_string(),
+            last_price: self.last_price.to_string(),
+            last_qty: self.last_qty.to_string(),
+            open: self.open.to_string(),
+            high: self.high.to_string(),
+            low: self.low.to_string(),
+            volume: self.volume.to_string(),
+            amount: self.amount.to_string(),
+            bid_price: self.bid_price.to_string(),
+            ask_price: self.ask_price.to_string(),
+            open_time: self.open_time,
+            close_time: self.close_time,
+            first_trade_id: self.first_trade_id,
+            trade_count: self.trade_count,
+            strike_price: self.strike_price.to_string(),
+            exercise_price: self.exercise_price.to_string(),
+        }
+    }
+}
+
+/// Error returned by [`OptionTicker::greeks`].
+#[derive(Debug, thiserror::Error)]
+pub enum GreeksError {
+    /// The ticker's `symbol` couldn't be parsed.
+    #[error("failed to parse option symbol: {0}")]
+    Symbol(#[from] OptionSymbolParseError),
+    /// A string-typed price field wasn't a valid decimal.
+    #[error("failed to parse decimal field: {0}")]
+    Decimal(#[from] rust_decimal::Error),
+    /// A `Decimal` value was too large or precise to represent as an `f64`.
+    #[error("decimal value could not be represented as f64")]
+    DecimalConversion,
+    /// The option symbol's expiry date isn't a valid calendar date.
+    #[error("option symbol has an invalid expiry date")]
+    InvalidExpiry,
+    /// The option's expiry has already passed, so there's no meaningful time value left.
+    #[error("option has already expired")]
+    Expired,
+    /// The underlying Black-76 pricing computation failed.
+    #[error(transparent)]
+    Pricing(#[from] crate::pricing::PricingError),
+}
+
+/// Whether an [`OptionSymbol`] is a call or a put.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum OptionKind {
+    /// A call option.
+    #[serde(rename = "CALL")]
+    Call,
+    /// A put option.
+    #[serde(rename = "PUT")]
+    Put,
+}
+
+impl std::fmt::Display for OptionKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OptionKind::Call => "C",
+            OptionKind::Put => "P",
+        })
+    }
+}
+
+/// Error returned when an [`OptionSymbol`] fails to parse from a string.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum OptionSymbolParseError {
+    /// The symbol doesn't have the expected `{UNDERLYING}-{YYMMDD}-{STRIKE}-{C|P}` shape.
+    #[error("expected 4 '-'-separated fields, got {0}: {1:?}")]
+    WrongFieldCount(usize, String),
+    /// The expiry field wasn't 6 ASCII digits (`YYMMDD`).
+    #[error("invalid expiry date {0:?}: expected 6 digits (YYMMDD)")]
+    InvalidExpiry(String),
+    /// The strike field wasn't a valid decimal.
+    #[error("invalid strike price {0:?}")]
+    InvalidStrike(String),
+    /// The option-type field wasn't `"C"` or `"P"`.
+    #[error("invalid option type {0:?}: expected \"C\" or \"P\"")]
+    InvalidKind(String),
+}
+
+/// A parsed Binance option symbol, e.g. `"BTC-200730-9000-C"`: the `BTC` underlying, a
+/// 2020-07-30 expiry, a 9000 strike, and a call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptionSymbol {
+    /// The underlying asset (e.g. `"BTC"`).
+    pub underlying: String,
+    /// Expiry year (e.g. `2020` for `"20"`).
+    pub expiry_year: u32,
+    /// Expiry month (1-12).
+    pub expiry_month: u32,
+    /// Expiry day of month (1-31).
+    pub expiry_day: u32,
+    /// Strike price.
+    pub strike: Decimal,
+    /// Whether this is a call or a put.
+    pub kind: OptionKind,
+}
+
+/// Returns the expiry instant for an option expiring on `year`-`month`-`day`, assuming
+/// Binance's standard 08:00 UTC expiry time for all options. Returns `None` if the date isn't
+/// a valid calendar date.
+pub(crate) fn option_expiry_datetime(year: u32, month: u32, day: u32) -> Option<DateTime<Utc>> {
+    Utc.with_ymd_and_hms(year.try_into().ok()?, month, day, 8, 0, 0)
+        .single()
+}
+
+impl OptionSymbol {
+    /// Returns this option's expiry instant, assuming Binance's standard 08:00 UTC expiry
+    /// time for all options. Returns `None` if `expiry_year`/`expiry_month`/`expiry_day`
+    /// isn't a valid calendar date.
+    pub fn expiry_datetime(&self) -> Option<DateTime<Utc>> {
+        option_expiry_datetime(self.expiry_year, self.expiry_month, self.expiry_day)
+    }
+
+    /// Returns the number of whole days until this option expires, or `None` if its expiry
+    /// isn't a valid calendar date. Negative once the option has expired.
+    pub fn days_to_expiry(&self) -> Option<i64> {
+        Some((self.expiry_datetime()? - Utc::now()).num_days())
+    }
+
+    /// Returns the time until this option expires, in fractional years (using the same
+    /// 365.25-day year as the pricing module), or `None` if its expiry isn't a valid calendar
+    /// date. Negative once the option has expired.
+    pub fn time_to_expiry_years(&self) -> Option<f64> {
+        Some((self.expiry_datetime()? - Utc::now()).as_seconds_f64() / SECONDS_PER_YEAR)
+    }
+
+    /// Returns `spot / strike`: greater than 1 when `spot` is above the strike, less than 1
+    /// when below, and exactly 1 at-the-money. Moneyness doesn't depend on call/put side.
+    pub fn moneyness(&self, spot: Decimal) -> Decimal {
+        spot / self.strike
+    }
+
+    /// Returns whether this option currently has intrinsic value: a call is in-the-money when
+    /// `spot` is above the strike, a put when `spot` is below it.
+    pub fn is_itm(&self, spot: Decimal) -> bool {
+        match self.kind {
+            OptionKind::Call => spot > self.strike,
+            OptionKind::Put => spot < self.strike,
+        }
+    }
+
+    /// Returns `!self.is_itm(spot)`: true at-the-money as well as strictly out-of-the-money,
+    /// since neither has intrinsic value.
+    pub fn is_otm(&self, spot: Decimal) -> bool {
+        !self.is_itm(spot)
+    }
+}
+
+impl std::fmt::Display for OptionSymbol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}-{:02}{:02}{:02}-{}-{}",
+            self.underlying,
+            self.expiry_year % 100,
+            self.expiry_month,
+            self.expiry_day,
+            self.strike,
+            self.kind
+        )
+    }
+}
+
+impl FromStr for OptionSymbol {
+    type Err = OptionSymbolParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts: Vec<&str> = s.split('-').collect();
+        let [underlying, expiry, strike, kind] = parts.as_slice() else {
+            return Err(OptionSymbolParseError::WrongFieldCount(
+                parts.len(),
+                s.to_string(),
+            ));
+        };
+
+        if expiry.len() != 6 || !expiry.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(OptionSymbolParseError::InvalidExpiry(expiry.to_string()));
+        }
+        let expiry_year = 2000 + expiry[0..2].parse::<u32>().unwrap();
+        let expiry_month = expiry[2..4].parse::<u32>().unwrap();
+        let expiry_day = expiry[4..6].parse::<u32>().unwrap();
+
+        let strike = Decimal::from_str(strike)
+            .map_err(|_| OptionSymbolParseError::InvalidStrike(strike.to_string()))?;
+
+        let kind = match *kind {
+            "C" => OptionKind::Call,
+            "P" => OptionKind::Put,
+            other => return Err(OptionSymbolParseError::InvalidKind(other.to_string())),
+        };
+
+        Ok(Self {
+            underlying: underlying.to_string(),
+            expiry_year,
+            expiry_month,
+            expiry_day,
+            strike,
+            kind,
+        })
+    }
+}
+
+/// Represents an order book snapshot returned from the Binance Options API.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct OrderBook {
+    /// Bid price levels as (price, quantity) pairs, best bid first.
+    pub bids: Vec<(String, String)>,
+    /// Ask price levels as (price, quantity) pairs, best ask first.
+    pub asks: Vec<(String, String)>,
+    /// Update ID for this order book snapshot.
+    #[serde(rename = "updateId")]
+    pub update_id: i64,
+}
+
+/// Represents a mark price and Greeks snapshot returned from the Binance Options API.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct MarkPrice {
+    /// The ticker symbol.
+    pub symbol: String,
+    /// Mark price.
+    #[serde(rename = "markPrice")]
+    pub mark_price: String,
+    /// Mark implied volatility.
+    #[serde(rename = "markIV")]
+    pub mark_iv: String,
+    /// Delta.
+    pub delta: String,
+    /// Gamma.
+    pub gamma: String,
+    /// Theta.
+    pub theta: String,
+    /// Vega.
+    pub vega: String,
+    /// Upper price limit.
+    #[serde(rename = "highPriceLimit")]
+    pub high_price_limit: String,
+    /// Lower price limit.
+    #[serde(rename = "lowPriceLimit")]
+    pub low_price_limit: String,
+}
+
+/// Represents an underlying index price snapshot returned from the Binance Options API.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct IndexPrice {
+    /// The underlying asset (e.g. "BTCUSDT").
+    pub underlying: String,
+    /// The index price.
+    #[serde(rename = "indexPrice")]
+    pub index_price: String,
+    /// Timestamp of the index price.
+    #[serde(with = "crate::timestamp")]
+    pub time: DateTime<Utc>,
+}
+
+/// Represents a single settlement record returned from the historical exercise records endpoint.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ExerciseRecord {
+    /// The option symbol that was settled.
+    pub symbol: String,
+    /// The option's strike price.
+    #[serde(rename = "strikePrice")]
+    pub strike_price: String,
+    /// The realized (settlement) price used for exercise.
+    #[serde(rename = "realStrikePrice")]
+    pub realized_price: String,
+    /// Expiry date of the settled option.
+    #[serde(rename = "expiryDate", with = "crate::timestamp")]
+    pub expiry_date: DateTime<Utc>,
+    /// Settlement outcome (e.g. "REALISTIC_VALUE_STRICKEN", "EXTRINSIC_VALUE_EXPIRED").
+    #[serde(rename = "strikeResult")]
+    pub result: String,
+}
+
+/// Represents a single asset balance within the signed account information response.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct AccountAsset {
+    /// The asset name (e.g. "USDT").
+    pub asset: String,
+    /// Margin balance for this asset.
+    #[serde(rename = "marginBalance")]
+    pub margin_balance: String,
+    /// Account equity for this asset.
+    pub equity: String,
+    /// Amount available for withdrawal or new positions.
+    pub available: String,
+}
+
+/// Represents the account's aggregate Greeks exposure for a single underlying.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct GreeksExposure {
+    /// The underlying asset (e.g. "BTCUSDT").
+    pub underlying: String,
+    /// Delta.
+    pub delta: String,
+    /// Gamma.
+    pub gamma: String,
+    /// Theta.
+    pub theta: String,
+    /// Vega.
+    pub vega: String,
+}
+
+/// Represents the signed account information response, covering asset balances
+/// and aggregate Greeks exposure.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct AccountInfo {
+    /// Per-asset balances.
+    pub asset: Vec<AccountAsset>,
+    /// Aggregate Greeks exposure, grouped by underlying.
+    pub greeks: Vec<GreeksExposure>,
+    /// Account risk level.
+    #[serde(rename = "riskLevel")]
+    pub risk_level: String,
+}
+
+/// Represents the response returned after placing a new order.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct OrderResponse {
+    /// Exchange-assigned order ID.
+    #[serde(rename = "orderId")]
+    pub order_id: i64,
+    /// The option symbol the order was placed for.
+    pub symbol: String,
+    /// Limit price, if the order has one.
+    pub price: String,
+    /// Order quantity.
+    pub quantity: String,
+    /// Quantity executed so far.
+    #[serde(rename = "executedQty")]
+    pub executed_qty: String,
+    /// Order side.
+    pub side: crate::api::OrderSide,
+    /// Order type.
+    #[serde(rename = "type")]
+    pub order_type: crate::api::OrderType,
+    /// Time-in-force policy.
+    #[serde(rename = "timeInForce")]
+    pub time_in_force: crate::api::TimeInForce,
+    /// Current order status (e.g. "ACCEPTED", "FILLED", "CANCELLED").
+    pub status: String,
+    /// Client-supplied order ID, if one was given.
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+    /// Order creation time.
+    #[serde(rename = "createTime", with = "crate::timestamp")]
+    pub create_time: DateTime<Utc>,
+}
+
+/// Represents the response returned after cancelling all open orders for a symbol.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct CancelAllOrdersResponse {
+    /// The option symbol the orders were cancelled for.
+    pub symbol: String,
+    /// The number of orders that were cancelled.
+    pub count: i64,
+}
+
+/// Represents a single option position held by the account.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct OptionPosition {
+    /// The option symbol.
+    pub symbol: String,
+    /// Position quantity (negative for a short position).
+    pub quantity: String,
+    /// Average entry price.
+    #[serde(rename = "entryPrice")]
+    pub entry_price: String,
+    /// Current mark price.
+    #[serde(rename = "markPrice")]
+    pub mark_price: String,
+    /// Unrealized profit and loss.
+    #[serde(rename = "unrealizedPNL")]
+    pub unrealized_pnl: String,
+}
+
+/// Represents the Binance Options API server's current time.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub struct ServerTime {
+    /// Server time.
+    #[serde(rename = "serverTime", with = "crate::timestamp")]
+    pub server_time: DateTime<Utc>,
+}
+
+/// Response from creating a user data stream listen key.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq, Eq)]
+pub struct ListenKeyResponse {
+    /// The listen key, used to subscribe to the user data stream and to keep it alive.
+    #[serde(rename = "listenKey")]
+    pub listen_key: String,
+}
+
+/// Response from `GET /eapi/v1/exchangeInfo`. Only the fields [`crate::symbol_validation`]
+/// needs are modeled; the real response carries other top-level fields this struct ignores.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ExchangeInfo {
+    /// The exchange's timezone, e.g. `"UTC"`.
+    pub timezone: String,
+    /// Server time at which this snapshot was taken.
+    #[serde(rename = "serverTime", with = "crate::timestamp")]
+    pub server_time: DateTime<Utc>,
+    /// The tradable option contracts (one per underlying, not one per strike/expiry).
+    #[serde(rename = "optionContracts")]
+    pub option_contracts: Vec<OptionContract>,
+}
+
+/// A single tradable contract (underlying pair) listed in [`ExchangeInfo::option_contracts`].
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct OptionContract {
+    /// Binance's internal contract ID.
+    pub id: i64,
+    /// The base asset, e.g. `"BTC"`, matching a parsed [`OptionSymbol`]'s `underlying` field
+    /// (which, confusingly, is the base asset alone, not this struct's `underlying`).
+    #[serde(rename = "baseAsset")]
+    pub base_asset: String,
+    /// The quote asset, e.g. `"USDT"`.
+    #[serde(rename = "quoteAsset")]
+    pub quote_asset: String,
+    /// The underlying trading pair, e.g. `"BTCUSDT"`.
+    pub underlying: String,
+    /// The asset option premiums and settlements are paid in, e.g. `"USDT"`.
+    #[serde(rename = "settleAsset")]
+    pub settle_asset: String,
+    /// Price/quantity filters for orders against this underlying. Absent in older or stripped-
+    /// down `exchangeInfo` payloads, hence `#[serde(default)]`.
+    #[serde(default)]
+    pub filters: Option<ContractFilters>,
+}
+
+/// Client-side order filters for an [`OptionContract`]'s underlying, mirroring Binance's
+/// `PRICE_FILTER`, `LOT_SIZE`, and `MIN_NOTIONAL` filter types. Checked by
+/// [`crate::api::NewOrderRequest::validate`] before an order is ever sent, so avoidable
+/// rejections (off-tick prices, under-notional orders) are caught locally.
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct ContractFilters {
+    /// Minimum price increment an order's `price` must be a multiple of.
+    #[serde(rename = "tickSize")]
+    pub tick_size: String,
+    /// Minimum quantity increment an order's `quantity` must be a multiple of.
+    #[serde(rename = "stepSize")]
+    pub step_size: String,
+    /// Minimum notional value (`price * quantity`) an order must meet.
+    #[serde(rename = "minNotional")]
+    pub min_notional: String,
+}
+
 /// Contains metrics related to the performance of the JSON parsing process.
 #[derive(Debug)]
 pub struct ParsingMetrics {
@@ -63,3 +726,176 @@ pub struct ParsingMetrics {
     /// Total parsing time in milliseconds.
     pub total_time_ms: f64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_ticker() -> OptionTicker {
+        OptionTicker {
+            symbol: "BTC-200730-9000-C".to_string(),
+            price_change: "10.5".to_string(),
+            price_change_percent: "0.05".to_string(),
+            last_price: "210.5".to_string(),
+            last_qty: "1.2".to_string(),
+            open: "200".to_string(),
+            high: "220".to_string(),
+            low: "195".to_string(),
+            volume: "1000".to_string(),
+            amount: "200000".to_string(),
+            bid_price: "209".to_string(),
+            ask_price: "211".to_string(),
+            open_time: DateTime::UNIX_EPOCH,
+            close_time: DateTime::UNIX_EPOCH,
+            first_trade_id: 0,
+            trade_count: 0,
+            strike_price: "9000".to_string(),
+            exercise_price: "9050".to_string(),
+        }
+    }
+
+    #[test]
+    fn to_decimal_parses_every_numeric_field() {
+        let decimal = sample_ticker().to_decimal().unwrap();
+        assert_eq!(decimal.last_price, Decimal::from_str("210.5").unwrap());
+        assert_eq!(decimal.strike_price, Decimal::from_str("9000").unwrap());
+    }
+
+    #[test]
+    fn to_decimal_rejects_a_non_numeric_field() {
+        let mut ticker = sample_ticker();
+        ticker.last_price = "not-a-number".to_string();
+        assert!(ticker.to_decimal().is_err());
+    }
+
+    #[test]
+    fn option_symbol_parses_a_call() {
+        let symbol: OptionSymbol = "BTC-200730-9000-C".parse().unwrap();
+        assert_eq!(symbol.underlying, "BTC");
+        assert_eq!(symbol.expiry_year, 2020);
+        assert_eq!(symbol.expiry_month, 7);
+        assert_eq!(symbol.expiry_day, 30);
+        assert_eq!(symbol.strike, Decimal::from_str("9000").unwrap());
+        assert_eq!(symbol.kind, OptionKind::Call);
+    }
+
+    #[test]
+    fn option_symbol_round_trips_through_display() {
+        let symbol: OptionSymbol = "ETH-211231-4000-P".parse().unwrap();
+        assert_eq!(symbol.to_string(), "ETH-211231-4000-P");
+    }
+
+    #[test]
+    fn option_symbol_rejects_malformed_input() {
+        assert!("BTC-200730-9000".parse::<OptionSymbol>().is_err());
+        assert!("BTC-NOTADATE-9000-C".parse::<OptionSymbol>().is_err());
+        assert!("BTC-200730-notastrike-C".parse::<OptionSymbol>().is_err());
+        assert!("BTC-200730-9000-X".parse::<OptionSymbol>().is_err());
+    }
+
+    #[test]
+    fn parsed_symbol_accessor_matches_direct_parse() {
+        let mut ticker = sample_ticker();
+        ticker.symbol = "BTC-200730-9000-C".to_string();
+        assert_eq!(
+            ticker.parsed_symbol().unwrap(),
+            "BTC-200730-9000-C".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn order_response_deserializes_typed_enum_fields() {
+        let json = r#"{
+            "orderId": 1,
+            "symbol": "BTC-200730-9000-C",
+            "price": "100",
+            "quantity": "1",
+            "executedQty": "1",
+            "side": "BUY",
+            "type": "LIMIT",
+            "timeInForce": "GTC",
+            "status": "FILLED",
+            "clientOrderId": "abc",
+            "createTime": 1690000000000
+        }"#;
+
+        let order: OrderResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(order.side, crate::api::OrderSide::Buy);
+        assert_eq!(order.order_type, crate::api::OrderType::Limit);
+        assert_eq!(order.time_in_force, crate::api::TimeInForce::GoodTillCancelled);
+    }
+
+    #[test]
+    fn expiry_datetime_uses_eight_am_utc() {
+        let symbol: OptionSymbol = "BTC-271230-9000-C".parse().unwrap();
+        let expiry = symbol.expiry_datetime().unwrap();
+        assert_eq!(expiry.to_rfc3339(), "2027-12-30T08:00:00+00:00");
+    }
+
+    #[test]
+    fn greeks_computes_a_plausible_call_delta_from_the_last_price() {
+        let mut ticker = sample_ticker();
+        ticker.symbol = "BTC-271230-9000-C".to_string();
+        ticker.last_price = "500".to_string();
+
+        let greeks = ticker
+            .greeks(Decimal::from_str("9200").unwrap(), 0.02)
+            .unwrap();
+        assert!(greeks.delta > 0.0 && greeks.delta < 1.0);
+        assert!(greeks.vega > 0.0);
+    }
+
+    #[test]
+    fn greeks_rejects_an_expired_option() {
+        let ticker = sample_ticker();
+        assert!(matches!(
+            ticker.greeks(Decimal::from_str("9200").unwrap(), 0.02),
+            Err(GreeksError::Expired)
+        ));
+    }
+
+    #[test]
+    fn days_to_expiry_is_positive_for_a_future_expiry() {
+        let symbol: OptionSymbol = "BTC-271230-9000-C".parse().unwrap();
+        assert!(symbol.days_to_expiry().unwrap() > 0);
+    }
+
+    #[test]
+    fn days_to_expiry_is_negative_for_a_past_expiry() {
+        let symbol: OptionSymbol = "BTC-200730-9000-C".parse().unwrap();
+        assert!(symbol.days_to_expiry().unwrap() < 0);
+    }
+
+    #[test]
+    fn moneyness_is_one_at_the_strike() {
+        let symbol: OptionSymbol = "BTC-271230-9000-C".parse().unwrap();
+        assert_eq!(
+            symbol.moneyness(Decimal::from_str("9000").unwrap()),
+            Decimal::from_str("1").unwrap()
+        );
+    }
+
+    #[test]
+    fn call_is_itm_above_the_strike_and_put_is_itm_below_it() {
+        let call: OptionSymbol = "BTC-271230-9000-C".parse().unwrap();
+        let put: OptionSymbol = "BTC-271230-9000-P".parse().unwrap();
+        let spot = Decimal::from_str("9500").unwrap();
+
+        assert!(call.is_itm(spot));
+        assert!(!call.is_otm(spot));
+        assert!(!put.is_itm(spot));
+        assert!(put.is_otm(spot));
+    }
+
+    #[test]
+    fn ticker_moneyness_helpers_delegate_to_the_parsed_symbol() {
+        let mut ticker = sample_ticker();
+        ticker.symbol = "BTC-271230-9000-C".to_string();
+        let spot = Decimal::from_str("9500").unwrap();
+
+        assert_eq!(ticker.is_itm(spot), Some(true));
+        assert_eq!(ticker.is_otm(spot), Some(false));
+        assert!(ticker.days_to_expiry().unwrap() > 0);
+        assert!(ticker.time_to_expiry_years().unwrap() > 0.0);
+    }
+}
@@ -0,0 +1,224 @@
+//! Put-call parity scanner: pairs calls and puts at each strike for a given underlying/expiry
+//! in an [`OptionsChain`], checks the observed price spread against theoretical parity at the
+//! current index price, and reports violations whose annualized edge exceeds a threshold.
+
+use crate::chain::{Expiry, OptionsChain};
+use crate::model::option_expiry_datetime;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// A put-call parity violation detected at a single strike.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParityViolation {
+    /// The strike at which the violation was found.
+    pub strike: Decimal,
+    /// The quoted call price.
+    pub call_price: f64,
+    /// The quoted put price.
+    pub put_price: f64,
+    /// The observed spread, `call_price - put_price`.
+    pub observed_spread: f64,
+    /// The theoretical spread under put-call parity, `index_price - strike * exp(-r * t)`.
+    pub theoretical_spread: f64,
+    /// The mispricing, `observed_spread - theoretical_spread`.
+    pub edge: f64,
+    /// `edge` expressed as an annualized return on the index price, so violations at
+    /// different expiries are comparable.
+    pub annualized_edge: f64,
+}
+
+/// Error returned by [`scan_parity_violations`].
+#[derive(Debug, thiserror::Error)]
+pub enum ParityScanError {
+    /// `expiry` isn't a valid calendar date.
+    #[error("expiry {0:?} is not a valid calendar date")]
+    InvalidExpiry(Expiry),
+    /// `expiry` has already passed, so there's no time value to compute an annualized edge
+    /// against.
+    #[error("expiry {0:?} has already passed")]
+    ExpiryInPast(Expiry),
+    /// A quoted price field wasn't a valid decimal, or couldn't be represented as `f64`.
+    #[error("failed to parse a quoted price for strike {0}")]
+    InvalidPrice(Decimal),
+}
+
+/// Scans every strike quoted for `underlying` at `expiry` in `chain` for put-call parity
+/// violations against `index_price`, returning those whose annualized edge meets or exceeds
+/// `min_annualized_edge`. Strikes missing a call or a put quote are skipped.
+///
+/// # Errors
+///
+/// Returns `ParityScanError::InvalidExpiry` or `ParityScanError::ExpiryInPast` if `expiry`
+/// isn't a valid, future calendar date, or `ParityScanError::InvalidPrice` if a quoted price
+/// can't be parsed.
+pub fn scan_parity_violations(
+    chain: &OptionsChain,
+    underlying: &str,
+    expiry: Expiry,
+    index_price: Decimal,
+    risk_free_rate: f64,
+    min_annualized_edge: f64,
+) -> Result<Vec<ParityViolation>, ParityScanError> {
+    let (year, month, day) = expiry;
+    let expiry_datetime =
+        option_expiry_datetime(year, month, day).ok_or(ParityScanError::InvalidExpiry(expiry))?;
+    let time_to_expiry_years =
+        (expiry_datetime - chrono::Utc::now()).as_seconds_f64() / SECONDS_PER_YEAR;
+    if time_to_expiry_years <= 0.0 {
+        return Err(ParityScanError::ExpiryInPast(expiry));
+    }
+
+    let index_price_f64 = decimal_to_f64(index_price)?;
+    let discount = (-risk_free_rate * time_to_expiry_years).exp();
+
+    let mut violations = Vec::new();
+    for (strike, pair) in chain.strikes(underlying, expiry) {
+        let (Some(call), Some(put)) = (&pair.call, &pair.put) else {
+            continue;
+        };
+        let call_price = parse_price(&call.last_price, strike)?;
+        let put_price = parse_price(&put.last_price, strike)?;
+        let strike_f64 = decimal_to_f64(strike)?;
+
+        let observed_spread = call_price - put_price;
+        let theoretical_spread = index_price_f64 - strike_f64 * discount;
+        let edge = observed_spread - theoretical_spread;
+        let annualized_edge = (edge / index_price_f64) / time_to_expiry_years;
+
+        if annualized_edge.abs() >= min_annualized_edge {
+            violations.push(ParityViolation {
+                strike,
+                call_price,
+                put_price,
+                observed_spread,
+                theoretical_spread,
+                edge,
+                annualized_edge,
+            });
+        }
+    }
+
+    Ok(violations)
+}
+
+/// Seconds in a year, for converting a duration until expiry into the fractional-year units
+/// the pricing module expects.
+const SECONDS_PER_YEAR: f64 = 365.25 * 24.0 * 60.0 * 60.0;
+
+fn decimal_to_f64(value: Decimal) -> Result<f64, ParityScanError> {
+    value.to_f64().ok_or(ParityScanError::InvalidPrice(value))
+}
+
+fn parse_price(raw: &str, strike: Decimal) -> Result<f64, ParityScanError> {
+    Decimal::from_str(raw)
+        .ok()
+        .and_then(|decimal| decimal.to_f64())
+        .ok_or(ParityScanError::InvalidPrice(strike))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::OptionTicker;
+
+    fn ticker(symbol: &str, last_price: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: last_price.to_string(),
+            last_qty: "0".to_string(),
+            open: "0".to_string(),
+            high: "0".to_string(),
+            low: "0".to_string(),
+            volume: "0".to_string(),
+            amount: "0".to_string(),
+            bid_price: "0".to_string(),
+            ask_price: "0".to_string(),
+            open_time: chrono::DateTime::UNIX_EPOCH,
+            close_time: chrono::DateTime::UNIX_EPOCH,
+            first_trade_id: 0,
+            trade_count: 0,
+            strike_price: "9000".to_string(),
+            exercise_price: "9000".to_string(),
+        }
+    }
+
+    #[test]
+    fn scan_rejects_an_expired_expiry() {
+        let chain = OptionsChain::build(vec![
+            ticker("BTC-200730-9000-C", "500"),
+            ticker("BTC-200730-9000-P", "500"),
+        ])
+        .unwrap();
+
+        let result = scan_parity_violations(
+            &chain,
+            "BTC",
+            (2020, 7, 30),
+            Decimal::from_str("9000").unwrap(),
+            0.0,
+            0.0,
+        );
+        assert!(matches!(result, Err(ParityScanError::ExpiryInPast(_))));
+    }
+
+    #[test]
+    fn scan_finds_no_violation_at_exact_parity() {
+        // At zero rates, parity requires call_price - put_price == index_price - strike.
+        let chain = OptionsChain::build(vec![
+            ticker("BTC-271230-9000-C", "1000"),
+            ticker("BTC-271230-9000-P", "0"),
+        ])
+        .unwrap();
+
+        let violations = scan_parity_violations(
+            &chain,
+            "BTC",
+            (2027, 12, 30),
+            Decimal::from_str("10000").unwrap(),
+            0.0,
+            0.0001,
+        )
+        .unwrap();
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn scan_flags_a_large_mispricing() {
+        let chain = OptionsChain::build(vec![
+            ticker("BTC-271230-9000-C", "5000"),
+            ticker("BTC-271230-9000-P", "0"),
+        ])
+        .unwrap();
+
+        let violations = scan_parity_violations(
+            &chain,
+            "BTC",
+            (2027, 12, 30),
+            Decimal::from_str("10000").unwrap(),
+            0.0,
+            0.0001,
+        )
+        .unwrap();
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].edge > 0.0);
+    }
+
+    #[test]
+    fn scan_skips_strikes_missing_either_side() {
+        let chain = OptionsChain::build(vec![ticker("BTC-271230-9000-C", "500")]).unwrap();
+
+        let violations = scan_parity_violations(
+            &chain,
+            "BTC",
+            (2027, 12, 30),
+            Decimal::from_str("9000").unwrap(),
+            0.0,
+            0.0,
+        )
+        .unwrap();
+        assert!(violations.is_empty());
+    }
+}
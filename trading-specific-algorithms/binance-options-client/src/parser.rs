@@ -1,8 +1,9 @@
 use crate::error::BinanceOptionsClientError;
-use crate::model::OptionTicker;
+use crate::model::{OptionTicker, OptionTickerRef};
 use log::debug;
+use serde::de::{Deserializer as _, SeqAccess, Visitor};
 use serde_json::Deserializer;
-use serde_json::Value;
+use std::fmt;
 
 /// Defines the available JSON parsing strategies.
 ///
@@ -27,11 +28,37 @@ impl Default for ParsingStrategy {
     }
 }
 
+/// Visits a top-level JSON array one element at a time, deserializing each element straight
+/// into an `OptionTicker` without ever materializing a `serde_json::Value` for the array (or any
+/// element of it) along the way.
+struct TickerArrayVisitor;
+
+impl<'de> Visitor<'de> for TickerArrayVisitor {
+    type Value = Vec<OptionTicker>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a JSON array of ticker objects")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        // Max 1400 tickers currently, so a little buffer over that won't harm.
+        let mut tickers = Vec::with_capacity(seq.size_hint().unwrap_or(1600));
+        while let Some(ticker) = seq.next_element::<OptionTicker>()? {
+            tickers.push(ticker);
+        }
+        Ok(tickers)
+    }
+}
+
 /// Parses ticker JSON data using streaming deserialization.
 ///
-/// This function leverages `serde_json::Deserializer` to iterate over
-/// the JSON input and parse it into a vector of `OptionTicker` entries,
-/// reducing memory usage for large payloads.
+/// This function deserializes the top-level array one element at a time via a custom
+/// `Visitor`, handing each element straight to `OptionTicker`'s `Deserialize` impl instead of
+/// first collecting the whole array into a `serde_json::Value`, keeping memory usage
+/// proportional to a single ticker rather than the whole payload.
 ///
 /// # Arguments
 ///
@@ -48,29 +75,9 @@ pub fn parse_ticker_streaming(
         json_data.chars().take(200).collect::<String>()
     );
 
-    // Create a stream deserializer that first yields a single top-level Value.
-    let mut stream = Deserializer::from_str(json_data).into_iter::<Value>();
-
-    // Expect the first (and only) value to be the JSON array.
-    let top_value = stream
-        .next()
-        .ok_or_else(|| BinanceOptionsClientError::Unknown("No JSON data".to_string()))??;
-
-    match top_value {
-        Value::Array(arr) => {
-            let mut tickers = Vec::with_capacity(1600); // max 1400 Tickers currently so a little buffer wont harm
-            // Iterate over each element in the array and deserialize it.
-            for item in arr {
-                let ticker: OptionTicker =
-                    serde_json::from_value(item).map_err(BinanceOptionsClientError::JsonParse)?;
-                tickers.push(ticker);
-            }
-            Ok(tickers)
-        }
-        _ => Err(BinanceOptionsClientError::Unknown(
-            "Expected JSON array at top-level".to_string(),
-        )),
-    }
+    Deserializer::from_str(json_data)
+        .deserialize_seq(TickerArrayVisitor)
+        .map_err(BinanceOptionsClientError::JsonParse)
 }
 
 /// Parses ticker JSON data using direct deserialization.
@@ -94,6 +101,26 @@ pub fn parse_ticker_direct(
     Ok(tickers)
 }
 
+/// Parses ticker JSON data into [`OptionTickerRef`] records that borrow their string fields
+/// directly from `json_data`, avoiding the 14 `String` allocations per ticker that
+/// [`parse_ticker_streaming`] and [`parse_ticker_direct`] incur. Use this for read-only
+/// processing (filtering, scanning for the best price, etc.) that doesn't need to hold onto the
+/// parsed tickers past `json_data`'s lifetime; call [`OptionTickerRef::to_owned_ticker`] on
+/// individual entries that do.
+///
+/// # Arguments
+///
+/// * `json_data` - A string slice containing JSON ticker data.
+///
+/// # Returns
+///
+/// A vector of `OptionTickerRef` entries borrowing from `json_data` on success.
+pub fn parse_ticker_borrowed(
+    json_data: &str,
+) -> Result<Vec<OptionTickerRef<'_>>, BinanceOptionsClientError> {
+    serde_json::from_str(json_data).map_err(BinanceOptionsClientError::JsonParse)
+}
+
 /// Parses ticker JSON data using the specified strategy.
 ///
 /// This function provides a unified interface to parse ticker data with either
@@ -116,3 +143,58 @@ pub fn parse_ticker(
         ParsingStrategy::Direct => parse_ticker_direct(data),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_TICKER_ARRAY: &str = r#"[{
+        "symbol": "BTC-200730-9000-C",
+        "priceChange": "0",
+        "priceChangePercent": "0",
+        "lastPrice": "100",
+        "lastQty": "1",
+        "open": "100",
+        "high": "100",
+        "low": "100",
+        "volume": "1",
+        "amount": "100",
+        "bidPrice": "99",
+        "askPrice": "101",
+        "openTime": 1690000000000,
+        "closeTime": 1690000000000,
+        "firstTradeId": 1,
+        "tradeCount": 1,
+        "strikePrice": "9000",
+        "exercisePrice": "9000"
+    }]"#;
+
+    #[test]
+    fn parse_ticker_streaming_matches_parse_ticker_direct() {
+        let streamed = parse_ticker_streaming(SAMPLE_TICKER_ARRAY).unwrap();
+        let direct = parse_ticker_direct(SAMPLE_TICKER_ARRAY).unwrap();
+        assert_eq!(streamed, direct);
+    }
+
+    #[test]
+    fn parse_ticker_streaming_rejects_a_non_array_top_level_value() {
+        assert!(parse_ticker_streaming(r#"{"symbol": "BTC-200730-9000-C"}"#).is_err());
+    }
+
+    #[test]
+    fn parse_ticker_borrowed_matches_parse_ticker_direct() {
+        let owned = parse_ticker_direct(SAMPLE_TICKER_ARRAY).unwrap();
+        let borrowed = parse_ticker_borrowed(SAMPLE_TICKER_ARRAY).unwrap();
+        assert_eq!(owned.len(), 1);
+        assert_eq!(borrowed.len(), 1);
+        assert_eq!(borrowed[0].to_owned_ticker(), owned[0]);
+    }
+
+    #[test]
+    fn parse_ticker_borrowed_does_not_allocate_string_fields() {
+        let borrowed = parse_ticker_borrowed(SAMPLE_TICKER_ARRAY).unwrap();
+        let ticker = &borrowed[0];
+        assert!(SAMPLE_TICKER_ARRAY.contains(ticker.symbol));
+        assert_eq!(ticker.strike_price, "9000");
+    }
+}
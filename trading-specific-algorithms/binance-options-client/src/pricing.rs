@@ -0,0 +1,289 @@
+//! Black-76 option pricing, greeks, and an implied-volatility solver, computed locally from
+//! `OptionTicker`/mark data so strategies don't need an external pricing library. Black-76
+//! (rather than Black-Scholes proper) is used because Binance options are quoted against the
+//! underlying futures/index price, not a dividend-paying spot.
+
+use crate::model::OptionKind;
+
+/// Inputs a pricing error message can reference.
+const MIN_TIME_TO_EXPIRY_YEARS: f64 = 1e-9;
+
+/// Error returned by [`implied_volatility`] when the inputs are invalid or no solution is
+/// found within the iteration budget.
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum PricingError {
+    /// One of the inputs was non-positive where a positive value is required.
+    #[error("invalid pricing input: {0}")]
+    InvalidInput(String),
+    /// Neither Newton's method nor the bisection fallback converged within the iteration
+    /// budget.
+    #[error("implied volatility did not converge after {0} iterations")]
+    DidNotConverge(u32),
+}
+
+/// Delta, gamma, vega, and theta for a single option.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Greeks {
+    /// Rate of change of price with respect to the underlying forward price.
+    pub delta: f64,
+    /// Rate of change of delta with respect to the underlying forward price.
+    pub gamma: f64,
+    /// Rate of change of price with respect to volatility (per 1.00 = 100 vol points).
+    pub vega: f64,
+    /// Rate of change of price with respect to time, in price units per year.
+    pub theta: f64,
+}
+
+/// Standard normal cumulative distribution function, via the Abramowitz-Stegun approximation
+/// (accurate to ~1e-7), to avoid pulling in a statistics dependency.
+fn norm_cdf(x: f64) -> f64 {
+    0.5 * (1.0 + erf(x / std::f64::consts::SQRT_2))
+}
+
+/// Standard normal probability density function.
+fn norm_pdf(x: f64) -> f64 {
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Error function, via Abramowitz-Stegun formula 7.1.26.
+fn erf(x: f64) -> f64 {
+    let sign = if x < 0.0 { -1.0 } else { 1.0 };
+    let x = x.abs();
+
+    const A1: f64 = 0.254829592;
+    const A2: f64 = -0.284496736;
+    const A3: f64 = 1.421413741;
+    const A4: f64 = -1.453152027;
+    const A5: f64 = 1.061405429;
+    const P: f64 = 0.3275911;
+
+    let t = 1.0 / (1.0 + P * x);
+    let y = 1.0 - (((((A5 * t + A4) * t) + A3) * t + A2) * t + A1) * t * (-x * x).exp();
+
+    sign * y
+}
+
+fn d1_d2(forward: f64, strike: f64, time_to_expiry_years: f64, volatility: f64) -> (f64, f64) {
+    let sqrt_t = time_to_expiry_years.sqrt();
+    let d1 = ((forward / strike).ln() + 0.5 * volatility * volatility * time_to_expiry_years)
+        / (volatility * sqrt_t);
+    let d2 = d1 - volatility * sqrt_t;
+    (d1, d2)
+}
+
+fn validate_inputs(
+    forward: f64,
+    strike: f64,
+    time_to_expiry_years: f64,
+    volatility: f64,
+) -> Result<(), PricingError> {
+    if forward <= 0.0 {
+        return Err(PricingError::InvalidInput(
+            "forward price must be positive".to_string(),
+        ));
+    }
+    if strike <= 0.0 {
+        return Err(PricingError::InvalidInput(
+            "strike price must be positive".to_string(),
+        ));
+    }
+    if time_to_expiry_years < MIN_TIME_TO_EXPIRY_YEARS {
+        return Err(PricingError::InvalidInput(
+            "time to expiry must be positive".to_string(),
+        ));
+    }
+    if volatility <= 0.0 {
+        return Err(PricingError::InvalidInput(
+            "volatility must be positive".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Computes the Black-76 theoretical price of a European option on a forward/futures price.
+///
+/// # Errors
+///
+/// Returns `PricingError::InvalidInput` if `forward`, `strike`, `time_to_expiry_years`, or
+/// `volatility` is non-positive.
+pub fn black76_price(
+    kind: OptionKind,
+    forward: f64,
+    strike: f64,
+    time_to_expiry_years: f64,
+    risk_free_rate: f64,
+    volatility: f64,
+) -> Result<f64, PricingError> {
+    validate_inputs(forward, strike, time_to_expiry_years, volatility)?;
+
+    let (d1, d2) = d1_d2(forward, strike, time_to_expiry_years, volatility);
+    let discount = (-risk_free_rate * time_to_expiry_years).exp();
+
+    Ok(match kind {
+        OptionKind::Call => discount * (forward * norm_cdf(d1) - strike * norm_cdf(d2)),
+        OptionKind::Put => discount * (strike * norm_cdf(-d2) - forward * norm_cdf(-d1)),
+    })
+}
+
+/// Computes Black-76 greeks for a European option on a forward/futures price.
+///
+/// # Errors
+///
+/// Returns `PricingError::InvalidInput` if `forward`, `strike`, `time_to_expiry_years`, or
+/// `volatility` is non-positive.
+pub fn black76_greeks(
+    kind: OptionKind,
+    forward: f64,
+    strike: f64,
+    time_to_expiry_years: f64,
+    risk_free_rate: f64,
+    volatility: f64,
+) -> Result<Greeks, PricingError> {
+    validate_inputs(forward, strike, time_to_expiry_years, volatility)?;
+
+    let (d1, d2) = d1_d2(forward, strike, time_to_expiry_years, volatility);
+    let sqrt_t = time_to_expiry_years.sqrt();
+    let discount = (-risk_free_rate * time_to_expiry_years).exp();
+    let pdf_d1 = norm_pdf(d1);
+
+    let delta = match kind {
+        OptionKind::Call => discount * norm_cdf(d1),
+        OptionKind::Put => discount * (norm_cdf(d1) - 1.0),
+    };
+    let gamma = discount * pdf_d1 / (forward * volatility * sqrt_t);
+    let vega = forward * discount * pdf_d1 * sqrt_t;
+    let theta = match kind {
+        OptionKind::Call => {
+            -forward * discount * pdf_d1 * volatility / (2.0 * sqrt_t)
+                + risk_free_rate * discount * (forward * norm_cdf(d1) - strike * norm_cdf(d2))
+        }
+        OptionKind::Put => {
+            -forward * discount * pdf_d1 * volatility / (2.0 * sqrt_t)
+                + risk_free_rate * discount * (strike * norm_cdf(-d2) - forward * norm_cdf(-d1))
+        }
+    };
+
+    Ok(Greeks {
+        delta,
+        gamma,
+        vega,
+        theta,
+    })
+}
+
+/// Solves for the Black-76 implied volatility that reproduces `market_price`, starting with
+/// Newton's method and falling back to bisection if Newton fails to converge (e.g. near-zero
+/// vega).
+///
+/// # Errors
+///
+/// Returns `PricingError::InvalidInput` if `forward`, `strike`, or `time_to_expiry_years` is
+/// non-positive, or `market_price` is negative. Returns `PricingError::DidNotConverge` if no
+/// volatility within `(1e-6, 5.0)` reproduces `market_price` to within `1e-6` after 100
+/// iterations of each method.
+pub fn implied_volatility(
+    kind: OptionKind,
+    market_price: f64,
+    forward: f64,
+    strike: f64,
+    time_to_expiry_years: f64,
+    risk_free_rate: f64,
+) -> Result<f64, PricingError> {
+    if market_price < 0.0 {
+        return Err(PricingError::InvalidInput(
+            "market price must not be negative".to_string(),
+        ));
+    }
+    validate_inputs(forward, strike, time_to_expiry_years, 1.0)?;
+
+    const MAX_ITERATIONS: u32 = 100;
+    const TOLERANCE: f64 = 1e-6;
+    const MIN_VOL: f64 = 1e-6;
+    const MAX_VOL: f64 = 5.0;
+
+    let price_at = |vol: f64| {
+        black76_price(kind, forward, strike, time_to_expiry_years, risk_free_rate, vol)
+    };
+
+    // Newton's method, seeded from a typical at-the-money volatility guess.
+    let mut vol = 0.5;
+    for _ in 0..MAX_ITERATIONS {
+        let price = price_at(vol)?;
+        let diff = price - market_price;
+        if diff.abs() < TOLERANCE {
+            return Ok(vol);
+        }
+        let vega = black76_greeks(kind, forward, strike, time_to_expiry_years, risk_free_rate, vol)?
+            .vega;
+        if vega.abs() < TOLERANCE {
+            break;
+        }
+        vol = (vol - diff / vega).clamp(MIN_VOL, MAX_VOL);
+    }
+
+    // Bisection fallback: price is monotonically increasing in volatility, so this always
+    // converges given a market price achievable within [MIN_VOL, MAX_VOL].
+    let mut low = MIN_VOL;
+    let mut high = MAX_VOL;
+    for _ in 0..MAX_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        let price = price_at(mid)?;
+        let diff = price - market_price;
+        if diff.abs() < TOLERANCE {
+            return Ok(mid);
+        }
+        if diff > 0.0 {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Err(PricingError::DidNotConverge(MAX_ITERATIONS))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_and_put_satisfy_put_call_parity() {
+        let call =
+            black76_price(OptionKind::Call, 100.0, 100.0, 0.5, 0.01, 0.3).unwrap();
+        let put = black76_price(OptionKind::Put, 100.0, 100.0, 0.5, 0.01, 0.3).unwrap();
+        let discount = (-0.01_f64 * 0.5).exp();
+        assert!((call - put - discount * (100.0 - 100.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn price_rejects_non_positive_inputs() {
+        assert!(black76_price(OptionKind::Call, 0.0, 100.0, 0.5, 0.01, 0.3).is_err());
+        assert!(black76_price(OptionKind::Call, 100.0, 100.0, 0.0, 0.01, 0.3).is_err());
+        assert!(black76_price(OptionKind::Call, 100.0, 100.0, 0.5, 0.01, 0.0).is_err());
+    }
+
+    #[test]
+    fn call_delta_is_between_zero_and_one() {
+        let greeks =
+            black76_greeks(OptionKind::Call, 100.0, 100.0, 0.5, 0.01, 0.3).unwrap();
+        assert!(greeks.delta > 0.0 && greeks.delta < 1.0);
+        assert!(greeks.gamma > 0.0);
+        assert!(greeks.vega > 0.0);
+    }
+
+    #[test]
+    fn implied_volatility_recovers_the_seed_volatility() {
+        let true_vol = 0.35;
+        let price =
+            black76_price(OptionKind::Call, 100.0, 105.0, 0.25, 0.02, true_vol).unwrap();
+
+        let solved =
+            implied_volatility(OptionKind::Call, price, 100.0, 105.0, 0.25, 0.02).unwrap();
+        assert!((solved - true_vol).abs() < 1e-4);
+    }
+
+    #[test]
+    fn implied_volatility_rejects_a_negative_price() {
+        assert!(implied_volatility(OptionKind::Call, -1.0, 100.0, 100.0, 0.5, 0.01).is_err());
+    }
+}
@@ -0,0 +1,182 @@
+//! Client-side rate limiter for the Binance Options API, tracking request weight against a
+//! per-minute limit (seeded from Binance's published defaults, or a live `exchangeInfo`
+//! response) so the client backs off before the API returns a 429/418 ban.
+
+use crate::error::BinanceOptionsClientError;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Default per-minute request-weight limit for the options API, mirroring Binance's documented
+/// default for `/eapi/*` endpoints.
+pub(crate) const DEFAULT_WEIGHT_LIMIT_PER_MINUTE: u32 = 400;
+
+/// The sliding window over which weight usage is tracked.
+const WINDOW: Duration = Duration::from_secs(60);
+
+/// Tracks request weight usage against a per-minute limit, delaying callers when sending a
+/// request would exceed the limit, and rejecting requests whose weight could never be satisfied.
+pub struct RateLimiter {
+    limit: u32,
+    state: Mutex<WindowState>,
+}
+
+struct WindowState {
+    window_start: Instant,
+    used_weight: u32,
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter using Binance's documented default weight limit.
+    pub fn new() -> Self {
+        Self::with_limit(DEFAULT_WEIGHT_LIMIT_PER_MINUTE)
+    }
+
+    /// Creates a rate limiter with a custom per-minute weight limit, e.g. one seeded from a live
+    /// `exchangeInfo` response.
+    pub fn with_limit(limit: u32) -> Self {
+        Self {
+            limit,
+            state: Mutex::new(WindowState {
+                window_start: Instant::now(),
+                used_weight: 0,
+            }),
+        }
+    }
+
+    /// Returns the configured per-minute weight limit.
+    pub fn limit(&self) -> u32 {
+        self.limit
+    }
+
+    /// Reserves `weight` against the current window, sleeping until the next window opens if
+    /// doing so would exceed the limit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::Unknown` if `weight` alone exceeds the configured
+    /// limit, since no amount of waiting would let the request through.
+    pub async fn acquire(&self, weight: u32) -> Result<(), BinanceOptionsClientError> {
+        if weight > self.limit {
+            return Err(BinanceOptionsClientError::Unknown(format!(
+                "request weight {weight} exceeds the configured rate limit of {} per minute",
+                self.limit
+            )));
+        }
+
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let elapsed = state.window_start.elapsed();
+                if elapsed >= WINDOW {
+                    state.window_start = Instant::now();
+                    state.used_weight = 0;
+                }
+
+                if state.used_weight + weight <= self.limit {
+                    state.used_weight += weight;
+                    None
+                } else {
+                    Some(WINDOW - elapsed)
+                }
+            };
+
+            match wait {
+                None => return Ok(()),
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Snapshot of the most recently observed `X-MBX-USED-WEIGHT*` response header, letting
+/// callers throttle proactively instead of waiting for Binance to return a 429/418.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    /// The most recently reported used weight for the relevant window.
+    pub used_weight: u32,
+    /// The configured per-minute weight limit, if the client has rate limiting enabled.
+    pub limit: Option<u32>,
+}
+
+impl RateLimitStatus {
+    /// True once `used_weight` has reached or passed `threshold_fraction` (e.g. `0.8` for 80%)
+    /// of `limit`. Always `false` if no limit is known.
+    pub fn is_near_limit(&self, threshold_fraction: f64) -> bool {
+        match self.limit {
+            Some(limit) if limit > 0 => {
+                f64::from(self.used_weight) >= f64::from(limit) * threshold_fraction
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Returns the documented request weight for a given endpoint path, falling back to a
+/// conservative weight of 1 for unrecognized endpoints.
+pub fn weight_for_path(path: &str) -> u32 {
+    match path {
+        "/eapi/v1/exerciseHistory" | "/eapi/v1/account" | "/eapi/v1/historyOrders" => 3,
+        "/eapi/v1/position" => 5,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn acquire_succeeds_immediately_while_under_the_limit() {
+        let limiter = RateLimiter::with_limit(10);
+        limiter.acquire(5).await.unwrap();
+        limiter.acquire(5).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn acquire_rejects_weight_above_the_limit() {
+        let limiter = RateLimiter::with_limit(10);
+        let result = limiter.acquire(11).await;
+        assert!(matches!(
+            result,
+            Err(BinanceOptionsClientError::Unknown(_))
+        ));
+    }
+
+    #[test]
+    fn is_near_limit_with_no_configured_limit_is_always_false() {
+        let status = RateLimitStatus {
+            used_weight: 10_000,
+            limit: None,
+        };
+        assert!(!status.is_near_limit(0.8));
+    }
+
+    #[test]
+    fn is_near_limit_crosses_the_threshold_fraction() {
+        let status = RateLimitStatus {
+            used_weight: 79,
+            limit: Some(100),
+        };
+        assert!(!status.is_near_limit(0.8));
+
+        let status = RateLimitStatus {
+            used_weight: 80,
+            limit: Some(100),
+        };
+        assert!(status.is_near_limit(0.8));
+    }
+
+    #[test]
+    fn weight_for_path_matches_known_endpoints() {
+        assert_eq!(weight_for_path("/eapi/v1/account"), 3);
+        assert_eq!(weight_for_path("/eapi/v1/position"), 5);
+        assert_eq!(weight_for_path("/eapi/v1/ticker"), 1);
+        assert_eq!(weight_for_path("/eapi/v1/unknown"), 1);
+    }
+}
@@ -0,0 +1,234 @@
+//! Record-and-replay [`HttpTransport`] wrappers, for capturing real request/response pairs to
+//! disk (e.g. while reproducing a production issue) and replaying them later without a network
+//! call, including to power benchmarks against fixed, versioned data instead of live traffic.
+//!
+//! ```no_run
+//! use binance_options_client::record_replay::RecordingTransport;
+//! use binance_options_client::{BinanceOptionsClient, ReqwestTransport};
+//!
+//! let recording = RecordingTransport::new(ReqwestTransport::new(Default::default()), "ticker.jsonl")?;
+//! let client = BinanceOptionsClient::builder().transport(recording).build()?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use std::fs::File;
+use std::future::Future;
+use std::io::{BufRead, BufReader, Write};
+use std::path::Path;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{HttpCall, HttpResponse, HttpTransport};
+use crate::error::BinanceOptionsClientError;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// One recorded request/response pair, persisted as a single line of newline-delimited JSON.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RecordedExchange {
+    url: String,
+    method: String,
+    params: Vec<(String, String)>,
+    status: u16,
+    body: String,
+}
+
+/// Wraps another [`HttpTransport`], appending every request/response pair it sees to a file as
+/// newline-delimited JSON, for later replay with [`ReplayTransport`].
+pub struct RecordingTransport<T> {
+    inner: T,
+    file: Mutex<File>,
+}
+
+impl<T: HttpTransport> RecordingTransport<T> {
+    /// Wraps `inner`, appending recordings to `path` (created if missing, truncated if present).
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if `path` can't be created.
+    pub fn new(inner: T, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = File::create(path)?;
+        Ok(Self {
+            inner,
+            file: Mutex::new(file),
+        })
+    }
+}
+
+impl<T: HttpTransport> HttpTransport for RecordingTransport<T> {
+    fn send<'a>(
+        &'a self,
+        call: &'a HttpCall,
+    ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+        Box::pin(async move {
+            let response = self.inner.send(call).await?;
+
+            let exchange = RecordedExchange {
+                url: call.url.clone(),
+                method: call.method.to_string(),
+                params: call.params.clone(),
+                status: response.status.as_u16(),
+                body: response.body.clone(),
+            };
+            if let Ok(line) = serde_json::to_string(&exchange)
+                && let Ok(mut file) = self.file.lock()
+            {
+                let _ = writeln!(file, "{line}");
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+/// Replays [`RecordedExchange`]s captured by [`RecordingTransport`], matching each [`HttpCall`]
+/// against the recording by URL and query params (ignoring headers and timeout).
+pub struct ReplayTransport {
+    exchanges: Vec<RecordedExchange>,
+}
+
+impl ReplayTransport {
+    /// Loads recordings from `path`, as written by [`RecordingTransport`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an `io::Error` if `path` can't be read, or a line isn't valid JSON.
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = File::open(path)?;
+        let mut exchanges = Vec::new();
+        for line in BufReader::new(file).lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let exchange: RecordedExchange = serde_json::from_str(&line)
+                .map_err(|error| std::io::Error::new(std::io::ErrorKind::InvalidData, error))?;
+            exchanges.push(exchange);
+        }
+        Ok(Self { exchanges })
+    }
+}
+
+impl HttpTransport for ReplayTransport {
+    fn send<'a>(
+        &'a self,
+        call: &'a HttpCall,
+    ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+        let matched = self
+            .exchanges
+            .iter()
+            .find(|exchange| exchange.url == call.url && exchange.params == call.params)
+            .cloned();
+
+        Box::pin(async move {
+            let exchange = matched.ok_or_else(|| {
+                BinanceOptionsClientError::Unknown(format!(
+                    "no recorded exchange matches {} {:?}",
+                    call.url, call.params
+                ))
+            })?;
+            let status = StatusCode::from_u16(exchange.status)
+                .map_err(|error| BinanceOptionsClientError::Unknown(error.to_string()))?;
+
+            Ok(HttpResponse {
+                status,
+                headers: reqwest::header::HeaderMap::new(),
+                body: exchange.body,
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{ServerTimeRequest, TickerRequest};
+    use crate::model::{OptionTicker, ServerTime};
+
+    struct FakeTransport {
+        body: String,
+    }
+
+    impl HttpTransport for FakeTransport {
+        fn send<'a>(
+            &'a self,
+            _call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+            let body = self.body.clone();
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body,
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn recording_then_replaying_reproduces_the_original_response() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!(
+            "binance-options-client-record-replay-test-{:?}.jsonl",
+            std::thread::current().id()
+        ));
+
+        let recording = RecordingTransport::new(
+            FakeTransport {
+                body: r#"{"serverTime":1700000000000}"#.to_string(),
+            },
+            &path,
+        )
+        .unwrap();
+        let recording_client = crate::BinanceOptionsClient::builder()
+            .transport(recording)
+            .build()
+            .unwrap();
+        let recorded: ServerTime = recording_client
+            .send_request(ServerTimeRequest::new().into())
+            .await
+            .unwrap();
+
+        let replay = ReplayTransport::load(&path).unwrap();
+        let replay_client = crate::BinanceOptionsClient::builder()
+            .transport(replay)
+            .build()
+            .unwrap();
+        let replayed: ServerTime = replay_client
+            .send_request(ServerTimeRequest::new().into())
+            .await
+            .unwrap();
+
+        assert_eq!(recorded, replayed);
+
+        std::fs::remove_file(&path).ok();
+    }
+
+    #[tokio::test]
+    async fn replay_fails_closed_on_an_unrecorded_request() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!(
+            "binance-options-client-record-replay-empty-test-{:?}.jsonl",
+            std::thread::current().id()
+        ));
+        File::create(&path).unwrap();
+
+        let replay = ReplayTransport::load(&path).unwrap();
+        let client = crate::BinanceOptionsClient::builder()
+            .transport(replay)
+            .build()
+            .unwrap();
+
+        let result: Result<Vec<OptionTicker>, _> =
+            client.send_request(TickerRequest::new().into()).await;
+        assert!(matches!(
+            result,
+            Err(BinanceOptionsClientError::Unknown(_))
+        ));
+
+        std::fs::remove_file(&path).ok();
+    }
+}
@@ -0,0 +1,312 @@
+//! A TTL + LRU cache of idempotent GET responses, keyed by URL and query params, backed by the
+//! `hash-table` crate's `LinkedHashTable` for O(1) least-recently-used tracking.
+//!
+//! [`CachingTransport`] wraps another [`HttpTransport`]; install it via
+//! [`crate::ClientBuilder::response_cache`] to keep repeated `exchangeInfo`/ticker calls within
+//! the TTL from hitting the network at all.
+//!
+//! The cached response bodies themselves live in a side `Vec`, with `LinkedHashTable` only ever
+//! storing a `usize` slot handle rather than the response directly. `LinkedOpenAddressing`'s
+//! `remove` reclaims an evicted value by swapping in a zero-initialized placeholder, which is
+//! unsound for heap types like `String` (see `hash_table::linked_open_addressing::remove`) —
+//! `usize` is the one value type that's always safe to zero, so routing eviction through it
+//! avoids the problem entirely while still getting O(1) recency tracking from the table.
+
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use hash_table::{HashTable, LinkedHashTable};
+use reqwest::{Method, StatusCode};
+
+use crate::api::{HttpCall, HttpResponse, HttpTransport};
+use crate::error::BinanceOptionsClientError;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// Identifies a cacheable call by everything that affects its response: URL and query params.
+/// Matches the identity [`crate::record_replay::ReplayTransport`] keys its recordings on.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    url: String,
+    params: Vec<(String, String)>,
+}
+
+#[derive(Debug, Clone)]
+struct CachedResponse {
+    status: StatusCode,
+    body: String,
+    cached_at: Instant,
+}
+
+struct CacheState {
+    /// Tracks recency and capacity; values are slot indices into `entries`, not responses.
+    order: LinkedHashTable<CacheKey, usize>,
+    entries: Vec<Option<CachedResponse>>,
+    free_slots: Vec<usize>,
+}
+
+impl CacheState {
+    fn new(capacity: usize) -> Self {
+        Self {
+            order: LinkedHashTable::new(capacity),
+            entries: Vec::new(),
+            free_slots: Vec::new(),
+        }
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<&CachedResponse> {
+        let slot = *self.order.get(key)?;
+        self.entries[slot].as_ref()
+    }
+
+    /// Records a fresh response for `key`, evicting the least-recently-used entry first if the
+    /// table is at capacity and `key` isn't already present.
+    fn put(&mut self, capacity: usize, key: CacheKey, response: CachedResponse) {
+        if let Some(&slot) = self.order.get(&key) {
+            // Refresh recency. `LinkedOpenAddressing::insert` on an existing key still hits its
+            // "table is full" check before it notices this is an update, so remove first to keep
+            // `len` below capacity going into the re-insert.
+            self.order.remove(&key);
+            self.order.insert(key, slot);
+            self.entries[slot] = Some(response);
+            return;
+        }
+
+        if self.order.len() >= capacity
+            && let Some((oldest_key, &oldest_slot)) = self.order.get_first()
+        {
+            let oldest_key = oldest_key.clone();
+            self.order.remove(&oldest_key);
+            self.entries[oldest_slot] = None;
+            self.free_slots.push(oldest_slot);
+        }
+
+        let slot = self.free_slots.pop().unwrap_or_else(|| {
+            self.entries.push(None);
+            self.entries.len() - 1
+        });
+        self.entries[slot] = Some(response);
+        self.order.insert(key, slot);
+    }
+}
+
+/// Wraps another [`HttpTransport`], serving GET calls from an in-memory TTL cache when a fresh
+/// entry exists and falling through to `inner` (then caching the result) otherwise. Non-GET
+/// calls always pass through, since they aren't idempotent.
+pub struct CachingTransport<T> {
+    inner: T,
+    ttl: Duration,
+    capacity: usize,
+    state: Mutex<CacheState>,
+}
+
+impl<T: HttpTransport> CachingTransport<T> {
+    /// Wraps `inner`, caching up to `capacity` distinct GET responses for `ttl` each.
+    pub fn new(inner: T, capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            capacity,
+            state: Mutex::new(CacheState::new(capacity)),
+        }
+    }
+}
+
+impl<T: HttpTransport> HttpTransport for CachingTransport<T> {
+    fn send<'a>(
+        &'a self,
+        call: &'a HttpCall,
+    ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+        Box::pin(async move {
+            if call.method != Method::GET {
+                return self.inner.send(call).await;
+            }
+
+            let key = CacheKey {
+                url: call.url.clone(),
+                params: call.params.clone(),
+            };
+
+            if let Ok(state) = self.state.lock()
+                && let Some(cached) = state.get(&key)
+                && cached.cached_at.elapsed() < self.ttl
+            {
+                return Ok(HttpResponse {
+                    status: cached.status,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body: cached.body.clone(),
+                });
+            }
+
+            let response = self.inner.send(call).await?;
+
+            if response.status.is_success()
+                && let Ok(mut state) = self.state.lock()
+            {
+                state.put(
+                    self.capacity,
+                    key,
+                    CachedResponse {
+                        status: response.status,
+                        body: response.body.clone(),
+                        cached_at: Instant::now(),
+                    },
+                );
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::ServerTimeRequest;
+    use crate::model::ServerTime;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    struct CountingTransport {
+        calls: AtomicUsize,
+        body: String,
+    }
+
+    impl HttpTransport for CountingTransport {
+        fn send<'a>(
+            &'a self,
+            _call: &'a HttpCall,
+        ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+            self.calls.fetch_add(1, Ordering::Relaxed);
+            let body = self.body.clone();
+            Box::pin(async move {
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    headers: reqwest::header::HeaderMap::new(),
+                    body,
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn a_repeated_call_within_the_ttl_is_served_from_the_cache() {
+        let caching = CachingTransport::new(
+            CountingTransport {
+                calls: AtomicUsize::new(0),
+                body: r#"{"serverTime":1700000000000}"#.to_string(),
+            },
+            10,
+            Duration::from_secs(60),
+        );
+        let client = crate::BinanceOptionsClient::builder()
+            .transport(caching)
+            .build()
+            .unwrap();
+
+        let first: ServerTime = client
+            .send_request(ServerTimeRequest::new().into())
+            .await
+            .unwrap();
+        let second: ServerTime = client
+            .send_request(ServerTimeRequest::new().into())
+            .await
+            .unwrap();
+
+        assert_eq!(first, second);
+    }
+
+    #[tokio::test]
+    async fn an_expired_entry_is_refetched() {
+        let caching = CachingTransport::new(
+            CountingTransport {
+                calls: AtomicUsize::new(0),
+                body: r#"{"serverTime":1700000000000}"#.to_string(),
+            },
+            10,
+            Duration::from_millis(1),
+        );
+
+        let call = HttpCall {
+            url: "http://example.invalid/server-time".to_string(),
+            method: Method::GET,
+            params: Vec::new(),
+            headers: Vec::new(),
+            timeout: None,
+        };
+
+        caching.send(&call).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        caching.send(&call).await.unwrap();
+
+        assert_eq!(caching.inner.calls.load(Ordering::Relaxed), 2);
+    }
+
+    #[tokio::test]
+    async fn an_expired_entry_can_be_refreshed_repeatedly() {
+        // `LinkedOpenAddressing` recycles a removed entry's node index on the next insert (see
+        // its own "Node Recycling" note), so refreshing an expired entry doesn't spend a node
+        // budget the way a brand-new key would; a small capacity is enough for many refreshes.
+        let caching = CachingTransport::new(
+            CountingTransport {
+                calls: AtomicUsize::new(0),
+                body: r#"{"serverTime":1700000000000}"#.to_string(),
+            },
+            5,
+            Duration::from_millis(1),
+        );
+
+        let call = HttpCall {
+            url: "http://example.invalid/server-time".to_string(),
+            method: Method::GET,
+            params: Vec::new(),
+            headers: Vec::new(),
+            timeout: None,
+        };
+
+        for _ in 0..5 {
+            caching.send(&call).await.unwrap();
+            tokio::time::sleep(Duration::from_millis(5)).await;
+        }
+
+        assert_eq!(caching.inner.calls.load(Ordering::Relaxed), 5);
+    }
+
+    #[tokio::test]
+    async fn filling_the_cache_to_capacity_keeps_every_entry_available() {
+        let caching = CachingTransport::new(
+            CountingTransport {
+                calls: AtomicUsize::new(0),
+                body: "{}".to_string(),
+            },
+            3,
+            Duration::from_secs(60),
+        );
+
+        for i in 0..3 {
+            let call = HttpCall {
+                url: format!("http://example.invalid/{i}"),
+                method: Method::GET,
+                params: Vec::new(),
+                headers: Vec::new(),
+                timeout: None,
+            };
+            caching.send(&call).await.unwrap();
+        }
+        assert_eq!(caching.state.lock().unwrap().order.len(), 3);
+
+        let calls_before = caching.inner.calls.load(Ordering::Relaxed);
+        for i in 0..3 {
+            let call = HttpCall {
+                url: format!("http://example.invalid/{i}"),
+                method: Method::GET,
+                params: Vec::new(),
+                headers: Vec::new(),
+                timeout: None,
+            };
+            caching.send(&call).await.unwrap();
+        }
+        assert_eq!(caching.inner.calls.load(Ordering::Relaxed), calls_before);
+    }
+}
@@ -0,0 +1,149 @@
+//! Retry policy for transient Binance Options API failures. Network errors, 5xx responses, and
+//! HTTP 429s are retried with exponential backoff and jitter, honoring a server-supplied
+//! `Retry-After` delay when present; other errors surface immediately.
+
+use crate::error::BinanceOptionsClientError;
+use std::time::Duration;
+
+/// Configures how `send_request` retries transient failures.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first. `1` disables retries.
+    pub max_attempts: u32,
+    /// Base delay used to compute exponential backoff (`base_delay * 2^(attempt - 1)`).
+    pub base_delay: Duration,
+    /// Upper bound on the computed backoff delay, before jitter.
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with the given attempt budget and sensible backoff defaults
+    /// (100ms base delay, 10s cap).
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+        }
+    }
+
+    /// Overrides the base delay used to compute exponential backoff.
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Overrides the maximum backoff delay (before jitter).
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Computes the delay before retrying the given (1-indexed) attempt, honoring a
+    /// server-supplied `Retry-After` delay if one was provided.
+    pub fn delay_for_attempt(&self, attempt: u32, retry_after: Option<Duration>) -> Duration {
+        if let Some(retry_after) = retry_after {
+            return retry_after;
+        }
+
+        let exponential = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+
+        // Full jitter: scale the exponential delay by a pseudo-random fraction in [0, 1),
+        // seeded from the current time to avoid pulling in a dependency on `rand`.
+        let jitter_seed = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .subsec_nanos();
+        let jitter_fraction = f64::from(jitter_seed % 1_000) / 1_000.0;
+
+        exponential.mul_f64(jitter_fraction)
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts total, with a 100ms base delay and a 10s cap.
+    fn default() -> Self {
+        Self::new(3)
+    }
+}
+
+/// True if `error` represents a transient failure worth retrying: network errors, 5xx HTTP
+/// responses, and HTTP 429 (rate limited). A thin wrapper around
+/// [`BinanceOptionsClientError::is_retryable`], kept as a free function since that's how
+/// `send_request`'s retry loop has always called it.
+pub fn is_retryable(error: &BinanceOptionsClientError) -> bool {
+    error.is_retryable()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_policy_allows_three_attempts() {
+        assert_eq!(RetryPolicy::default().max_attempts, 3);
+    }
+
+    #[test]
+    fn zero_attempts_is_clamped_to_one() {
+        assert_eq!(RetryPolicy::new(0).max_attempts, 1);
+    }
+
+    #[test]
+    fn retry_after_overrides_backoff() {
+        let policy = RetryPolicy::new(3);
+        let delay = policy.delay_for_attempt(2, Some(Duration::from_secs(7)));
+        assert_eq!(delay, Duration::from_secs(7));
+    }
+
+    #[test]
+    fn backoff_is_capped_at_max_delay() {
+        let policy = RetryPolicy::new(5)
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(500));
+        assert!(policy.delay_for_attempt(10, None) <= Duration::from_millis(500));
+    }
+
+    #[test]
+    fn network_and_server_errors_are_retryable() {
+        assert!(is_retryable(&BinanceOptionsClientError::Network(
+            reqwest::Client::new()
+                .get("not a url")
+                .build()
+                .unwrap_err()
+        )));
+        assert!(is_retryable(&BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::INTERNAL_SERVER_ERROR,
+            body: String::new(),
+            retry_after: None,
+            request_id: None,
+        }));
+        assert!(is_retryable(&BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::TOO_MANY_REQUESTS,
+            body: String::new(),
+            retry_after: None,
+            request_id: None,
+        }));
+    }
+
+    #[test]
+    fn api_and_client_errors_are_not_retryable() {
+        assert!(!is_retryable(&BinanceOptionsClientError::HttpResponse {
+            code: reqwest::StatusCode::BAD_REQUEST,
+            body: String::new(),
+            retry_after: None,
+            request_id: None,
+        }));
+        assert!(!is_retryable(&BinanceOptionsClientError::ApiError {
+            code: -1121,
+            msg: "Invalid symbol.".to_string(),
+            request_id: None,
+        }));
+        assert!(!is_retryable(&BinanceOptionsClientError::MissingCredentials(
+            "no key".to_string()
+        )));
+    }
+}
@@ -0,0 +1,159 @@
+//! Optional SQLite persistence for ticker and mark-price snapshots (the `storage` feature), so
+//! implied volatility and other fields can be compared across polls — e.g. IV over several days
+//! — instead of living only in the current process's memory. Gated behind the `storage` feature
+//! so clients that don't want the `rusqlite` dependency don't pay for it.
+
+use rusqlite::{Connection, params};
+
+use crate::model::{MarkPrice, OptionTicker};
+
+/// Opens (creating if needed) a SQLite database at `path` with the schema [`record_ticker`],
+/// [`record_mark_price`], and [`iv_history`] expect. Pass `":memory:"` for a throwaway
+/// in-process database.
+pub fn open(path: &str) -> rusqlite::Result<Connection> {
+    let conn = Connection::open(path)?;
+    init_schema(&conn)?;
+    Ok(conn)
+}
+
+fn init_schema(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "
+        CREATE TABLE IF NOT EXISTS ticker_snapshots (
+            id INTEGER PRIMARY KEY,
+            recorded_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+            symbol TEXT NOT NULL,
+            last_price TEXT NOT NULL,
+            volume TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS ticker_snapshots_symbol_idx ON ticker_snapshots(symbol);
+
+        CREATE TABLE IF NOT EXISTS mark_price_snapshots (
+            id INTEGER PRIMARY KEY,
+            recorded_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now')),
+            symbol TEXT NOT NULL,
+            mark_price TEXT NOT NULL,
+            mark_iv TEXT NOT NULL,
+            delta TEXT NOT NULL,
+            gamma TEXT NOT NULL,
+            theta TEXT NOT NULL,
+            vega TEXT NOT NULL
+        );
+        CREATE INDEX IF NOT EXISTS mark_price_snapshots_symbol_idx ON mark_price_snapshots(symbol);
+        ",
+    )
+}
+
+/// Records one ticker poll as a snapshot row.
+pub fn record_ticker(conn: &Connection, ticker: &OptionTicker) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO ticker_snapshots (symbol, last_price, volume) VALUES (?1, ?2, ?3)",
+        params![ticker.symbol, ticker.last_price, ticker.volume],
+    )?;
+    Ok(())
+}
+
+/// Records one mark-price poll (including its Greeks and mark IV) as a snapshot row.
+pub fn record_mark_price(conn: &Connection, mark_price: &MarkPrice) -> rusqlite::Result<()> {
+    conn.execute(
+        "INSERT INTO mark_price_snapshots (symbol, mark_price, mark_iv, delta, gamma, theta, vega)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            mark_price.symbol,
+            mark_price.mark_price,
+            mark_price.mark_iv,
+            mark_price.delta,
+            mark_price.gamma,
+            mark_price.theta,
+            mark_price.vega,
+        ],
+    )?;
+    Ok(())
+}
+
+/// One recorded mark-IV reading: when it was recorded, and the mark IV at that time (as a
+/// string, matching the API's own representation).
+#[derive(Debug, Clone, PartialEq)]
+pub struct IvSample {
+    pub recorded_at: String,
+    pub mark_iv: String,
+}
+
+/// The implied-volatility history for `symbol`, oldest first — e.g. to chart IV over several
+/// days of polling.
+pub fn iv_history(conn: &Connection, symbol: &str) -> rusqlite::Result<Vec<IvSample>> {
+    let mut stmt = conn
+        .prepare("SELECT recorded_at, mark_iv FROM mark_price_snapshots WHERE symbol = ?1 ORDER BY id ASC")?;
+    stmt.query_map(params![symbol], |row| {
+        Ok(IvSample {
+            recorded_at: row.get(0)?,
+            mark_iv: row.get(1)?,
+        })
+    })?
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn sample_ticker(symbol: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: "100".to_string(),
+            last_qty: "1".to_string(),
+            open: "95".to_string(),
+            high: "105".to_string(),
+            low: "90".to_string(),
+            volume: "10".to_string(),
+            amount: "1000".to_string(),
+            bid_price: "99".to_string(),
+            ask_price: "101".to_string(),
+            open_time: DateTime::<Utc>::UNIX_EPOCH,
+            close_time: DateTime::<Utc>::UNIX_EPOCH,
+            first_trade_id: 1,
+            trade_count: 5,
+            strike_price: "100".to_string(),
+            exercise_price: "100".to_string(),
+        }
+    }
+
+    fn sample_mark_price(symbol: &str, mark_iv: &str) -> MarkPrice {
+        MarkPrice {
+            symbol: symbol.to_string(),
+            mark_price: "100".to_string(),
+            mark_iv: mark_iv.to_string(),
+            delta: "0.5".to_string(),
+            gamma: "0.01".to_string(),
+            theta: "-0.02".to_string(),
+            vega: "0.03".to_string(),
+            high_price_limit: "120".to_string(),
+            low_price_limit: "80".to_string(),
+        }
+    }
+
+    #[test]
+    fn recorded_ticker_snapshots_round_trip_through_the_schema() {
+        let conn = open(":memory:").unwrap();
+        record_ticker(&conn, &sample_ticker("BTC-240101-50000-C")).unwrap();
+
+        let count: i64 = conn.query_row("SELECT COUNT(*) FROM ticker_snapshots", [], |row| row.get(0)).unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn iv_history_returns_mark_iv_readings_oldest_first() {
+        let conn = open(":memory:").unwrap();
+        record_mark_price(&conn, &sample_mark_price("BTC-240101-50000-C", "0.55")).unwrap();
+        record_mark_price(&conn, &sample_mark_price("BTC-240101-50000-C", "0.60")).unwrap();
+        record_mark_price(&conn, &sample_mark_price("ETH-240101-3000-C", "0.40")).unwrap();
+
+        let history = iv_history(&conn, "BTC-240101-50000-C").unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].mark_iv, "0.55");
+        assert_eq!(history[1].mark_iv, "0.60");
+    }
+}
@@ -0,0 +1,126 @@
+//! Local symbol validation against a cached `exchangeInfo` contract list, so a request for a
+//! symbol on an unlisted underlying fails immediately with
+//! [`BinanceOptionsClientError::UnknownSymbol`] instead of spending a network round trip just to
+//! learn the same thing from Binance's `-1121`.
+//!
+//! The cache only tracks known base assets (e.g. `BTC`), since that's the coarsest-grained thing
+//! `GET /eapi/v1/exchangeInfo`'s contract list enumerates; it can't catch a nonexistent
+//! strike/expiry/kind on an otherwise-listed underlying, since Binance doesn't advertise an
+//! exhaustive symbol list. Wire one up via [`crate::ClientBuilder::symbol_validator`].
+
+use crate::error::BinanceOptionsClientError;
+use crate::model::{ExchangeInfo, OptionSymbol};
+use std::collections::HashSet;
+use std::str::FromStr;
+use std::sync::Mutex;
+
+/// Caches the set of known base assets from a `GET /eapi/v1/exchangeInfo` response and checks a
+/// request's `symbol` parameter against it before it's sent.
+#[derive(Default)]
+pub struct SymbolValidator {
+    known_base_assets: Mutex<HashSet<String>>,
+}
+
+impl SymbolValidator {
+    /// Creates a validator with no known base assets yet. Every symbol passes
+    /// [`Self::validate`] until [`Self::refresh`] or [`Self::refresh_from_json`] has loaded
+    /// some, since an empty cache more likely means "not loaded yet" than "nothing is listed".
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the cached base assets with `base_assets`.
+    pub fn refresh(&self, base_assets: impl IntoIterator<Item = String>) {
+        *self.known_base_assets.lock().unwrap() = base_assets.into_iter().collect();
+    }
+
+    /// Parses a `GET /eapi/v1/exchangeInfo` response body (shaped like
+    /// [`crate::testing::EXCHANGE_INFO_FIXTURE`]) and replaces the cached base assets with the
+    /// ones its contract list names.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `serde_json::Error` if `body` isn't a valid `exchangeInfo` response.
+    pub fn refresh_from_json(&self, body: &str) -> Result<(), serde_json::Error> {
+        let info: ExchangeInfo = serde_json::from_str(body)?;
+        self.refresh(
+            info.option_contracts
+                .into_iter()
+                .map(|contract| contract.base_asset),
+        );
+        Ok(())
+    }
+
+    /// Checks `symbol` against the cached base assets, returning
+    /// [`BinanceOptionsClientError::UnknownSymbol`] if it's definitely not one of them.
+    /// Lets a symbol through uncontested if it doesn't parse (nothing local to check it
+    /// against) or if the cache hasn't been loaded yet.
+    pub fn validate(&self, symbol: &str) -> Result<(), BinanceOptionsClientError> {
+        let Ok(parsed) = OptionSymbol::from_str(symbol) else {
+            return Ok(());
+        };
+
+        let known_base_assets = self.known_base_assets.lock().unwrap();
+        if known_base_assets.is_empty() || known_base_assets.contains(&parsed.underlying) {
+            return Ok(());
+        }
+
+        Err(BinanceOptionsClientError::UnknownSymbol(symbol.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn an_unloaded_validator_lets_everything_through() {
+        let validator = SymbolValidator::new();
+        assert!(validator.validate("BTC-200730-9000-C").is_ok());
+    }
+
+    #[test]
+    fn an_unparseable_symbol_is_let_through_uncontested() {
+        let validator = SymbolValidator::new();
+        validator.refresh(["BTC".to_string()]);
+        assert!(validator.validate("not-a-real-symbol").is_ok());
+    }
+
+    #[test]
+    fn a_known_base_asset_passes() {
+        let validator = SymbolValidator::new();
+        validator.refresh(["BTC".to_string(), "ETH".to_string()]);
+        assert!(validator.validate("BTC-200730-9000-C").is_ok());
+    }
+
+    #[test]
+    fn an_unknown_base_asset_is_rejected() {
+        let validator = SymbolValidator::new();
+        validator.refresh(["BTC".to_string()]);
+        let err = validator.validate("SOL-200730-9000-C").unwrap_err();
+        assert!(matches!(err, BinanceOptionsClientError::UnknownSymbol(symbol) if symbol == "SOL-200730-9000-C"));
+    }
+
+    #[test]
+    fn refresh_from_json_loads_base_assets_from_an_exchange_info_response() {
+        let body = r#"{
+            "timezone": "UTC",
+            "serverTime": 1690000000000,
+            "optionContracts": [
+                {
+                    "id": 1,
+                    "baseAsset": "BTC",
+                    "quoteAsset": "USDT",
+                    "underlying": "BTCUSDT",
+                    "settleAsset": "USDT"
+                }
+            ]
+        }"#;
+
+        let validator = SymbolValidator::new();
+        validator.refresh_from_json(body).unwrap();
+        assert!(validator.validate("BTC-200730-9000-C").is_ok());
+        let err = validator.validate("ETH-200730-9000-C").unwrap_err();
+        assert!(matches!(err, BinanceOptionsClientError::UnknownSymbol(_)));
+    }
+}
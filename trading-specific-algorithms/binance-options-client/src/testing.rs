@@ -0,0 +1,191 @@
+//! Recorded-response fixtures and a canned [`HttpTransport`] for exercising strategies built on
+//! this client without a real network call. Gated behind the `testing` feature, since it's meant
+//! to be pulled in as a dev-dependency by downstream crates rather than built by default.
+//!
+//! ```
+//! use binance_options_client::testing::MockTransport;
+//! use binance_options_client::BinanceOptionsClient;
+//!
+//! let client = BinanceOptionsClient::builder()
+//!     .transport(MockTransport::ticker_fixture())
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use std::future::Future;
+use std::pin::Pin;
+
+use reqwest::StatusCode;
+
+use crate::api::{HttpCall, HttpResponse, HttpTransport};
+use crate::error::BinanceOptionsClientError;
+use crate::model::{OptionTicker, OrderBook};
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+/// A recorded `GET /eapi/v1/ticker` response for a single symbol, shaped like
+/// [`crate::model::OptionTicker`].
+pub const TICKER_FIXTURE: &str = r#"[{
+    "symbol": "BTC-200730-9000-C",
+    "priceChange": "0",
+    "priceChangePercent": "0",
+    "lastPrice": "100",
+    "lastQty": "1",
+    "open": "100",
+    "high": "100",
+    "low": "100",
+    "volume": "1",
+    "amount": "100",
+    "bidPrice": "99",
+    "askPrice": "101",
+    "openTime": 1690000000000,
+    "closeTime": 1690000000000,
+    "firstTradeId": 1,
+    "tradeCount": 1,
+    "strikePrice": "9000",
+    "exercisePrice": "9000"
+}]"#;
+
+/// A recorded `GET /eapi/v1/depth` response, shaped like [`crate::model::OrderBook`].
+pub const DEPTH_FIXTURE: &str = r#"{
+    "bids": [["99", "10"], ["98", "5"]],
+    "asks": [["101", "8"], ["102", "3"]],
+    "updateId": 1000000
+}"#;
+
+/// A recorded `GET /eapi/v1/exchangeInfo` response, shaped like [`crate::model::ExchangeInfo`].
+pub const EXCHANGE_INFO_FIXTURE: &str = r#"{
+    "timezone": "UTC",
+    "serverTime": 1690000000000,
+    "optionContracts": [
+        {
+            "id": 1,
+            "baseAsset": "BTC",
+            "quoteAsset": "USDT",
+            "underlying": "BTCUSDT",
+            "settleAsset": "USDT"
+        }
+    ]
+}"#;
+
+/// A canned [`HttpTransport`] that returns the same recorded `(status, body)` pair to every
+/// call, ignoring the [`HttpCall`] it's given. Install it via [`crate::ClientBuilder::transport`]
+/// to drive a [`crate::BinanceOptionsClient`] off a fixture instead of the network.
+pub struct MockTransport {
+    status: StatusCode,
+    body: String,
+}
+
+impl MockTransport {
+    /// Builds a transport that always returns `body` with the given `status`.
+    pub fn with_body(status: StatusCode, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            body: body.into(),
+        }
+    }
+
+    /// A transport returning [`TICKER_FIXTURE`] with a 200 status.
+    pub fn ticker_fixture() -> Self {
+        Self::with_body(StatusCode::OK, TICKER_FIXTURE)
+    }
+
+    /// A transport returning [`DEPTH_FIXTURE`] with a 200 status.
+    pub fn depth_fixture() -> Self {
+        Self::with_body(StatusCode::OK, DEPTH_FIXTURE)
+    }
+
+    /// A transport returning [`EXCHANGE_INFO_FIXTURE`] with a 200 status.
+    pub fn exchange_info_fixture() -> Self {
+        Self::with_body(StatusCode::OK, EXCHANGE_INFO_FIXTURE)
+    }
+}
+
+impl HttpTransport for MockTransport {
+    fn send<'a>(
+        &'a self,
+        _call: &'a HttpCall,
+    ) -> BoxFuture<'a, Result<HttpResponse, BinanceOptionsClientError>> {
+        let status = self.status;
+        let body = self.body.clone();
+        Box::pin(async move {
+            Ok(HttpResponse {
+                status,
+                headers: reqwest::header::HeaderMap::new(),
+                body,
+            })
+        })
+    }
+}
+
+/// Parses [`TICKER_FIXTURE`] into its typed representation.
+///
+/// # Panics
+///
+/// Panics if the fixture itself is malformed, which would be a bug in this module.
+pub fn parsed_ticker_fixture() -> Vec<OptionTicker> {
+    serde_json::from_str(TICKER_FIXTURE).expect("TICKER_FIXTURE is valid JSON")
+}
+
+/// Parses [`DEPTH_FIXTURE`] into its typed representation.
+///
+/// # Panics
+///
+/// Panics if the fixture itself is malformed, which would be a bug in this module.
+pub fn parsed_depth_fixture() -> OrderBook {
+    serde_json::from_str(DEPTH_FIXTURE).expect("DEPTH_FIXTURE is valid JSON")
+}
+
+/// Parses [`EXCHANGE_INFO_FIXTURE`] into its typed representation.
+///
+/// # Panics
+///
+/// Panics if the fixture itself is malformed, which would be a bug in this module.
+pub fn parsed_exchange_info_fixture() -> crate::model::ExchangeInfo {
+    serde_json::from_str(EXCHANGE_INFO_FIXTURE).expect("EXCHANGE_INFO_FIXTURE is valid JSON")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::TickerRequest;
+
+    #[test]
+    fn parsed_ticker_fixture_matches_ticker_fixture() {
+        let tickers = parsed_ticker_fixture();
+        assert_eq!(tickers.len(), 1);
+        assert_eq!(tickers[0].symbol, "BTC-200730-9000-C");
+    }
+
+    #[test]
+    fn parsed_depth_fixture_matches_depth_fixture() {
+        let depth = parsed_depth_fixture();
+        assert_eq!(depth.update_id, 1000000);
+        assert_eq!(depth.bids.len(), 2);
+        assert_eq!(depth.asks.len(), 2);
+    }
+
+    #[test]
+    fn parsed_exchange_info_fixture_matches_exchange_info_fixture() {
+        let info = parsed_exchange_info_fixture();
+        assert_eq!(info.timezone, "UTC");
+        assert_eq!(info.option_contracts.len(), 1);
+        assert_eq!(info.option_contracts[0].base_asset, "BTC");
+        assert_eq!(info.option_contracts[0].underlying, "BTCUSDT");
+    }
+
+    #[tokio::test]
+    async fn ticker_fixture_transport_drives_a_real_client() {
+        let client = crate::BinanceOptionsClient::builder()
+            .transport(MockTransport::ticker_fixture())
+            .build()
+            .unwrap();
+
+        let tickers: Vec<OptionTicker> = client
+            .send_request(TickerRequest::new().into())
+            .await
+            .unwrap();
+
+        assert_eq!(tickers, parsed_ticker_fixture());
+    }
+}
@@ -0,0 +1,102 @@
+//! Requests-per-interval pacing, independent of [`crate::rate_limit::RateLimiter`]'s
+//! weight-based accounting. Binance's weight limit is tracked per API key/IP on Binance's side,
+//! but a single client process only sees the weight it itself has spent; running several
+//! client instances behind the same IP (e.g. one per trading strategy) can still collectively
+//! blow through Binance's limit even though each instance looks fine on its own.
+//! [`RequestThrottle`] is meant to be wrapped in an `Arc` and shared across those instances so
+//! they pace themselves against one shared budget.
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Paces callers to at most `max_requests` per `interval`, sleeping whoever would exceed it
+/// until the next window opens. Share one instance (behind an `Arc`) across every
+/// [`crate::BinanceOptionsClient`] running behind the same IP to pace them collectively.
+pub struct RequestThrottle {
+    max_requests: u32,
+    interval: Duration,
+    state: Mutex<WindowState>,
+}
+
+struct WindowState {
+    window_start: Instant,
+    count: u32,
+}
+
+impl RequestThrottle {
+    /// Creates a throttle allowing at most `max_requests` per `interval`. `max_requests` is
+    /// clamped to at least 1, since a limit of zero could never be satisfied.
+    pub fn new(max_requests: u32, interval: Duration) -> Self {
+        Self {
+            max_requests: max_requests.max(1),
+            interval,
+            state: Mutex::new(WindowState {
+                window_start: Instant::now(),
+                count: 0,
+            }),
+        }
+    }
+
+    /// Returns the configured requests-per-interval limit.
+    pub fn max_requests(&self) -> u32 {
+        self.max_requests
+    }
+
+    /// Waits, if necessary, until another request can be sent without exceeding the configured
+    /// pace, then reserves a slot in the current window.
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().unwrap();
+                let elapsed = state.window_start.elapsed();
+                if elapsed >= self.interval {
+                    state.window_start = Instant::now();
+                    state.count = 0;
+                }
+
+                if state.count < self.max_requests {
+                    state.count += 1;
+                    None
+                } else {
+                    Some(self.interval - elapsed)
+                }
+            };
+
+            match wait {
+                None => return,
+                Some(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn acquire_does_not_block_while_under_the_limit() {
+        let throttle = RequestThrottle::new(5, Duration::from_secs(60));
+        let started = Instant::now();
+        for _ in 0..5 {
+            throttle.acquire().await;
+        }
+        assert!(started.elapsed() < Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn acquire_waits_for_the_next_window_once_the_limit_is_reached() {
+        let throttle = RequestThrottle::new(1, Duration::from_millis(50));
+        throttle.acquire().await;
+
+        let started = Instant::now();
+        throttle.acquire().await;
+        assert!(started.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[test]
+    fn zero_max_requests_is_clamped_to_one() {
+        let throttle = RequestThrottle::new(0, Duration::from_secs(1));
+        assert_eq!(throttle.max_requests(), 1);
+    }
+}
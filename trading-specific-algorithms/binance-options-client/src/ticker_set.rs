@@ -0,0 +1,254 @@
+//! Client-side filtering and sorting over a flat batch of tickers, so callers stop writing
+//! repetitive `iter().filter(...)` chains that re-parse `symbol` at every call site. Pairs with
+//! [`crate::chain::OptionsChain`], which groups the same data by underlying/expiry/strike
+//! instead of filtering it down.
+
+use crate::chain::Expiry;
+use crate::model::{OptionKind, OptionTicker};
+use rust_decimal::Decimal;
+
+/// A batch of tickers with chainable, consuming filter and sort methods. Tickers whose `symbol`
+/// doesn't parse are dropped by every symbol-derived filter (underlying, expiry, strike, kind),
+/// the same "no answer" treatment [`OptionTicker::moneyness`] and friends give an unparseable
+/// symbol.
+#[derive(Debug, Clone, Default)]
+pub struct TickerSet {
+    tickers: Vec<OptionTicker>,
+}
+
+impl TickerSet {
+    /// Wraps a flat list of tickers for filtering and sorting.
+    pub fn new(tickers: Vec<OptionTicker>) -> Self {
+        Self { tickers }
+    }
+
+    /// Unwraps back into the underlying `Vec`, in whatever order filtering/sorting left it.
+    pub fn into_inner(self) -> Vec<OptionTicker> {
+        self.tickers
+    }
+
+    /// Returns the tickers currently in the set.
+    pub fn as_slice(&self) -> &[OptionTicker] {
+        &self.tickers
+    }
+
+    /// Keeps only tickers whose underlying matches `underlying` exactly.
+    pub fn underlying(mut self, underlying: &str) -> Self {
+        self.tickers
+            .retain(|ticker| matches!(ticker.parsed_symbol(), Ok(symbol) if symbol.underlying == underlying));
+        self
+    }
+
+    /// Keeps only tickers whose expiry falls within `[start, end]`, inclusive.
+    pub fn expiry_range(mut self, start: Expiry, end: Expiry) -> Self {
+        self.tickers.retain(|ticker| {
+            let Ok(symbol) = ticker.parsed_symbol() else {
+                return false;
+            };
+            let expiry = (symbol.expiry_year, symbol.expiry_month, symbol.expiry_day);
+            expiry >= start && expiry <= end
+        });
+        self
+    }
+
+    /// Keeps only tickers whose strike falls within `[min, max]`, inclusive.
+    pub fn strike_range(mut self, min: Decimal, max: Decimal) -> Self {
+        self.tickers.retain(|ticker| {
+            matches!(ticker.parsed_symbol(), Ok(symbol) if symbol.strike >= min && symbol.strike <= max)
+        });
+        self
+    }
+
+    /// Keeps only calls or only puts.
+    pub fn kind(mut self, kind: OptionKind) -> Self {
+        self.tickers
+            .retain(|ticker| matches!(ticker.parsed_symbol(), Ok(symbol) if symbol.kind == kind));
+        self
+    }
+
+    /// Keeps only tickers with at least `min_volume` traded. Tickers whose `volume` field isn't
+    /// a valid decimal string are dropped, same as an unparseable symbol.
+    pub fn min_volume(mut self, min_volume: Decimal) -> Self {
+        self.tickers
+            .retain(|ticker| matches!(ticker.to_decimal(), Ok(decimal) if decimal.volume >= min_volume));
+        self
+    }
+
+    /// Sorts ascending by strike. Tickers whose symbol doesn't parse sort after every ticker
+    /// that does.
+    pub fn sort_by_strike(mut self) -> Self {
+        self.tickers
+            .sort_by_key(|ticker| ticker.parsed_symbol().map(|symbol| symbol.strike).ok());
+        self
+    }
+
+    /// Sorts descending by traded volume. Tickers whose `volume` field doesn't parse sort last.
+    pub fn sort_by_volume_desc(mut self) -> Self {
+        self.tickers.sort_by(|a, b| {
+            let volume_of = |ticker: &OptionTicker| ticker.to_decimal().map(|d| d.volume).ok();
+            volume_of(b).cmp(&volume_of(a))
+        });
+        self
+    }
+
+    /// Sorts ascending by expiry date. Tickers whose symbol doesn't parse sort after every
+    /// ticker that does.
+    pub fn sort_by_expiry(mut self) -> Self {
+        self.tickers.sort_by_key(|ticker| {
+            ticker
+                .parsed_symbol()
+                .map(|symbol| (symbol.expiry_year, symbol.expiry_month, symbol.expiry_day))
+                .ok()
+        });
+        self
+    }
+}
+
+impl From<Vec<OptionTicker>> for TickerSet {
+    fn from(tickers: Vec<OptionTicker>) -> Self {
+        Self::new(tickers)
+    }
+}
+
+impl IntoIterator for TickerSet {
+    type Item = OptionTicker;
+    type IntoIter = std::vec::IntoIter<OptionTicker>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.tickers.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    fn ticker(symbol: &str, volume: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: "0".to_string(),
+            last_qty: "0".to_string(),
+            open: "0".to_string(),
+            high: "0".to_string(),
+            low: "0".to_string(),
+            volume: volume.to_string(),
+            amount: "0".to_string(),
+            bid_price: "0".to_string(),
+            ask_price: "0".to_string(),
+            open_time: chrono::DateTime::UNIX_EPOCH,
+            close_time: chrono::DateTime::UNIX_EPOCH,
+            first_trade_id: 0,
+            trade_count: 0,
+            strike_price: "0".to_string(),
+            exercise_price: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn underlying_keeps_only_matching_tickers() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200730-9000-C", "10"),
+            ticker("ETH-200730-9000-C", "10"),
+        ])
+        .underlying("BTC");
+        assert_eq!(set.as_slice().len(), 1);
+        assert_eq!(set.as_slice()[0].symbol, "BTC-200730-9000-C");
+    }
+
+    #[test]
+    fn expiry_range_keeps_only_expiries_within_bounds() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200630-9000-C", "10"),
+            ticker("BTC-200730-9000-C", "10"),
+            ticker("BTC-200830-9000-C", "10"),
+        ])
+        .expiry_range((2020, 7, 1), (2020, 7, 31));
+        assert_eq!(set.as_slice().len(), 1);
+        assert_eq!(set.as_slice()[0].symbol, "BTC-200730-9000-C");
+    }
+
+    #[test]
+    fn strike_range_keeps_only_strikes_within_bounds() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200730-8000-C", "10"),
+            ticker("BTC-200730-9000-C", "10"),
+            ticker("BTC-200730-10000-C", "10"),
+        ])
+        .strike_range(Decimal::from_str("9000").unwrap(), Decimal::from_str("10000").unwrap());
+        let strikes: Vec<_> = set.as_slice().iter().map(|t| t.symbol.clone()).collect();
+        assert_eq!(strikes, vec!["BTC-200730-9000-C", "BTC-200730-10000-C"]);
+    }
+
+    #[test]
+    fn kind_keeps_only_the_requested_side() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200730-9000-C", "10"),
+            ticker("BTC-200730-9000-P", "10"),
+        ])
+        .kind(OptionKind::Put);
+        assert_eq!(set.as_slice().len(), 1);
+        assert_eq!(set.as_slice()[0].symbol, "BTC-200730-9000-P");
+    }
+
+    #[test]
+    fn min_volume_drops_thinly_traded_tickers() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200730-9000-C", "5"),
+            ticker("BTC-200730-9500-C", "50"),
+        ])
+        .min_volume(Decimal::from_str("10").unwrap());
+        assert_eq!(set.as_slice().len(), 1);
+        assert_eq!(set.as_slice()[0].symbol, "BTC-200730-9500-C");
+    }
+
+    #[test]
+    fn sort_by_strike_orders_ascending() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200730-10000-C", "10"),
+            ticker("BTC-200730-9000-C", "10"),
+        ])
+        .sort_by_strike();
+        let strikes: Vec<_> = set.as_slice().iter().map(|t| t.symbol.clone()).collect();
+        assert_eq!(strikes, vec!["BTC-200730-9000-C", "BTC-200730-10000-C"]);
+    }
+
+    #[test]
+    fn sort_by_volume_desc_orders_descending() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200730-9000-C", "5"),
+            ticker("BTC-200730-9500-C", "50"),
+        ])
+        .sort_by_volume_desc();
+        let volumes: Vec<_> = set.as_slice().iter().map(|t| t.volume.clone()).collect();
+        assert_eq!(volumes, vec!["50", "5"]);
+    }
+
+    #[test]
+    fn sort_by_expiry_orders_ascending() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200830-9000-C", "10"),
+            ticker("BTC-200730-9000-C", "10"),
+        ])
+        .sort_by_expiry();
+        let symbols: Vec<_> = set.as_slice().iter().map(|t| t.symbol.clone()).collect();
+        assert_eq!(symbols, vec!["BTC-200730-9000-C", "BTC-200830-9000-C"]);
+    }
+
+    #[test]
+    fn chained_filters_compose() {
+        let set = TickerSet::new(vec![
+            ticker("BTC-200730-9000-C", "50"),
+            ticker("BTC-200730-9000-P", "50"),
+            ticker("ETH-200730-9000-C", "50"),
+            ticker("BTC-200730-9000-C", "1"),
+        ])
+        .underlying("BTC")
+        .kind(OptionKind::Call)
+        .min_volume(Decimal::from_str("10").unwrap());
+        assert_eq!(set.as_slice().len(), 1);
+        assert_eq!(set.as_slice()[0].volume, "50");
+    }
+}
@@ -0,0 +1,175 @@
+//! A bounded, symbol-keyed store of the latest [`OptionTicker`] per instrument, backed by the
+//! `hash-table` crate's `LinkedHashTable` for O(1) most-/least-recently-updated lookups —
+//! e.g. to answer "which instrument just moved" or "which instrument hasn't reported in a
+//! while" without scanning every entry.
+//!
+//! As in `response_cache`'s `CacheState`, tickers live in a side `Vec`, with `LinkedHashTable`
+//! only ever storing a `usize` slot handle. `LinkedOpenAddressing::remove` reclaims an evicted
+//! value by swapping in a zero-initialized placeholder, which is unsound for heap types like
+//! `OptionTicker`'s `String` fields — `usize` is the one value type that's always safe to zero,
+//! so routing eviction through it avoids the problem while still getting O(1) recency tracking.
+//!
+//! `LinkedOpenAddressing` recycles a removed entry's node index on the next insert (see its
+//! "Node Recycling" note), so evicting an old symbol to make room for a new one doesn't exhaust
+//! the node budget the way it used to -- `capacity` bounds this store's live entry count, not
+//! its lifetime total of upserts.
+
+use hash_table::{HashTable, LinkedHashTable};
+
+use crate::model::OptionTicker;
+
+/// A bounded store of the latest ticker per symbol, evicting the least-recently-updated symbol
+/// when a new one arrives at capacity.
+pub struct TickerStore {
+    capacity: usize,
+    /// Tracks recency and capacity; values are slot indices into `entries`, not tickers.
+    order: LinkedHashTable<String, usize>,
+    entries: Vec<Option<OptionTicker>>,
+    free_slots: Vec<usize>,
+}
+
+impl TickerStore {
+    /// Creates a store that holds at most `capacity` symbols.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            order: LinkedHashTable::new(capacity),
+            entries: Vec::new(),
+            free_slots: Vec::new(),
+        }
+    }
+
+    /// Records `ticker` as the latest update for its symbol, marking it the most recently
+    /// updated entry. If the symbol is new and the store is at capacity, the least recently
+    /// updated symbol is evicted first.
+    pub fn upsert(&mut self, ticker: OptionTicker) {
+        let symbol = ticker.symbol.clone();
+
+        if let Some(&slot) = self.order.get(&symbol) {
+            // Refresh recency. `LinkedOpenAddressing::insert` on an existing key still hits its
+            // "table is full" check before it notices this is an update, so remove first to keep
+            // `len` below capacity going into the re-insert.
+            self.order.remove(&symbol);
+            self.order.insert(symbol, slot);
+            self.entries[slot] = Some(ticker);
+            return;
+        }
+
+        if self.order.len() >= self.capacity
+            && let Some((stale_symbol, &stale_slot)) = self.order.get_first()
+        {
+            let stale_symbol = stale_symbol.clone();
+            self.order.remove(&stale_symbol);
+            self.entries[stale_slot] = None;
+            self.free_slots.push(stale_slot);
+        }
+
+        let slot = self.free_slots.pop().unwrap_or_else(|| {
+            self.entries.push(None);
+            self.entries.len() - 1
+        });
+        self.entries[slot] = Some(ticker);
+        self.order.insert(symbol, slot);
+    }
+
+    /// The latest ticker recorded for `symbol`, if any.
+    pub fn get(&self, symbol: &str) -> Option<&OptionTicker> {
+        let slot = *self.order.get(&symbol.to_string())?;
+        self.entries[slot].as_ref()
+    }
+
+    /// The most recently updated ticker, i.e. the last symbol to come through [`upsert`].
+    ///
+    /// [`upsert`]: Self::upsert
+    pub fn most_recently_updated(&self) -> Option<&OptionTicker> {
+        let (_, &slot) = self.order.get_last()?;
+        self.entries[slot].as_ref()
+    }
+
+    /// The stalest ticker, i.e. the symbol that has gone the longest without an update.
+    pub fn stalest(&self) -> Option<&OptionTicker> {
+        let (_, &slot) = self.order.get_first()?;
+        self.entries[slot].as_ref()
+    }
+
+    /// The number of symbols currently tracked.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Whether no symbols are currently tracked.
+    pub fn is_empty(&self) -> bool {
+        self.order.len() == 0
+    }
+
+    /// The maximum number of symbols this store can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn ticker(symbol: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: "100".to_string(),
+            last_qty: "1".to_string(),
+            open: "95".to_string(),
+            high: "105".to_string(),
+            low: "90".to_string(),
+            volume: "10".to_string(),
+            amount: "1000".to_string(),
+            bid_price: "99".to_string(),
+            ask_price: "101".to_string(),
+            open_time: DateTime::<Utc>::UNIX_EPOCH,
+            close_time: DateTime::<Utc>::UNIX_EPOCH,
+            first_trade_id: 1,
+            trade_count: 5,
+            strike_price: "100".to_string(),
+            exercise_price: "100".to_string(),
+        }
+    }
+
+    #[test]
+    fn get_returns_the_latest_ticker_for_a_symbol() {
+        let mut store = TickerStore::new(4);
+        store.upsert(ticker("BTC-240101-50000-C"));
+
+        assert_eq!(store.get("BTC-240101-50000-C").unwrap().symbol, "BTC-240101-50000-C");
+        assert!(store.get("ETH-240101-3000-C").is_none());
+    }
+
+    #[test]
+    fn most_recently_updated_tracks_the_latest_upsert() {
+        let mut store = TickerStore::new(4);
+        store.upsert(ticker("BTC-240101-50000-C"));
+        store.upsert(ticker("ETH-240101-3000-C"));
+
+        assert_eq!(store.most_recently_updated().unwrap().symbol, "ETH-240101-3000-C");
+    }
+
+    #[test]
+    fn stalest_reports_the_symbol_updated_longest_ago() {
+        let mut store = TickerStore::new(4);
+        store.upsert(ticker("BTC-240101-50000-C"));
+        store.upsert(ticker("ETH-240101-3000-C"));
+
+        assert_eq!(store.stalest().unwrap().symbol, "BTC-240101-50000-C");
+    }
+
+    #[test]
+    fn len_and_is_empty_reflect_tracked_symbols() {
+        let mut store = TickerStore::new(4);
+        assert!(store.is_empty());
+
+        store.upsert(ticker("BTC-240101-50000-C"));
+        assert_eq!(store.len(), 1);
+        assert!(!store.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+//! Incremental splitting of a streamed top-level JSON array into its element substrings, as
+//! they complete, without buffering more than the current in-progress element plus whatever
+//! whitespace/separators precede it. Backs [`crate::BinanceOptionsClient::get_ticker_stream`],
+//! which feeds this splitter from `response.bytes_stream()` chunks so ticker parsing overlaps
+//! the download instead of waiting for the whole response body.
+
+use crate::error::BinanceOptionsClientError;
+
+/// Splits a byte stream of a single top-level JSON array (e.g. Binance's ticker list response)
+/// into its element substrings. Assumes every element is a JSON object or array (true for every
+/// array response this client parses); a bare scalar element is rejected rather than mishandled.
+#[derive(Debug, Default)]
+pub(crate) struct ArraySplitter {
+    pending: Vec<u8>,
+    in_element: bool,
+    depth: u32,
+    in_string: bool,
+    escape_next: bool,
+}
+
+impl ArraySplitter {
+    /// Feeds `chunk` in and returns every element it completes, in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `BinanceOptionsClientError::Unknown` if an element isn't UTF-8, or if a `}`/`]`
+    /// appears without a matching opener (malformed input).
+    pub(crate) fn feed(&mut self, chunk: &[u8]) -> Result<Vec<String>, BinanceOptionsClientError> {
+        let mut completed = Vec::new();
+
+        for &byte in chunk {
+            if !self.in_element {
+                if byte.is_ascii_whitespace() || byte == b'[' || byte == b',' || byte == b']' {
+                    continue;
+                }
+                if byte != b'{' && byte != b'[' {
+                    return Err(BinanceOptionsClientError::Unknown(format!(
+                        "ticker stream element started with unexpected byte {byte:#x}; expected an object or array"
+                    )));
+                }
+                self.in_element = true;
+            }
+
+            self.pending.push(byte);
+
+            if self.in_string {
+                if self.escape_next {
+                    self.escape_next = false;
+                } else if byte == b'\\' {
+                    self.escape_next = true;
+                } else if byte == b'"' {
+                    self.in_string = false;
+                }
+                continue;
+            }
+
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => self.depth += 1,
+                b'}' | b']' => {
+                    self.depth = self.depth.checked_sub(1).ok_or_else(|| {
+                        BinanceOptionsClientError::Unknown(
+                            "ticker stream contained an unbalanced array".to_string(),
+                        )
+                    })?;
+                    if self.depth == 0 {
+                        let text = String::from_utf8(std::mem::take(&mut self.pending))
+                            .map_err(|error| {
+                                BinanceOptionsClientError::Unknown(format!(
+                                    "ticker stream produced invalid UTF-8: {error}"
+                                ))
+                            })?;
+                        completed.push(text);
+                        self.in_element = false;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Ok(completed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn feed_emits_nothing_for_a_partial_element() {
+        let mut splitter = ArraySplitter::default();
+        let completed = splitter.feed(br#"[{"a": 1"#).unwrap();
+        assert!(completed.is_empty());
+    }
+
+    #[test]
+    fn feed_emits_an_element_once_its_chunk_completes_it() {
+        let mut splitter = ArraySplitter::default();
+        assert!(splitter.feed(br#"[{"a": 1"#).unwrap().is_empty());
+        let completed = splitter.feed(br#"},{"b": 2"#).unwrap();
+        assert_eq!(completed, vec![r#"{"a": 1}"#.to_string()]);
+    }
+
+    #[test]
+    fn feed_handles_a_whole_array_arriving_at_once() {
+        let mut splitter = ArraySplitter::default();
+        let completed = splitter.feed(br#"[{"a": 1}, {"b": 2}]"#).unwrap();
+        assert_eq!(
+            completed,
+            vec![r#"{"a": 1}"#.to_string(), r#"{"b": 2}"#.to_string()]
+        );
+    }
+
+    #[test]
+    fn feed_ignores_braces_inside_string_values() {
+        let mut splitter = ArraySplitter::default();
+        let completed = splitter.feed(br#"[{"a": "}"}]"#).unwrap();
+        assert_eq!(completed, vec![r#"{"a": "}"}"#.to_string()]);
+    }
+
+    #[test]
+    fn feed_rejects_a_bare_scalar_element() {
+        let mut splitter = ArraySplitter::default();
+        assert!(splitter.feed(b"[1, 2]").is_err());
+    }
+
+    #[test]
+    fn feed_rejects_an_unbalanced_closer() {
+        let mut splitter = ArraySplitter::default();
+        assert!(splitter.feed(b"]}").is_err());
+    }
+}
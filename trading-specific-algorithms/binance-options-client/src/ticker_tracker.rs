@@ -0,0 +1,164 @@
+//! Diffing between successive ticker snapshots: [`TickerTracker`] remembers the last ticker
+//! seen per symbol and, given a new batch (e.g. from [`crate::BinanceOptionsClient::get_ticker_raw`]
+//! plus parsing, or [`crate::BinanceOptionsClient::get_tickers_for`]), emits typed
+//! [`TickerChange`]s for the symbols that actually moved, so a polling pipeline can act on
+//! changes instead of re-scanning every ticker on each poll.
+
+use crate::model::OptionTicker;
+use std::collections::{HashMap, HashSet};
+
+/// A change detected between two successive snapshots given to [`TickerTracker::diff`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum TickerChange {
+    /// `symbol` was present in both snapshots, but its last traded price differs.
+    PriceMoved {
+        /// The symbol whose price changed.
+        symbol: String,
+        /// The last traded price in the previous snapshot.
+        previous_price: String,
+        /// The last traded price in the new snapshot.
+        current_price: String,
+    },
+    /// `symbol` appeared for the first time in this snapshot.
+    Listed {
+        /// The newly-seen ticker.
+        ticker: Box<OptionTicker>,
+    },
+    /// `symbol` was present in the previous snapshot but is missing from this one, meaning the
+    /// contract has most likely expired or been delisted.
+    Expired {
+        /// The symbol that disappeared.
+        symbol: String,
+    },
+}
+
+/// Tracks the most recently seen ticker per symbol and diffs each new batch against it.
+#[derive(Debug, Default)]
+pub struct TickerTracker {
+    last_seen: HashMap<String, OptionTicker>,
+}
+
+impl TickerTracker {
+    /// Creates an empty tracker. Every symbol in the first snapshot given to [`Self::diff`]
+    /// will be reported as [`TickerChange::Listed`], since there's nothing to diff it against.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Diffs `snapshot` against the previous snapshot, if any, then remembers `snapshot` as the
+    /// new baseline for the next call. Symbols with an unchanged last price produce no change.
+    pub fn diff(&mut self, snapshot: &[OptionTicker]) -> Vec<TickerChange> {
+        let mut changes = Vec::new();
+        let mut seen_this_batch = HashSet::with_capacity(snapshot.len());
+
+        for ticker in snapshot {
+            seen_this_batch.insert(ticker.symbol.as_str());
+            match self.last_seen.get(&ticker.symbol) {
+                Some(previous) if previous.last_price != ticker.last_price => {
+                    changes.push(TickerChange::PriceMoved {
+                        symbol: ticker.symbol.clone(),
+                        previous_price: previous.last_price.clone(),
+                        current_price: ticker.last_price.clone(),
+                    });
+                }
+                Some(_) => {}
+                None => changes.push(TickerChange::Listed {
+                    ticker: Box::new(ticker.clone()),
+                }),
+            }
+        }
+
+        for symbol in self.last_seen.keys() {
+            if !seen_this_batch.contains(symbol.as_str()) {
+                changes.push(TickerChange::Expired {
+                    symbol: symbol.clone(),
+                });
+            }
+        }
+
+        self.last_seen = snapshot
+            .iter()
+            .map(|ticker| (ticker.symbol.clone(), ticker.clone()))
+            .collect();
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ticker(symbol: &str, last_price: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: last_price.to_string(),
+            last_qty: "1".to_string(),
+            open: "100".to_string(),
+            high: "100".to_string(),
+            low: "100".to_string(),
+            volume: "1".to_string(),
+            amount: "100".to_string(),
+            bid_price: "99".to_string(),
+            ask_price: "101".to_string(),
+            open_time: chrono::DateTime::UNIX_EPOCH,
+            close_time: chrono::DateTime::UNIX_EPOCH,
+            first_trade_id: 1,
+            trade_count: 1,
+            strike_price: "9000".to_string(),
+            exercise_price: "9000".to_string(),
+        }
+    }
+
+    #[test]
+    fn the_first_snapshot_reports_every_symbol_as_listed() {
+        let mut tracker = TickerTracker::new();
+        let changes = tracker.diff(&[ticker("BTC-200730-9000-C", "100")]);
+
+        assert_eq!(
+            changes,
+            vec![TickerChange::Listed {
+                ticker: Box::new(ticker("BTC-200730-9000-C", "100"))
+            }]
+        );
+    }
+
+    #[test]
+    fn a_changed_price_is_reported_and_an_unchanged_one_is_not() {
+        let mut tracker = TickerTracker::new();
+        tracker.diff(&[
+            ticker("BTC-200730-9000-C", "100"),
+            ticker("ETH-200730-9000-C", "50"),
+        ]);
+
+        let changes = tracker.diff(&[
+            ticker("BTC-200730-9000-C", "110"),
+            ticker("ETH-200730-9000-C", "50"),
+        ]);
+
+        assert_eq!(
+            changes,
+            vec![TickerChange::PriceMoved {
+                symbol: "BTC-200730-9000-C".to_string(),
+                previous_price: "100".to_string(),
+                current_price: "110".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn a_symbol_missing_from_the_new_snapshot_is_reported_as_expired() {
+        let mut tracker = TickerTracker::new();
+        tracker.diff(&[ticker("BTC-200730-9000-C", "100")]);
+
+        let changes = tracker.diff(&[]);
+
+        assert_eq!(
+            changes,
+            vec![TickerChange::Expired {
+                symbol: "BTC-200730-9000-C".to_string(),
+            }]
+        );
+    }
+}
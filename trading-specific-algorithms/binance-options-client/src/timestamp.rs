@@ -0,0 +1,43 @@
+//! Serde adapter for millisecond-precision Unix timestamps. Binance represents timestamps as
+//! epoch milliseconds on the wire; this crate exposes them as `chrono::DateTime<Utc>` so
+//! consumers stop re-deriving calendar fields from a raw `i64` themselves. Apply with
+//! `#[serde(with = "crate::timestamp")]`.
+
+use chrono::{DateTime, Utc};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a `DateTime<Utc>` as epoch milliseconds.
+pub fn serialize<S: Serializer>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error> {
+    value.timestamp_millis().serialize(serializer)
+}
+
+/// Deserializes epoch milliseconds into a `DateTime<Utc>`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DateTime<Utc>, D::Error> {
+    let millis = i64::deserialize(deserializer)?;
+    DateTime::from_timestamp_millis(millis)
+        .ok_or_else(|| D::Error::custom(format!("timestamp {millis} out of range")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Wrapper {
+        #[serde(with = "super")]
+        at: DateTime<Utc>,
+    }
+
+    #[test]
+    fn millisecond_timestamps_round_trip() {
+        let original = Wrapper {
+            at: DateTime::from_timestamp_millis(1_690_000_000_123).unwrap(),
+        };
+        let json = serde_json::to_string(&original).unwrap();
+        assert_eq!(json, r#"{"at":1690000000123}"#);
+
+        let parsed: Wrapper = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, original);
+    }
+}
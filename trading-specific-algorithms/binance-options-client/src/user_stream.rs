@@ -0,0 +1,170 @@
+//! User data stream support: the WebSocket consumer for a listen key's private stream
+//! (`wss://nbstream.binance.com/eoptions/ws/{listenKey}`), delivering typed account-update and
+//! order-update events for signed users. Listen-key lifecycle management
+//! (create/keepalive/close) lives on [`crate::BinanceOptionsClient`], since it's a regular
+//! signed REST call, not part of the WebSocket itself.
+
+use crate::api::{OrderSide, OrderType, TimeInForce};
+use futures_util::{Stream, StreamExt};
+use serde::Deserialize;
+use tokio_tungstenite::tungstenite::Message;
+
+const WS_BASE_URL: &str = "wss://nbstream.binance.com/eoptions/ws";
+
+/// An account balance/position update, delivered as an `"ACCOUNT_UPDATE"` event.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct AccountUpdateEvent {
+    /// Event time, epoch milliseconds.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+}
+
+/// The order details nested inside an `"ORDER_TRADE_UPDATE"` event.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct OrderUpdateDetail {
+    /// The option symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// The order ID.
+    #[serde(rename = "i")]
+    pub order_id: i64,
+    /// Buy or sell.
+    #[serde(rename = "S")]
+    pub side: OrderSide,
+    /// Limit or market.
+    #[serde(rename = "o")]
+    pub order_type: OrderType,
+    /// Good-till-cancelled, immediate-or-cancel, or fill-or-kill.
+    #[serde(rename = "f")]
+    pub time_in_force: TimeInForce,
+    /// The order's current status, e.g. `"FILLED"`, `"CANCELLED"`.
+    #[serde(rename = "X")]
+    pub status: String,
+    /// The order's limit price.
+    #[serde(rename = "p")]
+    pub price: String,
+    /// The order's original quantity.
+    #[serde(rename = "q")]
+    pub quantity: String,
+}
+
+/// An order status change, delivered as an `"ORDER_TRADE_UPDATE"` event.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct OrderUpdateEvent {
+    /// Event time, epoch milliseconds.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// The order details.
+    #[serde(rename = "o")]
+    pub order: OrderUpdateDetail,
+}
+
+/// A parsed user data stream event.
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserStreamEvent {
+    /// An account balance/position update.
+    AccountUpdate(AccountUpdateEvent),
+    /// An order status change.
+    OrderUpdate(OrderUpdateEvent),
+}
+
+/// Error returned while connecting to or reading from a user data stream.
+#[derive(Debug, thiserror::Error)]
+pub enum UserStreamError {
+    /// The WebSocket connection failed or was closed unexpectedly.
+    #[error("WebSocket error: {0}")]
+    WebSocket(#[from] Box<tokio_tungstenite::tungstenite::Error>),
+    /// An event payload didn't match the expected shape for its event type.
+    #[error("failed to parse stream payload: {0}")]
+    JsonParse(#[from] serde_json::Error),
+    /// The event's `"e"` field wasn't a recognized event type.
+    #[error("received an event with an unrecognized type: {0:?}")]
+    UnknownEventType(String),
+}
+
+/// The fields common to every user data stream event, used to dispatch on `event_type` before
+/// parsing the rest of the payload.
+#[derive(Debug, Deserialize)]
+struct EventEnvelope {
+    #[serde(rename = "e")]
+    event_type: String,
+}
+
+/// Connects to the user data stream for `listen_key` and returns a `Stream` of parsed events,
+/// in the order received.
+///
+/// # Errors
+///
+/// Returns `UserStreamError::WebSocket` if the initial connection fails.
+pub async fn connect(
+    listen_key: &str,
+) -> Result<impl Stream<Item = Result<UserStreamEvent, UserStreamError>>, UserStreamError> {
+    let url = format!("{WS_BASE_URL}/{listen_key}");
+
+    let (ws_stream, _response) = tokio_tungstenite::connect_async(url)
+        .await
+        .map_err(Box::new)?;
+    Ok(ws_stream.filter_map(|message| async move {
+        match message {
+            Ok(Message::Text(text)) => Some(parse_event(&text)),
+            Ok(_) => None,
+            Err(error) => Some(Err(UserStreamError::from(Box::new(error)))),
+        }
+    }))
+}
+
+fn parse_event(text: &str) -> Result<UserStreamEvent, UserStreamError> {
+    let envelope: EventEnvelope = serde_json::from_str(text)?;
+    match envelope.event_type.as_str() {
+        "ACCOUNT_UPDATE" => Ok(UserStreamEvent::AccountUpdate(serde_json::from_str(text)?)),
+        "ORDER_TRADE_UPDATE" => Ok(UserStreamEvent::OrderUpdate(serde_json::from_str(text)?)),
+        other => Err(UserStreamError::UnknownEventType(other.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_event_dispatches_an_account_update() {
+        let text = r#"{"e": "ACCOUNT_UPDATE", "E": 1690000000000}"#;
+        assert!(matches!(
+            parse_event(text),
+            Ok(UserStreamEvent::AccountUpdate(_))
+        ));
+    }
+
+    #[test]
+    fn parse_event_dispatches_an_order_update() {
+        let text = r#"{
+            "e": "ORDER_TRADE_UPDATE",
+            "E": 1690000000000,
+            "o": {
+                "s": "BTC-200730-9000-C",
+                "i": 1,
+                "S": "BUY",
+                "o": "LIMIT",
+                "f": "GTC",
+                "X": "FILLED",
+                "p": "100",
+                "q": "1"
+            }
+        }"#;
+
+        let event = parse_event(text).unwrap();
+        assert!(matches!(
+            event,
+            UserStreamEvent::OrderUpdate(ref update) if update.order.status == "FILLED"
+        ));
+    }
+
+    #[test]
+    fn parse_event_rejects_an_unrecognized_event_type() {
+        let text = r#"{"e": "LISTEN_KEY_EXPIRED"}"#;
+        assert!(matches!(
+            parse_event(text),
+            Err(UserStreamError::UnknownEventType(_))
+        ));
+    }
+}
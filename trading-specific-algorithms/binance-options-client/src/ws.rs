@@ -0,0 +1,439 @@
+//! WebSocket market data streams (`wss://nbstream.binance.com/eoptions`) for ticker, trade, and
+//! kline updates per symbol. Polling the REST API for the ~1400 listed option tickers is
+//! wasteful and far too slow for trading; this module delivers parsed events over an async
+//! `Stream` as they arrive instead.
+
+use futures_util::stream::{SplitStream, Stream};
+use futures_util::{SinkExt, StreamExt};
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+
+const WS_BASE_URL: &str = "wss://nbstream.binance.com/eoptions";
+
+/// Binance sends a server ping roughly every 3 minutes and disconnects a connection that hasn't
+/// replied within 10 minutes, so a client that never pings still stays alive as long as it keeps
+/// reading — these defaults only matter if the network silently drops without a clean close.
+const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(180);
+const DEFAULT_STALENESS_THRESHOLD: Duration = Duration::from_secs(600);
+
+/// A single market-data stream to subscribe to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StreamKind {
+    /// 24hr ticker statistics for `symbol`.
+    Ticker(String),
+    /// Individual trade events for `symbol`.
+    Trade(String),
+    /// Candlestick updates for `symbol` at `interval` (e.g. `"1m"`, `"1h"`).
+    Kline {
+        /// The option symbol, e.g. `"BTC-200730-9000-C"`.
+        symbol: String,
+        /// The candlestick interval, e.g. `"1m"`.
+        interval: String,
+    },
+}
+
+impl StreamKind {
+    /// Returns this stream's name as used in the combined-stream path, e.g.
+    /// `"btc-200730-9000-c@ticker"`.
+    fn stream_name(&self) -> String {
+        match self {
+            StreamKind::Ticker(symbol) => format!("{}@ticker", symbol.to_lowercase()),
+            StreamKind::Trade(symbol) => format!("{}@trade", symbol.to_lowercase()),
+            StreamKind::Kline { symbol, interval } => {
+                format!("{}@kline_{interval}", symbol.to_lowercase())
+            }
+        }
+    }
+}
+
+/// A 24hr ticker update delivered over the `@ticker` stream.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TickerEvent {
+    /// Event time, epoch milliseconds.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// The option symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Opening price.
+    #[serde(rename = "o")]
+    pub open: String,
+    /// Highest price.
+    #[serde(rename = "h")]
+    pub high: String,
+    /// Lowest price.
+    #[serde(rename = "l")]
+    pub low: String,
+    /// Last traded price.
+    #[serde(rename = "c")]
+    pub last_price: String,
+    /// Trading volume.
+    #[serde(rename = "V")]
+    pub volume: String,
+    /// Best bid price.
+    #[serde(rename = "b")]
+    pub bid_price: String,
+    /// Best ask price.
+    #[serde(rename = "a")]
+    pub ask_price: String,
+}
+
+/// A trade event delivered over the `@trade` stream.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct TradeEvent {
+    /// Event time, epoch milliseconds.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// The option symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// Trade price.
+    #[serde(rename = "p")]
+    pub price: String,
+    /// Trade quantity.
+    #[serde(rename = "q")]
+    pub quantity: String,
+    /// Trade time, epoch milliseconds.
+    #[serde(rename = "T")]
+    pub trade_time: i64,
+    /// The trade's initiating side, `"BUY"` or `"SELL"`.
+    #[serde(rename = "S")]
+    pub side: String,
+}
+
+/// A candlestick payload nested inside a `@kline_*` stream event.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct Kline {
+    /// Candle open time, epoch milliseconds.
+    #[serde(rename = "t")]
+    pub open_time: i64,
+    /// Candle close time, epoch milliseconds.
+    #[serde(rename = "T")]
+    pub close_time: i64,
+    /// The candlestick interval, e.g. `"1m"`.
+    #[serde(rename = "i")]
+    pub interval: String,
+    /// Opening price.
+    #[serde(rename = "o")]
+    pub open: String,
+    /// Closing price (the latest price for an unclosed candle).
+    #[serde(rename = "c")]
+    pub close: String,
+    /// Highest price.
+    #[serde(rename = "h")]
+    pub high: String,
+    /// Lowest price.
+    #[serde(rename = "l")]
+    pub low: String,
+    /// Trading volume.
+    #[serde(rename = "v")]
+    pub volume: String,
+}
+
+/// A candlestick update delivered over the `@kline_*` stream.
+#[derive(Debug, Clone, Deserialize, PartialEq)]
+pub struct KlineEvent {
+    /// Event time, epoch milliseconds.
+    #[serde(rename = "E")]
+    pub event_time: i64,
+    /// The option symbol.
+    #[serde(rename = "s")]
+    pub symbol: String,
+    /// The candlestick payload.
+    #[serde(rename = "k")]
+    pub kline: Kline,
+}
+
+/// A parsed market-data event from any stream passed to [`connect`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarketEvent {
+    /// A 24hr ticker update.
+    Ticker(TickerEvent),
+    /// A trade.
+    Trade(TradeEvent),
+    /// A candlestick update.
+    Kline(KlineEvent),
+}
+
+/// Error returned while connecting to or reading from a market-data stream.
+#[derive(Debug, thiserror::Error)]
+pub enum WsError {
+    /// The WebSocket connection failed or was closed unexpectedly.
+    #[error("WebSocket error: {0}")]
+    WebSocket(#[from] Box<tokio_tungstenite::tungstenite::Error>),
+    /// A stream payload didn't match the expected shape for its stream type.
+    #[error("failed to parse stream payload: {0}")]
+    JsonParse(#[from] serde_json::Error),
+    /// The combined-stream envelope named a stream this module doesn't recognize.
+    #[error("received an event for an unrecognized stream: {0:?}")]
+    UnknownStream(String),
+    /// No message (including a server ping) arrived within the configured staleness threshold,
+    /// meaning the connection is presumed dead even though it was never cleanly closed.
+    #[error("no message received for {0:?}; connection presumed dead")]
+    StaleConnection(Duration),
+}
+
+/// The envelope Binance's combined-stream endpoint wraps every event in:
+/// `{"stream": "<name>", "data": <payload>}`.
+#[derive(Debug, Deserialize)]
+struct StreamEnvelope {
+    stream: String,
+    data: serde_json::Value,
+}
+
+/// Connects to `streams` over a single WebSocket connection (using the combined-stream
+/// endpoint) and returns a `Stream` of parsed events, in the order received.
+///
+/// # Errors
+///
+/// Returns `WsError::WebSocket` if the initial connection fails.
+pub async fn connect(
+    streams: &[StreamKind],
+) -> Result<impl Stream<Item = Result<MarketEvent, WsError>> + use<>, WsError> {
+    let names: Vec<String> = streams.iter().map(StreamKind::stream_name).collect();
+    let url = format!("{WS_BASE_URL}/stream?streams={}", names.join("/"));
+
+    let (ws_stream, _response) = tokio_tungstenite::connect_async(url)
+        .await
+        .map_err(Box::new)?;
+    Ok(ws_stream.filter_map(|message| async move {
+        match message {
+            Ok(Message::Text(text)) => Some(parse_event(&text)),
+            Ok(_) => None,
+            Err(error) => Some(Err(WsError::from(Box::new(error)))),
+        }
+    }))
+}
+
+/// Configures client-side liveness handling for [`connect_with_liveness`].
+#[derive(Debug, Clone, Copy)]
+pub struct LivenessConfig {
+    /// How often to send a client-initiated `Ping` frame.
+    pub ping_interval: Duration,
+    /// How long to wait for *any* message (a data event, a server ping, or our own ping's pong)
+    /// before treating the connection as dead.
+    pub staleness_threshold: Duration,
+}
+
+impl LivenessConfig {
+    /// Overrides the client ping interval.
+    pub fn ping_interval(mut self, ping_interval: Duration) -> Self {
+        self.ping_interval = ping_interval;
+        self
+    }
+
+    /// Overrides the staleness threshold.
+    pub fn staleness_threshold(mut self, staleness_threshold: Duration) -> Self {
+        self.staleness_threshold = staleness_threshold;
+        self
+    }
+}
+
+impl Default for LivenessConfig {
+    /// A 3 minute ping interval and a 10 minute staleness threshold, matching Binance's own
+    /// server-ping cadence and disconnect grace period.
+    fn default() -> Self {
+        Self {
+            ping_interval: DEFAULT_PING_INTERVAL,
+            staleness_threshold: DEFAULT_STALENESS_THRESHOLD,
+        }
+    }
+}
+
+type RawStream = SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>;
+
+/// Tracks whether the underlying connection is still believed to be alive, so a dead connection
+/// reported once doesn't keep being polled afterwards.
+enum LivenessState {
+    Alive(RawStream),
+    Dead,
+}
+
+/// Like [`connect`], but also sends periodic client `Ping` frames (server `Ping`s are answered
+/// with a `Pong` automatically by the underlying WebSocket implementation) and watches for
+/// staleness: if no message of any kind arrives within `config.staleness_threshold`, the stream
+/// yields a single `WsError::StaleConnection` and ends, so a caller like [`crate::ws_supervisor`]
+/// knows to reconnect rather than waiting forever on a connection the network silently dropped.
+///
+/// # Errors
+///
+/// Returns `WsError::WebSocket` if the initial connection fails.
+pub async fn connect_with_liveness(
+    streams: &[StreamKind],
+    config: LivenessConfig,
+) -> Result<impl Stream<Item = Result<MarketEvent, WsError>> + use<>, WsError> {
+    let names: Vec<String> = streams.iter().map(StreamKind::stream_name).collect();
+    let url = format!("{WS_BASE_URL}/stream?streams={}", names.join("/"));
+
+    let (ws_stream, _response) = tokio_tungstenite::connect_async(url)
+        .await
+        .map_err(Box::new)?;
+    let (mut sink, stream) = ws_stream.split();
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(config.ping_interval);
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            if sink.send(Message::Ping(Vec::new())).await.is_err() {
+                return;
+            }
+        }
+    });
+
+    let staleness_threshold = config.staleness_threshold;
+    Ok(futures_util::stream::unfold(
+        LivenessState::Alive(stream),
+        move |state| async move {
+            let mut stream = match state {
+                LivenessState::Dead => return None,
+                LivenessState::Alive(stream) => stream,
+            };
+            loop {
+                return match tokio::time::timeout(staleness_threshold, stream.next()).await {
+                    Ok(Some(Ok(Message::Text(text)))) => {
+                        Some((parse_event(&text), LivenessState::Alive(stream)))
+                    }
+                    Ok(Some(Ok(_))) => continue,
+                    Ok(Some(Err(error))) => {
+                        Some((Err(WsError::from(Box::new(error))), LivenessState::Dead))
+                    }
+                    Ok(None) => None,
+                    Err(_elapsed) => Some((
+                        Err(WsError::StaleConnection(staleness_threshold)),
+                        LivenessState::Dead,
+                    )),
+                };
+            }
+        },
+    ))
+}
+
+fn parse_event(text: &str) -> Result<MarketEvent, WsError> {
+    let envelope: StreamEnvelope = serde_json::from_str(text)?;
+    if envelope.stream.ends_with("@ticker") {
+        Ok(MarketEvent::Ticker(serde_json::from_value(envelope.data)?))
+    } else if envelope.stream.ends_with("@trade") {
+        Ok(MarketEvent::Trade(serde_json::from_value(envelope.data)?))
+    } else if envelope.stream.contains("@kline") {
+        Ok(MarketEvent::Kline(serde_json::from_value(envelope.data)?))
+    } else {
+        Err(WsError::UnknownStream(envelope.stream))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_liveness_config_matches_binances_ping_cadence() {
+        let config = LivenessConfig::default();
+        assert_eq!(config.ping_interval, Duration::from_secs(180));
+        assert_eq!(config.staleness_threshold, Duration::from_secs(600));
+    }
+
+    #[test]
+    fn liveness_config_builder_overrides_defaults() {
+        let config = LivenessConfig::default()
+            .ping_interval(Duration::from_secs(30))
+            .staleness_threshold(Duration::from_secs(90));
+        assert_eq!(config.ping_interval, Duration::from_secs(30));
+        assert_eq!(config.staleness_threshold, Duration::from_secs(90));
+    }
+
+    #[test]
+    fn stream_name_lowercases_the_symbol() {
+        assert_eq!(
+            StreamKind::Ticker("BTC-200730-9000-C".to_string()).stream_name(),
+            "btc-200730-9000-c@ticker"
+        );
+        assert_eq!(
+            StreamKind::Trade("BTC-200730-9000-C".to_string()).stream_name(),
+            "btc-200730-9000-c@trade"
+        );
+        assert_eq!(
+            StreamKind::Kline {
+                symbol: "BTC-200730-9000-C".to_string(),
+                interval: "1m".to_string(),
+            }
+            .stream_name(),
+            "btc-200730-9000-c@kline_1m"
+        );
+    }
+
+    #[test]
+    fn parse_event_dispatches_a_ticker_payload() {
+        let text = r#"{
+            "stream": "btc-200730-9000-c@ticker",
+            "data": {
+                "E": 1690000000000,
+                "s": "BTC-200730-9000-C",
+                "o": "100",
+                "h": "110",
+                "l": "90",
+                "c": "105",
+                "V": "42",
+                "b": "104",
+                "a": "106"
+            }
+        }"#;
+
+        let event = parse_event(text).unwrap();
+        assert!(matches!(event, MarketEvent::Ticker(ref t) if t.symbol == "BTC-200730-9000-C"));
+    }
+
+    #[test]
+    fn parse_event_dispatches_a_trade_payload() {
+        let text = r#"{
+            "stream": "btc-200730-9000-c@trade",
+            "data": {
+                "E": 1690000000000,
+                "s": "BTC-200730-9000-C",
+                "p": "105",
+                "q": "1",
+                "T": 1690000000001,
+                "S": "BUY"
+            }
+        }"#;
+
+        let event = parse_event(text).unwrap();
+        assert!(matches!(event, MarketEvent::Trade(ref t) if t.side == "BUY"));
+    }
+
+    #[test]
+    fn parse_event_dispatches_a_kline_payload() {
+        let text = r#"{
+            "stream": "btc-200730-9000-c@kline_1m",
+            "data": {
+                "E": 1690000000000,
+                "s": "BTC-200730-9000-C",
+                "k": {
+                    "t": 1690000000000,
+                    "T": 1690000059999,
+                    "i": "1m",
+                    "o": "100",
+                    "c": "105",
+                    "h": "110",
+                    "l": "90",
+                    "v": "42"
+                }
+            }
+        }"#;
+
+        let event = parse_event(text).unwrap();
+        assert!(matches!(event, MarketEvent::Kline(ref k) if k.kline.interval == "1m"));
+    }
+
+    #[test]
+    fn parse_event_rejects_an_unrecognized_stream() {
+        let text = r#"{"stream": "btc-200730-9000-c@depth", "data": {}}"#;
+        assert!(matches!(
+            parse_event(text),
+            Err(WsError::UnknownStream(_))
+        ));
+    }
+}
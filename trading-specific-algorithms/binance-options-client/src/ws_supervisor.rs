@@ -0,0 +1,119 @@
+//! A reconnecting supervision layer over [`crate::ws`]. A bare [`ws::connect`] stream ends (or
+//! errors) the moment the underlying TCP/TLS connection drops, which happens routinely —
+//! Binance closes WebSocket connections after 24 hours, and the network in between isn't always
+//! reliable. [`Supervisor`] wraps the raw stream, reconnecting with backoff and resubscribing to
+//! the same set of streams each time, and surfaces a [`SupervisedEvent::Gap`] marker whenever a
+//! reconnect happens so consumers relying on sequenced data (e.g. [`crate::depth`]) know they
+//! must re-snapshot rather than assume continuity.
+
+use crate::retry::RetryPolicy;
+use crate::ws::{self, MarketEvent, StreamKind, WsError};
+use futures_util::stream::{self, Stream, StreamExt};
+use std::pin::Pin;
+
+/// An event delivered by a supervised stream.
+#[derive(Debug)]
+pub enum SupervisedEvent {
+    /// A market-data event from the underlying stream.
+    Market(MarketEvent),
+    /// The connection was lost and has been (or is being) reconnected. Any state derived from
+    /// the stream's prior sequencing (e.g. a [`crate::depth::ManagedOrderBook`]) should be
+    /// rebuilt from a fresh REST snapshot.
+    Gap,
+}
+
+type InnerStream = Pin<Box<dyn Stream<Item = Result<MarketEvent, WsError>> + Send>>;
+
+enum SupervisorState {
+    Disconnected { attempt: u32 },
+    Connected { inner: InnerStream },
+}
+
+/// Supervises a WebSocket connection to a fixed set of streams, reconnecting with backoff and
+/// resubscribing on every drop.
+pub struct Supervisor {
+    streams: Vec<StreamKind>,
+    retry_policy: RetryPolicy,
+}
+
+impl Supervisor {
+    /// Creates a supervisor for `streams`, using the default retry policy.
+    pub fn new(streams: Vec<StreamKind>) -> Self {
+        Self {
+            streams,
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Overrides the backoff policy used between reconnect attempts.
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Runs the supervisor, returning a `Stream` that never ends on its own: a dropped or
+    /// errored connection is reconnected with backoff and silently replaced, after yielding a
+    /// single [`SupervisedEvent::Gap`].
+    pub fn run(self) -> impl Stream<Item = SupervisedEvent> {
+        let Supervisor {
+            streams,
+            retry_policy,
+        } = self;
+
+        stream::unfold(SupervisorState::Disconnected { attempt: 0 }, move |mut state| {
+            let streams = streams.clone();
+            async move {
+                loop {
+                    state = match state {
+                        SupervisorState::Disconnected { attempt } => {
+                            if attempt > 0 {
+                                let delay = retry_policy.delay_for_attempt(attempt, None);
+                                tokio::time::sleep(delay).await;
+                            }
+                            match ws::connect(&streams).await {
+                                Ok(inner) => SupervisorState::Connected {
+                                    inner: Box::pin(inner),
+                                },
+                                Err(_) => SupervisorState::Disconnected { attempt: attempt + 1 },
+                            }
+                        }
+                        SupervisorState::Connected { mut inner } => match inner.next().await {
+                            Some(Ok(event)) => {
+                                return Some((
+                                    SupervisedEvent::Market(event),
+                                    SupervisorState::Connected { inner },
+                                ));
+                            }
+                            Some(Err(_)) | None => {
+                                return Some((
+                                    SupervisedEvent::Gap,
+                                    SupervisorState::Disconnected { attempt: 1 },
+                                ));
+                            }
+                        },
+                    };
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn new_supervisor_uses_the_default_retry_policy() {
+        let supervisor = Supervisor::new(vec![StreamKind::Ticker("BTCUSDT".to_string())]);
+        assert_eq!(supervisor.retry_policy.max_attempts, RetryPolicy::default().max_attempts);
+    }
+
+    #[test]
+    fn retry_policy_overrides_the_default() {
+        let supervisor = Supervisor::new(vec![StreamKind::Ticker("BTCUSDT".to_string())])
+            .retry_policy(RetryPolicy::new(10).base_delay(Duration::from_millis(5)));
+        assert_eq!(supervisor.retry_policy.max_attempts, 10);
+        assert_eq!(supervisor.retry_policy.base_delay, Duration::from_millis(5));
+    }
+}
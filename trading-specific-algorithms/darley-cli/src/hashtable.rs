@@ -0,0 +1,55 @@
+//! Wraps `hash-table` for an ad-hoc insert/get timing check, without pulling in criterion and
+//! its harness the way `hash-table/benches/benchmarks.rs` does for proper statistical
+//! benchmarking. This is a quick, single-run sanity check, not a substitute for that.
+
+use std::time::Instant;
+
+use clap::Subcommand;
+use hash_table::{HashTable, LinkedHashTable};
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Time inserting and then looking up `count` distinct keys
+    Bench {
+        /// Number of distinct keys to insert
+        #[arg(long, default_value_t = 100_000)]
+        count: usize,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum HashtableCliError {
+    #[error("count must be greater than zero")]
+    ZeroCount,
+}
+
+pub fn run(command: Command) -> Result<(), HashtableCliError> {
+    match command {
+        Command::Bench { count } => bench(count),
+    }
+}
+
+fn bench(count: usize) -> Result<(), HashtableCliError> {
+    if count == 0 {
+        return Err(HashtableCliError::ZeroCount);
+    }
+
+    let mut table: LinkedHashTable<String, usize> = LinkedHashTable::new(count);
+
+    let insert_start = Instant::now();
+    for i in 0..count {
+        table.insert(format!("key{i}"), i);
+    }
+    let insert_elapsed = insert_start.elapsed();
+
+    let get_start = Instant::now();
+    for i in 0..count {
+        table.get(&format!("key{i}"));
+    }
+    let get_elapsed = get_start.elapsed();
+
+    println!("inserted {count} keys in {insert_elapsed:?} ({:?}/key)", insert_elapsed / count as u32);
+    println!("looked up {count} keys in {get_elapsed:?} ({:?}/key)", get_elapsed / count as u32);
+
+    Ok(())
+}
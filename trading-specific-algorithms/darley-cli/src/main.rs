@@ -0,0 +1,53 @@
+//! `darley`: a single installable entry point over this workspace's assessment crates.
+//!
+//! Each subcommand wraps an existing crate rather than reimplementing its logic:
+//! - `wordfreq analyze` / `wordfreq compare` call `word-frequency` directly.
+//! - `options tickers` / `options chain` call `binance-options-client` directly.
+//! - `hashtable bench` calls `hash-table` directly.
+
+mod hashtable;
+mod options;
+mod wordfreq;
+
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+#[derive(Parser, Debug)]
+#[command(name = "darley", version, about = "Unified CLI over the assessment workspace crates")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Word frequency analysis, via `word-frequency`
+    #[command(subcommand)]
+    Wordfreq(wordfreq::Command),
+    /// Binance options market data, via `binance-options-client`
+    #[command(subcommand)]
+    Options(options::Command),
+    /// `hash-table` micro-benchmarks
+    #[command(subcommand)]
+    Hashtable(hashtable::Command),
+}
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result: Result<(), Box<dyn std::error::Error>> = match cli.command {
+        Command::Wordfreq(command) => wordfreq::run(command).map_err(Into::into),
+        Command::Options(command) => options::run(command).await.map_err(Into::into),
+        Command::Hashtable(command) => hashtable::run(command).map_err(Into::into),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        }
+    }
+}
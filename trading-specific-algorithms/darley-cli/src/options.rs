@@ -0,0 +1,88 @@
+//! Wraps `binance-options-client` for quick one-shot lookups from the command line.
+
+use binance_options_client::{BinanceOptionsClient, BinanceOptionsClientError, OptionsChain};
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Fetch and print the ticker list (or a single symbol's ticker)
+    Tickers {
+        /// Restrict to a single symbol (e.g. `BTC-250307-90000-C`); omit for the full list
+        symbol: Option<String>,
+    },
+    /// Build an options chain from the current ticker list and print one expiry's strikes
+    Chain {
+        /// Underlying asset, e.g. `BTC`
+        underlying: String,
+        /// Which expiry to print, by index into the underlying's expiries (chronological,
+        /// 0-based); defaults to the nearest expiry
+        #[arg(long)]
+        expiry_index: Option<usize>,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum OptionsCliError {
+    #[error("failed to build Binance options client: {0}")]
+    ClientBuild(#[source] BinanceOptionsClientError),
+    #[error("failed to fetch ticker data: {0}")]
+    Fetch(#[source] BinanceOptionsClientError),
+    #[error("failed to build options chain: {0}")]
+    Chain(#[source] binance_options_client::OptionSymbolParseError),
+    #[error("no quoted expiries found for underlying {0}")]
+    NoExpiries(String),
+}
+
+pub async fn run(command: Command) -> Result<(), OptionsCliError> {
+    let client = BinanceOptionsClient::builder()
+        .build()
+        .map_err(OptionsCliError::ClientBuild)?;
+
+    match command {
+        Command::Tickers { symbol } => print_tickers(&client, symbol.as_deref()).await,
+        Command::Chain { underlying, expiry_index } => {
+            print_chain(&client, &underlying, expiry_index).await
+        }
+    }
+}
+
+async fn print_tickers(client: &BinanceOptionsClient, symbol: Option<&str>) -> Result<(), OptionsCliError> {
+    let tickers = client.get_ticker(symbol).await.map_err(OptionsCliError::Fetch)?;
+
+    println!("{:<24} {:>12} {:>12} {:>12}", "SYMBOL", "LAST", "BID", "ASK");
+    for ticker in &tickers {
+        println!(
+            "{:<24} {:>12} {:>12} {:>12}",
+            ticker.symbol, ticker.last_price, ticker.bid_price, ticker.ask_price
+        );
+    }
+
+    Ok(())
+}
+
+async fn print_chain(
+    client: &BinanceOptionsClient,
+    underlying: &str,
+    expiry_index: Option<usize>,
+) -> Result<(), OptionsCliError> {
+    let tickers = client.get_ticker(None).await.map_err(OptionsCliError::Fetch)?;
+    let chain = OptionsChain::build(tickers).map_err(OptionsCliError::Chain)?;
+
+    let expiries = chain.expiries(underlying);
+    let expiry = match expiry_index {
+        Some(index) => expiries.get(index).copied(),
+        None => chain.nearest_expiry(underlying),
+    }
+    .ok_or_else(|| OptionsCliError::NoExpiries(underlying.to_string()))?;
+
+    let (year, month, day) = expiry;
+    println!("{underlying} chain — expiry {year:04}-{month:02}-{day:02}");
+    println!("{:>14} {:<24} {:<24}", "STRIKE", "CALL", "PUT");
+    for (strike, pair) in chain.strikes(underlying, expiry) {
+        let call = pair.call.as_ref().map_or("-".to_string(), |t| t.last_price.clone());
+        let put = pair.put.as_ref().map_or("-".to_string(), |t| t.last_price.clone());
+        println!("{:>14} {:<24} {:<24}", strike, call, put);
+    }
+
+    Ok(())
+}
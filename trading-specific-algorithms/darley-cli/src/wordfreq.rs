@@ -0,0 +1,123 @@
+//! Wraps `word-frequency` for quick one-shot frequency reports, the way [`crate::options`]
+//! wraps `binance-options-client` and [`crate::hashtable`] wraps `hash-table`.
+//!
+//! `word-frequency` used to be a binary-only crate, so this used to shell out to the installed
+//! binary and forward its exit code. It has since grown a library target
+//! (`build_frequency_table`/`compute_frequency_stats`), so this calls into that directly instead
+//! of depending on a separately-installed binary being on `PATH`. It doesn't reimplement the
+//! binary's full CLI surface — config files, result caching, colorized/chart output stay specific
+//! to being a command-line tool, per `word-frequency`'s own `lib.rs` doc comment.
+
+use clap::Subcommand;
+use word_frequency::build_frequency_table;
+use word_processor::{WordProcessorConfig, WordProcessorError, io, parse_text};
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Count word frequencies in a single input (file path or URL)
+    Analyze {
+        /// Input source: file path or URL
+        input: String,
+        /// Number of entries to show in the frequency report
+        #[arg(long, default_value_t = 20)]
+        top: usize,
+    },
+    /// Compare word frequencies between two inputs
+    Compare {
+        /// First input source: file path or URL
+        first: String,
+        /// Second input source: file path or URL
+        second: String,
+        /// Number of biggest relative-frequency differences to show
+        #[arg(long, default_value_t = 20)]
+        top: usize,
+    },
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WordfreqError {
+    #[error("failed to read {0}: {1}")]
+    Read(String, #[source] WordProcessorError),
+    #[error("failed to tokenize {0}: {1}")]
+    Tokenize(String, #[source] WordProcessorError),
+}
+
+pub fn run(command: Command) -> Result<(), WordfreqError> {
+    match command {
+        Command::Analyze { input, top } => analyze(&input, top),
+        Command::Compare { first, second, top } => compare(&first, &second, top),
+    }
+}
+
+fn read_words(source: &str) -> Result<Vec<String>, WordfreqError> {
+    let text = io::read_from_source(source).map_err(|err| WordfreqError::Read(source.to_string(), err))?;
+    let config = WordProcessorConfig::default();
+    parse_text(&text, &config).map_err(|err| WordfreqError::Tokenize(source.to_string(), err))
+}
+
+fn analyze(input: &str, top: usize) -> Result<(), WordfreqError> {
+    let words = read_words(input)?;
+    let table = build_frequency_table(&words);
+
+    println!("{} words, {} unique", words.len(), table.len());
+    for (rank, (word, count)) in table.iter().take(top).enumerate() {
+        println!("{:>3}. {:<24} {}", rank + 1, word, count);
+    }
+
+    Ok(())
+}
+
+fn compare(first: &str, second: &str, top: usize) -> Result<(), WordfreqError> {
+    let first_words = read_words(first)?;
+    let second_words = read_words(second)?;
+
+    let first_table = build_frequency_table(&first_words);
+    let second_table = build_frequency_table(&second_words);
+
+    let first_counts: std::collections::HashMap<&str, usize> =
+        first_table.iter().map(|(word, count)| (word.as_str(), *count)).collect();
+    let second_counts: std::collections::HashMap<&str, usize> =
+        second_table.iter().map(|(word, count)| (word.as_str(), *count)).collect();
+
+    let mut unique_to_first: Vec<&str> =
+        first_counts.keys().filter(|word| !second_counts.contains_key(*word)).copied().collect();
+    unique_to_first.sort_unstable();
+
+    let mut unique_to_second: Vec<&str> =
+        second_counts.keys().filter(|word| !first_counts.contains_key(*word)).copied().collect();
+    unique_to_second.sort_unstable();
+
+    let mut shared: Vec<&str> =
+        first_counts.keys().filter(|word| second_counts.contains_key(*word)).copied().collect();
+    shared.sort_unstable();
+
+    println!("Comparing '{first}' and '{second}'");
+    println!(
+        "\nVocabulary: {} words only in '{first}', {} words only in '{second}', {} shared",
+        unique_to_first.len(),
+        unique_to_second.len(),
+        shared.len()
+    );
+
+    // Relative frequency (per 1000 words) makes differing document lengths comparable.
+    let first_total = first_words.len().max(1) as f64;
+    let second_total = second_words.len().max(1) as f64;
+
+    let mut differences: Vec<(&str, f64, f64, f64)> = shared
+        .iter()
+        .map(|&word| {
+            let first_rate = first_counts[word] as f64 / first_total * 1000.0;
+            let second_rate = second_counts[word] as f64 / second_total * 1000.0;
+            (word, first_rate, second_rate, (first_rate - second_rate).abs())
+        })
+        .collect();
+    differences.sort_by(|a, b| b.3.partial_cmp(&a.3).unwrap_or(std::cmp::Ordering::Equal));
+
+    println!("\nBiggest relative frequency differences (per 1000 words):");
+    let word_width = differences.iter().take(top).map(|(word, ..)| word.chars().count()).max().unwrap_or(0);
+    for (rank, (word, first_rate, second_rate, _)) in differences.iter().take(top).enumerate() {
+        println!("  {}. {:<word_width$} {:.2} vs {:.2}", rank + 1, word, first_rate, second_rate);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,147 @@
+//! A shared error type for binaries that compose multiple crates from this workspace (e.g.
+//! `analysis-service`, which calls into both `word-processor` and `binance-options-client`)
+//! so they don't have to hand-roll a local enum wrapping every crate-specific error type they
+//! touch.
+//!
+//! [`AppError`] wraps a source crate's own error behind a stable [`AppError::code`] (for
+//! logging or metrics labels) and an optional chain of human-readable context, attached with
+//! [`ResultExt::context`] as the error propagates up through call sites. Future hash-table
+//! errors should be added here the same way: a new [`ErrorKind`] variant and a `From` impl.
+
+use std::error::Error as StdError;
+use std::fmt;
+
+/// The crate-specific error wrapped by an [`AppError`].
+#[derive(Debug)]
+enum ErrorKind {
+    WordProcessor(word_processor::WordProcessorError),
+    BinanceOptionsClient(binance_options_client::BinanceOptionsClientError),
+}
+
+impl ErrorKind {
+    fn code(&self) -> &'static str {
+        match self {
+            Self::WordProcessor(_) => "word_processor",
+            Self::BinanceOptionsClient(_) => "binance_options_client",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WordProcessor(err) => write!(f, "{err}"),
+            Self::BinanceOptionsClient(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl StdError for ErrorKind {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            Self::WordProcessor(err) => Some(err),
+            Self::BinanceOptionsClient(err) => Some(err),
+        }
+    }
+}
+
+/// A workspace-wide error, wrapping a source crate's own error behind a stable [`code`] and,
+/// optionally, a chain of context describing what the caller was doing when it failed.
+///
+/// [`code`]: AppError::code
+#[derive(Debug)]
+pub struct AppError {
+    kind: ErrorKind,
+    /// Innermost-first: `context[0]` was attached closest to where the error originated.
+    context: Vec<String>,
+}
+
+impl AppError {
+    /// A short, stable identifier for this error's underlying crate, suitable for logging or
+    /// metrics labels (e.g. `"word_processor"`, `"binance_options_client"`).
+    pub fn code(&self) -> &'static str {
+        self.kind.code()
+    }
+
+    /// Adds a line of human-readable context describing what the caller was doing when this
+    /// error occurred. Prefer [`ResultExt::context`] at call sites instead of calling this
+    /// directly.
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context.push(context.into());
+        self
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for ctx in self.context.iter().rev() {
+            write!(f, "{ctx}: ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+impl StdError for AppError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        Some(&self.kind)
+    }
+}
+
+impl From<word_processor::WordProcessorError> for AppError {
+    fn from(err: word_processor::WordProcessorError) -> Self {
+        Self { kind: ErrorKind::WordProcessor(err), context: Vec::new() }
+    }
+}
+
+impl From<binance_options_client::BinanceOptionsClientError> for AppError {
+    fn from(err: binance_options_client::BinanceOptionsClientError) -> Self {
+        Self { kind: ErrorKind::BinanceOptionsClient(err), context: Vec::new() }
+    }
+}
+
+/// Lets call sites attach context to any `Result` whose error converts into [`AppError`]:
+/// `do_thing().context("doing the thing")?` instead of matching and wrapping by hand.
+pub trait ResultExt<T> {
+    /// Converts the error (if any) into an [`AppError`] and attaches `context` to it.
+    fn context(self, context: impl Into<String>) -> Result<T, AppError>;
+}
+
+impl<T, E> ResultExt<T> for Result<T, E>
+where
+    E: Into<AppError>,
+{
+    fn context(self, context: impl Into<String>) -> Result<T, AppError> {
+        self.map_err(|err| err.into().context(context))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use word_processor::error::CapacityError;
+
+    fn word_processor_error() -> word_processor::WordProcessorError {
+        CapacityError::EmptyText.into()
+    }
+
+    #[test]
+    fn a_word_processor_error_converts_with_the_right_code() {
+        let err: AppError = word_processor_error().into();
+        assert_eq!(err.code(), "word_processor");
+    }
+
+    #[test]
+    fn context_is_displayed_innermost_first() {
+        let err: Result<(), _> = Err(word_processor_error());
+        let err = err.context("estimating capacity").unwrap_err();
+        let err = Err::<(), AppError>(err).context("analyzing book.txt").unwrap_err();
+
+        assert_eq!(err.to_string(), "analyzing book.txt: estimating capacity: Capacity error: Cannot estimate capacity from empty text");
+    }
+
+    #[test]
+    fn the_underlying_error_is_reachable_via_source() {
+        let err: AppError = word_processor_error().into();
+        assert!(err.source().is_some());
+    }
+}
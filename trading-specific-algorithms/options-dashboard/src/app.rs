@@ -0,0 +1,197 @@
+//! Dashboard state: the latest poll's data, plus what the user currently has selected.
+
+use binance_options_client::{OptionTicker, OptionsChain};
+
+use crate::store::TickerBoard;
+
+/// How many distinct symbols [`App::board`] tracks before it starts dropping updates for new
+/// ones. Binance's live option chain runs to roughly 1400 entries; this leaves headroom.
+const BOARD_CAPACITY: usize = 2048;
+
+/// Top-level dashboard state, rebuilt incrementally as each poll tick's tickers arrive.
+pub struct App {
+    /// Per-symbol latest ticker, used for the recency panel.
+    pub board: TickerBoard,
+    /// The most recently fetched batch, organized into a chain for the selected-underlying view.
+    pub chain: OptionsChain,
+    /// Distinct underlyings seen in the most recent batch, sorted.
+    pub underlyings: Vec<String>,
+    /// Index into `underlyings` of the chain view currently on screen.
+    pub underlying_index: usize,
+    /// Index into that underlying's expiries of the chain view currently on screen.
+    pub expiry_index: usize,
+    /// The most recent poll or parse failure, shown in the status line until the next success.
+    pub last_error: Option<String>,
+    /// Set once the user asks to quit.
+    pub should_quit: bool,
+}
+
+impl App {
+    pub fn new() -> Self {
+        Self {
+            board: TickerBoard::new(BOARD_CAPACITY),
+            chain: OptionsChain::default(),
+            underlyings: Vec::new(),
+            underlying_index: 0,
+            expiry_index: 0,
+            last_error: None,
+            should_quit: false,
+        }
+    }
+
+    /// Folds a freshly polled batch of tickers into the dashboard's state: every ticker updates
+    /// the recency board, and the batch as a whole replaces the chain view.
+    pub fn apply_tickers(&mut self, tickers: Vec<OptionTicker>) {
+        let mut underlyings: Vec<String> = tickers
+            .iter()
+            .filter_map(|ticker| Some(ticker.parsed_symbol().ok()?.underlying))
+            .collect();
+        underlyings.sort();
+        underlyings.dedup();
+
+        match OptionsChain::build(tickers.clone()) {
+            Ok(chain) => {
+                self.chain = chain;
+                self.last_error = None;
+            }
+            Err(err) => {
+                self.last_error = Some(format!("failed to build options chain: {err}"));
+            }
+        }
+
+        for ticker in tickers {
+            self.board.upsert(ticker);
+        }
+
+        self.underlyings = underlyings;
+        self.underlying_index = self.underlying_index.min(self.underlyings.len().saturating_sub(1));
+        self.expiry_index = 0;
+    }
+
+    /// The underlying currently selected for the chain view, if any data has arrived yet.
+    pub fn selected_underlying(&self) -> Option<&str> {
+        self.underlyings.get(self.underlying_index).map(String::as_str)
+    }
+
+    /// Moves the chain view to the next underlying, wrapping around.
+    pub fn select_next_underlying(&mut self) {
+        if !self.underlyings.is_empty() {
+            self.underlying_index = (self.underlying_index + 1) % self.underlyings.len();
+            self.expiry_index = 0;
+        }
+    }
+
+    /// Moves the chain view to the previous underlying, wrapping around.
+    pub fn select_previous_underlying(&mut self) {
+        if !self.underlyings.is_empty() {
+            self.underlying_index =
+                (self.underlying_index + self.underlyings.len() - 1) % self.underlyings.len();
+            self.expiry_index = 0;
+        }
+    }
+
+    /// The expiries quoted for the selected underlying, chronologically.
+    pub fn expiries(&self) -> Vec<(u32, u32, u32)> {
+        self.selected_underlying()
+            .map(|underlying| self.chain.expiries(underlying))
+            .unwrap_or_default()
+    }
+
+    /// Moves the chain view to the next expiry for the selected underlying, wrapping around.
+    pub fn select_next_expiry(&mut self) {
+        let count = self.expiries().len();
+        if count > 0 {
+            self.expiry_index = (self.expiry_index + 1) % count;
+        }
+    }
+
+    /// Moves the chain view to the previous expiry for the selected underlying, wrapping around.
+    pub fn select_previous_expiry(&mut self) {
+        let count = self.expiries().len();
+        if count > 0 {
+            self.expiry_index = (self.expiry_index + count - 1) % count;
+        }
+    }
+}
+
+impl Default for App {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn ticker(symbol: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: "0".to_string(),
+            last_qty: "0".to_string(),
+            open: "0".to_string(),
+            high: "0".to_string(),
+            low: "0".to_string(),
+            volume: "0".to_string(),
+            amount: "0".to_string(),
+            bid_price: "0".to_string(),
+            ask_price: "0".to_string(),
+            open_time: DateTime::<Utc>::UNIX_EPOCH,
+            close_time: DateTime::<Utc>::UNIX_EPOCH,
+            first_trade_id: 0,
+            trade_count: 0,
+            strike_price: "0".to_string(),
+            exercise_price: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn apply_tickers_collects_distinct_sorted_underlyings() {
+        let mut app = App::new();
+        app.apply_tickers(vec![
+            ticker("ETH-200730-9000-C"),
+            ticker("BTC-200730-9000-C"),
+            ticker("BTC-200730-9500-P"),
+        ]);
+
+        assert_eq!(app.underlyings, vec!["BTC", "ETH"]);
+    }
+
+    #[test]
+    fn selecting_underlyings_wraps_around() {
+        let mut app = App::new();
+        app.apply_tickers(vec![ticker("BTC-200730-9000-C"), ticker("ETH-200730-9000-C")]);
+
+        assert_eq!(app.selected_underlying(), Some("BTC"));
+        app.select_previous_underlying();
+        assert_eq!(app.selected_underlying(), Some("ETH"));
+        app.select_next_underlying();
+        assert_eq!(app.selected_underlying(), Some("BTC"));
+    }
+
+    #[test]
+    fn expiries_are_scoped_to_the_selected_underlying() {
+        let mut app = App::new();
+        app.apply_tickers(vec![
+            ticker("BTC-200730-9000-C"),
+            ticker("BTC-200830-9000-C"),
+            ticker("ETH-200930-9000-C"),
+        ]);
+
+        assert_eq!(app.expiries(), vec![(2020, 7, 30), (2020, 8, 30)]);
+        app.select_next_underlying();
+        assert_eq!(app.expiries(), vec![(2020, 9, 30)]);
+    }
+
+    #[test]
+    fn a_later_batch_refreshes_the_board_without_losing_earlier_symbols() {
+        let mut app = App::new();
+        app.apply_tickers(vec![ticker("BTC-200730-9000-C")]);
+        app.apply_tickers(vec![ticker("ETH-200730-9000-C")]);
+
+        assert_eq!(app.board.len(), 2);
+    }
+}
@@ -0,0 +1,109 @@
+//! A terminal dashboard over `binance-options-client`: polls the full ticker list on an
+//! interval, tracks per-symbol recency in a [`store::TickerBoard`], and renders the most
+//! recently updated instrument alongside a sortable-by-construction chain view (underlying →
+//! expiry → strike, courtesy of `binance_options_client::OptionsChain`'s `BTreeMap`s).
+//!
+//! Polling rather than streaming: `binance-options-client`'s WebSocket support
+//! (`binance_options_client::ws`) is per-symbol, with no all-market ticker stream, so a REST
+//! poll of the whole chain every [`POLL_INTERVAL`] is simpler than juggling a few thousand
+//! per-symbol subscriptions for a dashboard that wants to cover the whole board.
+
+mod app;
+mod store;
+mod ui;
+
+use std::io;
+use std::time::Duration;
+
+use binance_options_client::{BinanceOptionsClient, OptionTicker};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use crossterm::terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode};
+use crossterm::{ExecutableCommand, execute};
+use ratatui::Terminal;
+use ratatui::backend::CrosstermBackend;
+use tokio::sync::mpsc;
+
+use app::App;
+
+/// How often the dashboard re-polls the full ticker list.
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+#[tokio::main]
+async fn main() -> io::Result<()> {
+    let client = BinanceOptionsClient::builder()
+        .build()
+        .map_err(|err| io::Error::other(err.to_string()))?;
+
+    let (tx, mut rx) = mpsc::channel::<Result<Vec<OptionTicker>, String>>(1);
+    tokio::spawn(poll_loop(client, tx));
+
+    let mut stdout = io::stdout();
+    enable_raw_mode()?;
+    stdout.execute(EnterAlternateScreen)?;
+    let mut terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let mut app = App::new();
+    let result = run(&mut terminal, &mut app, &mut rx).await;
+
+    disable_raw_mode()?;
+    execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+
+    result
+}
+
+/// Fetches the full ticker list every [`POLL_INTERVAL`] and forwards the result down `tx`.
+/// Exits once the receiver is dropped (the dashboard is shutting down).
+async fn poll_loop(client: BinanceOptionsClient, tx: mpsc::Sender<Result<Vec<OptionTicker>, String>>) {
+    loop {
+        let outcome = client.get_ticker(None).await.map_err(|err| err.to_string());
+        if tx.send(outcome).await.is_err() {
+            return;
+        }
+        tokio::time::sleep(POLL_INTERVAL).await;
+    }
+}
+
+async fn run(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    app: &mut App,
+    rx: &mut mpsc::Receiver<Result<Vec<OptionTicker>, String>>,
+) -> io::Result<()> {
+    while !app.should_quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        tokio::select! {
+            tickers = rx.recv() => {
+                match tickers {
+                    Some(Ok(tickers)) => app.apply_tickers(tickers),
+                    Some(Err(error)) => app.last_error = Some(error),
+                    None => app.should_quit = true,
+                }
+            }
+            _ = tokio::time::sleep(Duration::from_millis(100)) => {
+                handle_input(app)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn handle_input(app: &mut App) -> io::Result<()> {
+    if !event::poll(Duration::from_millis(0))? {
+        return Ok(());
+    }
+
+    if let Event::Key(key) = event::read()?
+        && key.kind == KeyEventKind::Press
+    {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => app.should_quit = true,
+            KeyCode::Left => app.select_previous_underlying(),
+            KeyCode::Right => app.select_next_underlying(),
+            KeyCode::Up => app.select_previous_expiry(),
+            KeyCode::Down => app.select_next_expiry(),
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
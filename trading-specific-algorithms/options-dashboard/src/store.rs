@@ -0,0 +1,149 @@
+//! Tracks each symbol's latest ticker in a `hash-table` `LinkedHashTable`, so the "most
+//! recently updated" panel can answer that with `get_last()`'s O(1) lookup instead of scanning
+//! every ticker on every refresh.
+//!
+//! `OptionTicker` is heap-containing (`String` fields throughout), and
+//! `LinkedOpenAddressing::remove` is unsound for heap values, as documented on
+//! `binance_options_client::response_cache`. This board never removes entries, only updates
+//! them in place, so that particular hazard doesn't apply here — but it still stores only a
+//! `usize` slot index in the table, with the tickers themselves in a side `Vec`, for the same
+//! reason `response_cache` does: it keeps the table's value type `Copy` regardless.
+//!
+//! `slots_by_symbol` is a second, redundant-looking index into the same slots. It earns its
+//! keep: `LinkedOpenAddressing::probe` panics rather than returning "not found" when it's asked
+//! to resolve a key that's absent from a table that has no empty slot or tombstone to report
+//! (i.e. a full board that has never had an entry removed from it), and this board's whole point
+//! is to keep running once it's full rather than evict. Checking `slots_by_symbol` first means
+//! `recency` is only ever queried for a key we've already confirmed is present.
+
+use std::collections::HashMap;
+
+use binance_options_client::OptionTicker;
+use hash_table::{HashTable, LinkedHashTable};
+
+/// Per-symbol latest-ticker tracker backed by a fixed-capacity `LinkedHashTable`.
+pub struct TickerBoard {
+    capacity: usize,
+    slots_by_symbol: HashMap<String, usize>,
+    recency: LinkedHashTable<String, usize>,
+    entries: Vec<OptionTicker>,
+}
+
+impl TickerBoard {
+    /// Tracks up to `capacity` distinct symbols. Once full, tickers for symbols not already
+    /// tracked are silently dropped rather than evicted — this board has no LRU eviction policy
+    /// (unlike `binance_options_client::response_cache`'s cache, which does).
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            slots_by_symbol: HashMap::new(),
+            recency: LinkedHashTable::new(capacity),
+            entries: Vec::new(),
+        }
+    }
+
+    /// Records `ticker` as the latest snapshot for its symbol, moving it to the front of the
+    /// recency order. A no-op if the board is full and `ticker.symbol` isn't already tracked.
+    pub fn upsert(&mut self, ticker: OptionTicker) {
+        let symbol = ticker.symbol.clone();
+
+        if let Some(&slot) = self.slots_by_symbol.get(&symbol) {
+            // `LinkedOpenAddressing::insert` checks "is the table full" before it notices an
+            // existing key is just being updated, so a re-insert once the board is at capacity
+            // would panic unless we free up the slot first (same workaround
+            // `response_cache::CacheState::put` uses, and sound here for the same reason: the
+            // table's value type is `usize`, not a heap type, so `remove`'s zero-placeholder
+            // swap is safe).
+            self.entries[slot] = ticker;
+            self.recency.remove(&symbol);
+            self.recency.insert(symbol, slot);
+            return;
+        }
+
+        if self.slots_by_symbol.len() >= self.capacity {
+            return;
+        }
+
+        let slot = self.entries.len();
+        self.entries.push(ticker);
+        self.slots_by_symbol.insert(symbol.clone(), slot);
+        self.recency.insert(symbol, slot);
+    }
+
+    /// The symbol updated most recently (by the most recent [`Self::upsert`] call), if any.
+    pub fn most_recently_updated(&self) -> Option<&OptionTicker> {
+        let (_, &slot) = self.recency.get_last()?;
+        self.entries.get(slot)
+    }
+
+    /// The symbol that has gone longest without an update, if any.
+    pub fn stalest(&self) -> Option<&OptionTicker> {
+        let (_, &slot) = self.recency.get_first()?;
+        self.entries.get(slot)
+    }
+
+    /// How many distinct symbols are currently tracked.
+    pub fn len(&self) -> usize {
+        self.recency.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    fn ticker(symbol: &str) -> OptionTicker {
+        OptionTicker {
+            symbol: symbol.to_string(),
+            price_change: "0".to_string(),
+            price_change_percent: "0".to_string(),
+            last_price: "0".to_string(),
+            last_qty: "0".to_string(),
+            open: "0".to_string(),
+            high: "0".to_string(),
+            low: "0".to_string(),
+            volume: "0".to_string(),
+            amount: "0".to_string(),
+            bid_price: "0".to_string(),
+            ask_price: "0".to_string(),
+            open_time: DateTime::<Utc>::UNIX_EPOCH,
+            close_time: DateTime::<Utc>::UNIX_EPOCH,
+            first_trade_id: 0,
+            trade_count: 0,
+            strike_price: "0".to_string(),
+            exercise_price: "0".to_string(),
+        }
+    }
+
+    #[test]
+    fn most_recently_updated_tracks_the_latest_upsert() {
+        let mut board = TickerBoard::new(4);
+        board.upsert(ticker("BTC-200730-9000-C"));
+        board.upsert(ticker("ETH-200730-9000-C"));
+
+        assert_eq!(board.most_recently_updated().unwrap().symbol, "ETH-200730-9000-C");
+    }
+
+    #[test]
+    fn re_upserting_an_existing_symbol_refreshes_its_recency() {
+        let mut board = TickerBoard::new(4);
+        board.upsert(ticker("BTC-200730-9000-C"));
+        board.upsert(ticker("ETH-200730-9000-C"));
+        board.upsert(ticker("BTC-200730-9000-C"));
+
+        assert_eq!(board.most_recently_updated().unwrap().symbol, "BTC-200730-9000-C");
+        assert_eq!(board.stalest().unwrap().symbol, "ETH-200730-9000-C");
+        assert_eq!(board.len(), 2);
+    }
+
+    #[test]
+    fn a_full_board_drops_updates_for_untracked_symbols() {
+        let mut board = TickerBoard::new(1);
+        board.upsert(ticker("BTC-200730-9000-C"));
+        board.upsert(ticker("ETH-200730-9000-C"));
+
+        assert_eq!(board.len(), 1);
+        assert_eq!(board.most_recently_updated().unwrap().symbol, "BTC-200730-9000-C");
+    }
+}
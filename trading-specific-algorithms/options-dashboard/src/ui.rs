@@ -0,0 +1,117 @@
+//! Renders the dashboard's current [`App`] state into the terminal frame.
+
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
+
+use crate::app::App;
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+    draw_recency_panel(frame, app, layout[0]);
+    draw_chain_panel(frame, app, layout[1]);
+    draw_status_line(frame, app, layout[2]);
+}
+
+fn draw_recency_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let most_recent = app
+        .board
+        .most_recently_updated()
+        .map(|ticker| format!("{} @ {}", ticker.symbol, ticker.last_price))
+        .unwrap_or_else(|| "(no data yet)".to_string());
+    let stalest = app
+        .board
+        .stalest()
+        .map(|ticker| format!("{} @ {}", ticker.symbol, ticker.last_price))
+        .unwrap_or_else(|| "(no data yet)".to_string());
+
+    let paragraph = Paragraph::new(vec![
+        Line::from(vec![
+            Span::styled("Most recently updated: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(most_recent),
+        ]),
+        Line::from(vec![
+            Span::styled("Stalest tracked: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::raw(stalest),
+        ]),
+    ])
+    .block(Block::default().borders(Borders::ALL).title(format!(
+        "{} instruments tracked",
+        app.board.len()
+    )));
+
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_chain_panel(frame: &mut Frame, app: &App, area: Rect) {
+    let Some(underlying) = app.selected_underlying() else {
+        frame.render_widget(
+            Paragraph::new("Waiting for the first poll...")
+                .block(Block::default().borders(Borders::ALL).title("Chain")),
+            area,
+        );
+        return;
+    };
+
+    let expiries = app.expiries();
+    let expiry = expiries.get(app.expiry_index).copied();
+
+    let rows: Vec<Row> = expiry
+        .map(|expiry| app.chain.strikes(underlying, expiry))
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(strike, pair)| {
+            let call_price = pair
+                .call
+                .as_ref()
+                .map(|t| t.last_price.clone())
+                .unwrap_or_else(|| "-".to_string());
+            let put_price = pair
+                .put
+                .as_ref()
+                .map(|t| t.last_price.clone())
+                .unwrap_or_else(|| "-".to_string());
+            Row::new(vec![
+                Cell::from(call_price),
+                Cell::from(strike.to_string()),
+                Cell::from(put_price),
+            ])
+        })
+        .collect();
+
+    let title = match expiry {
+        Some((year, month, day)) => format!(
+            "{underlying} chain — expiry {year:04}-{month:02}-{day:02} ({}/{})",
+            app.expiry_index + 1,
+            expiries.len().max(1),
+        ),
+        None => format!("{underlying} chain — no expiries quoted"),
+    };
+
+    let table = Table::new(
+        rows,
+        [Constraint::Percentage(34), Constraint::Percentage(32), Constraint::Percentage(34)],
+    )
+    .header(Row::new(vec!["Call", "Strike", "Put"]).style(Style::default().fg(Color::Yellow)))
+    .block(Block::default().borders(Borders::ALL).title(title));
+
+    frame.render_widget(table, area);
+}
+
+fn draw_status_line(frame: &mut Frame, app: &App, area: Rect) {
+    let text = match &app.last_error {
+        Some(error) => format!("error: {error}"),
+        None => "q: quit   ←/→: underlying   ↑/↓: expiry".to_string(),
+    };
+    frame.render_widget(Paragraph::new(text), area);
+}